@@ -3,7 +3,8 @@ use std::collections::HashMap;
 use cgmath::{Vector2, Vector3};
 use derive_new::new;
 use korangar_interface::application::ClipTraitExt;
-use ragnarok_packets::{EntityId, QuestColor, QuestEffectPacket};
+use korangar_networking::QuestObjective;
+use ragnarok_packets::{EntityId, ExperienceType, QuestColor, QuestEffectPacket};
 use rand::{thread_rng, Rng};
 
 use crate::graphics::*;
@@ -127,6 +128,122 @@ impl Particle for HealNumber {
     }
 }
 
+/// Floating feed entry that shows the experience gained by the player.
+#[derive(new)]
+pub struct ExperienceGain {
+    position: Vector3<f32>,
+    experience_amount: String,
+    color: Color,
+    #[new(value = "30.0")]
+    velocity_y: f32,
+    #[new(value = "1.5")]
+    timer: f32,
+}
+
+impl ExperienceGain {
+    pub fn from_experience(position: Vector3<f32>, amount: u64, experience_type: ExperienceType) -> Self {
+        let (experience_amount, color) = match experience_type {
+            ExperienceType::BaseExperience => (format!("+{amount} Base EXP"), Color::rgb_u8(224, 200, 80)),
+            ExperienceType::JobExperience => (format!("+{amount} Job EXP"), Color::rgb_u8(170, 130, 240)),
+        };
+
+        // Offset job experience a little so both entries of a kill don't overlap.
+        let offset = match experience_type {
+            ExperienceType::BaseExperience => 20.0,
+            ExperienceType::JobExperience => 15.0,
+        };
+
+        Self::new(position + Vector3::new(0.0, offset, 0.0), experience_amount, color)
+    }
+}
+
+impl Particle for ExperienceGain {
+    fn update(&mut self, delta_time: f32) -> bool {
+        self.position.y += self.velocity_y * delta_time;
+
+        self.timer -= delta_time;
+        self.timer > 0.0
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        renderer.render_damage_text(render_target, &self.experience_amount, final_position, self.color, 14.0);
+    }
+}
+
+/// Shows the progress of a hunting objective above the player when the quest
+/// journal is updated.
+#[derive(new)]
+pub struct QuestProgress {
+    position: Vector3<f32>,
+    progress: String,
+    color: Color,
+    #[new(value = "20.0")]
+    velocity_y: f32,
+    #[new(value = "2.0")]
+    timer: f32,
+}
+
+impl QuestProgress {
+    pub fn from_objective(position: Vector3<f32>, objective: &QuestObjective) -> Self {
+        let progress = format!("{} {}/{}", objective.mob_name, objective.current_count, objective.total_count);
+        let color = match objective.is_completed() {
+            true => Color::rgb_u8(30, 200, 30),
+            false => Color::rgb_u8(200, 200, 30),
+        };
+
+        Self::new(position + Vector3::new(0.0, 25.0, 0.0), progress, color)
+    }
+}
+
+impl Particle for QuestProgress {
+    fn update(&mut self, delta_time: f32) -> bool {
+        self.position.y += self.velocity_y * delta_time;
+
+        self.timer -= delta_time;
+        self.timer > 0.0
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+        let final_position = ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        };
+
+        renderer.render_damage_text(render_target, &self.progress, final_position, self.color, 14.0);
+    }
+}
+
 pub struct QuestIcon {
     position: Vector3<f32>,
     texture: Arc<ImageView>,
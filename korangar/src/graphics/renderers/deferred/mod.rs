@@ -23,7 +23,6 @@ use cgmath::{Matrix4, Vector2, Vector3};
 use korangar_interface::application::FontSizeTrait;
 #[cfg(feature = "debug")]
 use ragnarok_formats::transform::Transform;
-use ragnarok_packets::EntityId;
 use vulkano::device::{DeviceOwned, Queue};
 use vulkano::format::Format;
 use vulkano::image::Image;
@@ -541,7 +540,7 @@ impl EntityRendererTrait for DeferredRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
-        _entity_id: EntityId,
+        _picker_target: PickerTarget,
     ) where
         Self: Renderer,
     {
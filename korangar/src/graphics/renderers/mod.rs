@@ -48,7 +48,6 @@ use korangar_debug::profile_block;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
 use option_ext::OptionExt;
-use ragnarok_packets::EntityId;
 use vulkano::buffer::{Buffer, BufferUsage, Subbuffer};
 use vulkano::command_buffer::{
     AutoCommandBufferBuilder, ClearAttachment, ClearRect, CommandBufferUsage, CopyImageToBufferInfo, PrimaryAutoCommandBuffer,
@@ -155,7 +154,7 @@ pub trait EntityRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
-        entity_id: EntityId,
+        picker_target: PickerTarget,
     ) where
         Self: Renderer;
 }
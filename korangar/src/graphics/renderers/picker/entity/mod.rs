@@ -4,7 +4,6 @@ fragment_shader!("src/graphics/renderers/picker/entity/fragment_shader.glsl");
 use std::sync::Arc;
 
 use cgmath::{Vector2, Vector3};
-use ragnarok_packets::EntityId;
 use vulkano::descriptor_set::WriteDescriptorSet;
 use vulkano::device::{Device, DeviceOwned};
 use vulkano::image::sampler::Sampler;
@@ -99,7 +98,7 @@ impl EntityRenderer {
         scale: Vector2<f32>,
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
-        entity_id: EntityId,
+        picker_target: PickerTarget,
         mirror: bool,
     ) {
         if render_target.bind_subrenderer(PickerSubrenderer::Entity) {
@@ -115,7 +114,6 @@ impl EntityRenderer {
         let world_matrix = camera.billboard_matrix(position, origin, size);
         let texture_size = Vector2::new(1.0 / cell_count.x as f32, 1.0 / cell_count.y as f32);
         let texture_position = Vector2::new(texture_size.x * cell_position.x as f32, texture_size.y * cell_position.y as f32);
-        let picker_target = PickerTarget::Entity(entity_id);
 
         let (layout, set, set_id) = allocate_descriptor_set(&self.pipeline, &self.memory_allocator, 1, [
             WriteDescriptorSet::image_view_sampler(0, texture, self.nearest_sampler.clone()),
@@ -8,7 +8,6 @@ mod tile;
 use std::sync::Arc;
 
 use cgmath::{Matrix4, Vector2, Vector3};
-use ragnarok_packets::EntityId;
 use vulkano::device::{DeviceOwned, Queue};
 use vulkano::format::Format;
 use vulkano::pipeline::graphics::viewport::Viewport;
@@ -169,7 +168,7 @@ impl EntityRendererTrait for PickerRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
-        entity_id: EntityId,
+        picker_target: PickerTarget,
     ) where
         Self: Renderer,
     {
@@ -182,7 +181,7 @@ impl EntityRendererTrait for PickerRenderer {
             scale,
             cell_count,
             cell_position,
-            entity_id,
+            picker_target,
             mirror,
         );
     }
@@ -1,4 +1,4 @@
-use ragnarok_packets::EntityId;
+use ragnarok_packets::{EntityId, GroundItemId};
 
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -19,14 +19,19 @@ enum MarkerEncoding {
 /// remaining 16 bits contain the Y position. That gives us an effective maximum
 /// value of 16384 for the X channel, which for Ragnarok Online is plenty.
 ///
+/// If the most significant bit is not set but the second most significant bit
+/// is (`(1 << 30)`), it encodes a ground item. The remaining 30 bits contain
+/// the [`GroundItemId`].
+///
 /// If the `debug` feature is enabled, the most significant byte can contain a
 /// value matching that of a `MarkerEncoding` (private enum). If that is the
 /// case, the remaining 3 bytes are to be interpreted as the value of the
 /// marker.
 ///
-/// If the most significant bit is not set and the first byte does not match any
-/// `MarkerEncoding`, an entity is encoded. This way we can use the full 32
-/// bits to store the 32 bit [`EntityId`].
+/// If neither of the two most significant bits is set and the first byte does
+/// not match any `MarkerEncoding`, an entity is encoded. This way we can use
+/// the remaining 30 bits to store the [`EntityId`], which is plenty for the
+/// ids handed out by the server.
 #[derive(Debug, PartialEq, Eq)]
 pub enum PickerTarget {
     Tile {
@@ -34,6 +39,7 @@ pub enum PickerTarget {
         y: u16,
     },
     Entity(EntityId),
+    GroundItem(GroundItemId),
     #[cfg(feature = "debug")]
     Marker(MarkerIdentifier),
 }
@@ -46,6 +52,10 @@ impl From<u32> for PickerTarget {
             return Self::Tile { x, y };
         }
 
+        if data >> 30 == 1 {
+            return Self::GroundItem(GroundItemId(data ^ (1 << 30)));
+        }
+
         #[cfg(feature = "debug")]
         if data >> 24 == MarkerEncoding::Object as u32 {
             return Self::Marker(MarkerIdentifier::Object(data as usize & 0xFFF));
@@ -84,6 +94,7 @@ impl From<PickerTarget> for u32 {
                 encoded
             }
             PickerTarget::Entity(EntityId(entity_id)) => entity_id,
+            PickerTarget::GroundItem(GroundItemId(ground_item_id)) => (ground_item_id & 0x3FFFFFFF) | (1 << 30),
             #[cfg(feature = "debug")]
             PickerTarget::Marker(marker_identifier) => match marker_identifier {
                 MarkerIdentifier::Object(index) => ((MarkerEncoding::Object as u32) << 24) | (index as u32 & 0xFFF),
@@ -100,7 +111,7 @@ impl From<PickerTarget> for u32 {
 #[cfg(test)]
 #[allow(clippy::unusual_byte_groupings)]
 mod encoding {
-    use ragnarok_packets::EntityId;
+    use ragnarok_packets::{EntityId, GroundItemId};
 
     use crate::graphics::PickerTarget;
     #[cfg(feature = "debug")]
@@ -137,6 +148,10 @@ mod encoding {
     const ENTITY_ID: EntityId = EntityId(7);
     const ENCODED_ENTITY_ID: u32 = 0b00000000000000000000000000000111;
 
+    // Ground item
+    const GROUND_ITEM_ID: GroundItemId = GroundItemId(7);
+    const ENCODED_GROUND_ITEM_ID: u32 = 0b01_000000000000000000000000000111;
+
     #[test]
     fn encode_tile() {
         assert_eq!(u32::from(PickerTarget::Tile { x: X, y: Y }), ENCODED_POSITION);
@@ -234,4 +249,17 @@ mod encoding {
     fn decode_entity() {
         assert_eq!(PickerTarget::from(ENCODED_ENTITY_ID), PickerTarget::Entity(ENTITY_ID));
     }
+
+    #[test]
+    fn encode_ground_item() {
+        assert_eq!(u32::from(PickerTarget::GroundItem(GROUND_ITEM_ID)), ENCODED_GROUND_ITEM_ID);
+    }
+
+    #[test]
+    fn decode_ground_item() {
+        assert_eq!(
+            PickerTarget::from(ENCODED_GROUND_ITEM_ID),
+            PickerTarget::GroundItem(GROUND_ITEM_ID)
+        );
+    }
 }
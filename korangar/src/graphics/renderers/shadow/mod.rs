@@ -5,7 +5,6 @@ mod indicator;
 use std::sync::Arc;
 
 use cgmath::{Matrix4, Vector2, Vector3};
-use ragnarok_packets::EntityId;
 use serde::{Deserialize, Serialize};
 use vulkano::device::{DeviceOwned, Queue};
 use vulkano::format::{ClearValue, Format};
@@ -161,7 +160,7 @@ impl EntityRendererTrait for ShadowRenderer {
         cell_count: Vector2<usize>,
         cell_position: Vector2<usize>,
         mirror: bool,
-        _entity_id: EntityId,
+        _picker_target: PickerTarget,
     ) where
         Self: Renderer,
     {
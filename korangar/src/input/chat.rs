@@ -0,0 +1,95 @@
+use ragnarok_packets::EmotionId;
+
+/// Chat commands of all emotions that can be sent by the player, in the order
+/// of the `emotion.act`.
+pub const EMOTION_COMMANDS: &[(&str, EmotionId)] = &[
+    ("/!", EmotionId(0)),
+    ("/?", EmotionId(1)),
+    ("/ho", EmotionId(2)),
+    ("/lv", EmotionId(3)),
+    ("/swt", EmotionId(4)),
+    ("/ic", EmotionId(5)),
+    ("/an", EmotionId(6)),
+    ("/ag", EmotionId(7)),
+    ("/$", EmotionId(8)),
+    ("/...", EmotionId(9)),
+    ("/lv2", EmotionId(14)),
+    ("/thx", EmotionId(15)),
+    ("/wah", EmotionId(16)),
+    ("/sry", EmotionId(17)),
+    ("/heh", EmotionId(18)),
+    ("/swt2", EmotionId(19)),
+    ("/hmm", EmotionId(20)),
+    ("/no1", EmotionId(21)),
+    ("/??", EmotionId(22)),
+    ("/omg", EmotionId(23)),
+    ("/oh", EmotionId(24)),
+    ("/X", EmotionId(25)),
+    ("/hlp", EmotionId(26)),
+    ("/go", EmotionId(27)),
+    ("/sob", EmotionId(28)),
+    ("/gg", EmotionId(29)),
+    ("/kis", EmotionId(30)),
+    ("/kis2", EmotionId(31)),
+    ("/pif", EmotionId(32)),
+    ("/ok", EmotionId(33)),
+];
+
+/// A message typed into the chat input, split into the different kinds of
+/// commands that the chat supports.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChatCommand<'a> {
+    /// A regular message to all players around.
+    Message(&'a str),
+    /// A message to the party, written as `%message`.
+    PartyMessage(&'a str),
+    /// A message to the guild, written as `$message`.
+    GuildMessage(&'a str),
+    /// A private message to a single player, written as `/w "name" message`.
+    /// The quotes can be omitted if the name does not contain any spaces.
+    Whisper { name: &'a str, message: &'a str },
+    /// An emotion shown above the head of the player, written as one of the
+    /// [`EMOTION_COMMANDS`] (e.g. `/gg`).
+    Emotion(EmotionId),
+}
+
+impl<'a> ChatCommand<'a> {
+    pub const WHISPER_USAGE: &'static str = "Usage: /w \"name\" message";
+
+    /// Parse the contents of the chat input. Returns the usage of the command
+    /// if a command was recognized but is malformed.
+    pub fn parse(input: &'a str) -> Result<Self, &'static str> {
+        if let Some(party_message) = input.strip_prefix('%') {
+            return Ok(Self::PartyMessage(party_message));
+        }
+
+        if let Some(guild_message) = input.strip_prefix('$') {
+            return Ok(Self::GuildMessage(guild_message));
+        }
+
+        if let Some(&(_, emotion)) = EMOTION_COMMANDS.iter().find(|(command, _)| *command == input.trim_end()) {
+            return Ok(Self::Emotion(emotion));
+        }
+
+        let (command, arguments) = input.split_once(' ').unwrap_or((input, ""));
+
+        if !matches!(command, "/w" | "/whisper") {
+            return Ok(Self::Message(input));
+        }
+
+        let arguments = arguments.trim_start();
+
+        let (name, message) = match arguments.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').ok_or(Self::WHISPER_USAGE)?,
+            None => arguments.split_once(' ').ok_or(Self::WHISPER_USAGE)?,
+        };
+
+        let message = message.trim_start();
+
+        if name.is_empty() || message.is_empty() {
+            return Err(Self::WHISPER_USAGE);
+        }
+
+        Ok(Self::Whisper { name, message })
+    }
+}
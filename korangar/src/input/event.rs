@@ -3,7 +3,9 @@ use korangar_interface::event::ClickAction;
 use korangar_interface::ElementEvent;
 use korangar_networking::ShopItem;
 use ragnarok_packets::{
-    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EntityId, HotbarSlot, ShopId, SoldItemInformation, TilePosition,
+    AccountId, BuyOrSellOption, CharacterId, CharacterServerInformation, EmotionId, EntityId, GroundItemId, GuildId, HotbarSlot,
+    InventoryIndex, MailId, MailType, PartyId, Sex, ShopId, SoldItemInformation, StatusIncreaseType, TilePosition,
+    VendingShopItemInformation,
 };
 
 use crate::interface::application::{InterfaceSettings, InternalThemeKind};
@@ -28,10 +30,16 @@ pub enum UserEvent {
     OpenMenuWindow,
     OpenInventoryWindow,
     OpenEquipmentWindow,
+    OpenStatusWindow,
     OpenSkillTreeWindow,
+    OpenQuestWindow,
+    OpenAchievementWindow,
     OpenGraphicsSettingsWindow,
     OpenAudioSettingsWindow,
     OpenFriendsWindow,
+    OpenEmotionsWindow,
+    OpenPartyWindow,
+    OpenGuildWindow,
     ToggleShowInterface,
     SetThemeFile {
         theme_file: String,
@@ -45,8 +53,26 @@ pub enum UserEvent {
     },
     SelectCharacter(usize),
     OpenCharacterCreationWindow(usize),
-    CreateCharacter(usize, String),
-    DeleteCharacter(CharacterId),
+    UpdateCharacterPreview {
+        start_job: u16,
+        sex: Sex,
+        hair_style: u16,
+        hair_color: u16,
+    },
+    CreateCharacter {
+        slot: usize,
+        name: String,
+        hair_color: u16,
+        hair_style: u16,
+        start_job: u16,
+        sex: Sex,
+    },
+    ReserveCharacterDeletion(CharacterId),
+    AcceptCharacterDeletion {
+        character_id: CharacterId,
+        birthdate: [u8; 6],
+    },
+    CancelCharacterDeletion(CharacterId),
     RequestSwitchCharacterSlot(usize),
     CancelSwitchCharacterSlot,
     SwitchCharacterSlot(usize),
@@ -54,10 +80,21 @@ pub enum UserEvent {
     RequestPlayerInteract(EntityId),
     RequestWarpToMap(String, TilePosition),
     SendMessage(String),
+    SendEmotion(EmotionId),
+    SendWhisper {
+        name: String,
+        message: String,
+    },
     NextDialog(EntityId),
     CloseDialog(EntityId),
     ChooseDialogOption(EntityId, i8),
     MoveResource(Move),
+    UseItem(InventoryIndex),
+    DropItem {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    PickUpItem(GroundItemId),
     CastSkill(HotbarSlot),
     StopSkill(HotbarSlot),
     AddFriend(String),
@@ -73,6 +110,61 @@ pub enum UserEvent {
         account_id: AccountId,
         character_id: CharacterId,
     },
+    CreateParty(String),
+    InviteToParty(String),
+    LeaveParty,
+    ExpelPartyMember {
+        account_id: AccountId,
+        name: String,
+    },
+    ChangePartyLeader(AccountId),
+    RejectPartyInvitation(PartyId),
+    AcceptPartyInvitation(PartyId),
+    IncreaseStatus(StatusIncreaseType),
+    InviteToGuild(String),
+    LeaveGuild,
+    RejectGuildInvitation(GuildId),
+    AcceptGuildInvitation(GuildId),
+    RejectTradeRequest,
+    AcceptTradeRequest,
+    OfferTradeZeny(u32),
+    LockTrade,
+    CommitTrade,
+    CancelTrade,
+    CloseStorage,
+    OpenVendingShop {
+        title: String,
+        items: Vec<VendingShopItemInformation>,
+    },
+    CancelVendingSetup,
+    CloseVendingShop,
+    OpenMailbox,
+    CloseMailbox,
+    RequestMoreMails,
+    ReadMail {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    DeleteMail {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    TakeMailZeny {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    TakeMailItems {
+        mail_type: MailType,
+        mail_id: MailId,
+    },
+    OpenWriteMail,
+    ClaimAchievementReward(u32),
+    SendMail {
+        receiver_name: String,
+        title: String,
+        text: String,
+        zeny: u64,
+    },
     BuyItems {
         items: Vec<ShopItem<u32>>,
     },
@@ -114,6 +206,8 @@ pub enum UserEvent {
     #[cfg(feature = "debug")]
     ClearPacketHistory,
     #[cfg(feature = "debug")]
+    OpenNetworkStatisticsWindow,
+    #[cfg(feature = "debug")]
     CameraLookAround(Vector2<f32>),
     #[cfg(feature = "debug")]
     CameraMoveForward,
@@ -1,3 +1,4 @@
+mod chat;
 mod event;
 mod key;
 mod mode;
@@ -10,10 +11,12 @@ use korangar_interface::elements::{ElementCell, Focus};
 use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, TrackedState};
 use korangar_interface::Interface;
+use korangar_networking::InventoryItemDetails;
 use ragnarok_packets::{ClientTick, HotbarSlot};
 use winit::dpi::PhysicalPosition;
 use winit::event::{ElementState, MouseButton, MouseScrollDelta, VirtualKeyCode};
 
+pub use self::chat::{ChatCommand, EMOTION_COMMANDS};
 pub use self::event::UserEvent;
 pub use self::key::Key;
 pub use self::mode::{Grabbed, MouseInputMode};
@@ -23,7 +26,7 @@ use crate::graphics::{PickerRenderTarget, PickerTarget};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::layout::{ScreenPosition, ScreenSize};
-use crate::interface::resource::PartialMove;
+use crate::interface::resource::{ItemSource, PartialMove};
 
 const MOUSE_SCOLL_MULTIPLIER: f32 = 30.0;
 const KEY_COUNT: usize = variant_count::<VirtualKeyCode>();
@@ -273,6 +276,14 @@ impl InputSystem {
                             if let Some(resource_move) = hovered_element.borrow_mut().drop_resource(PartialMove::Item { source, item }) {
                                 events.push(UserEvent::MoveResource(resource_move));
                             }
+                        } else if window_index.is_none() && source == ItemSource::Inventory {
+                            // Releasing an item outside of any window drops it on the ground.
+                            let amount = match item.details {
+                                InventoryItemDetails::Regular { amount, .. } => amount,
+                                _ => 1,
+                            };
+
+                            events.push(UserEvent::DropItem { index: item.index, amount });
                         }
                     }
                     MouseInputMode::MoveSkill(source, skill) => {
@@ -466,6 +477,10 @@ impl InputSystem {
                 events.push(UserEvent::OpenInventoryWindow);
             }
 
+            if alt_down && self.get_key(VirtualKeyCode::M).pressed() {
+                events.push(UserEvent::OpenEmotionsWindow);
+            }
+
             if control_down && self.get_key(VirtualKeyCode::H).pressed() {
                 events.push(UserEvent::ToggleShowInterface);
             }
@@ -583,6 +598,7 @@ impl InputSystem {
                     if self.left_mouse_button.pressed() {
                         match picker_target {
                             PickerTarget::Entity(entity_id) => events.push(UserEvent::RequestPlayerInteract(entity_id)),
+                            PickerTarget::GroundItem(ground_item_id) => events.push(UserEvent::PickUpItem(ground_item_id)),
                             PickerTarget::Tile { x, y } => {
                                 let position = Vector2::new(x as usize, y as usize);
                                 self.mouse_input_mode = MouseInputMode::Walk(position);
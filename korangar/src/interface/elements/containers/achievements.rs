@@ -0,0 +1,232 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::{AchievementEntry, AchievementList};
+
+fn category_name(category: u32) -> &'static str {
+    match category {
+        1 => "General",
+        2 => "Adventure",
+        3 => "Battle",
+        4 => "Memorial",
+        5 => "Feat",
+        _ => "Other",
+    }
+}
+
+pub struct AchievementContainer {
+    achievements: PlainRemote<AchievementList>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl AchievementContainer {
+    pub fn new(achievements: PlainRemote<AchievementList>) -> Self {
+        let elements = {
+            let list = achievements.get();
+            let summary = &list.summary;
+
+            let mut categories: Vec<u32> = list.achievements.iter().map(AchievementEntry::get_category).collect();
+            categories.sort_unstable();
+            categories.dedup();
+
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = vec![
+                Text::default()
+                    .with_text(format!("Level {} ({} points)", summary.level, summary.total_score))
+                    .wrap(),
+                Text::default()
+                    .with_text(format!(
+                        "Experience: {} / {}",
+                        summary.experience, summary.experience_to_next_level
+                    ))
+                    .wrap(),
+            ];
+
+            elements.extend(categories.into_iter().map(|category| {
+                let achievements = list
+                    .achievements
+                    .iter()
+                    .filter(|entry| entry.get_category() == category)
+                    .map(Self::achievement_to_element)
+                    .collect();
+
+                Expandable::new(category_name(category).to_owned(), achievements, false).wrap()
+            }));
+
+            elements
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self {
+            achievements,
+            weak_self,
+            state,
+        }
+    }
+
+    fn achievement_to_element(entry: &AchievementEntry) -> ElementCell<InterfaceSettings> {
+        let achievement = &entry.achievement;
+        let mut elements: Vec<ElementCell<InterfaceSettings>> = Vec::new();
+
+        match &entry.metadata {
+            Some(metadata) => {
+                if !metadata.summary.is_empty() {
+                    elements.push(Text::default().with_text(metadata.summary.clone()).wrap());
+                }
+
+                elements.extend(
+                    metadata
+                        .objectives
+                        .iter()
+                        .zip(achievement.objectives.iter())
+                        .map(|((text, count), progress)| {
+                            let text = match achievement.is_completed || progress >= count {
+                                true => format!("^55ff55{}^000000: {} / {}", text, progress.min(count), count),
+                                false => format!("{}: {} / {}", text, progress, count),
+                            };
+
+                            Text::default().with_text(text).wrap()
+                        }),
+                );
+            }
+            None => {
+                elements.extend(
+                    achievement
+                        .objectives
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, progress)| **progress != 0)
+                        .map(|(index, progress)| Text::default().with_text(format!("Objective {}: {}", index + 1, progress)).wrap()),
+                );
+            }
+        }
+
+        if achievement.is_completed && !achievement.is_rewarded {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("Claim reward")
+                    .with_event(UserEvent::ClaimAchievementReward(achievement.achievement_id))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        let title = match achievement.is_completed {
+            true => format!("^55ff55{}^000000", entry.get_title()),
+            false => entry.get_title(),
+        };
+
+        Expandable::new(title, elements, false).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for AchievementContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.achievements.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.achievements.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Weak;
+use std::time::Instant;
 
 use korangar_interface::application::FontSizeTrait;
 use korangar_interface::elements::{ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text};
@@ -7,20 +9,24 @@ use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
 use korangar_interface::layout::PlacementResolver;
 use korangar_interface::state::{PlainRemote, Remote};
 use korangar_interface::{dimension_bound, size_bound};
-use ragnarok_packets::CharacterInformation;
+use ragnarok_packets::{CharacterId, CharacterInformation};
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer};
 use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::{CharacterDeletionStage, CharacterDeletionWindow};
 use crate::loaders::FontSize;
 
 // TODO: rework all of this
 pub struct CharacterPreview {
     characters: PlainRemote<Vec<CharacterInformation>>,
     move_request: PlainRemote<Option<usize>>,
+    deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
     slot: usize,
+    /// Seconds until the character can be deleted, as currently displayed.
+    displayed_deletion_time: Option<u64>,
     state: ContainerState<InterfaceSettings>,
 }
 
@@ -28,6 +34,7 @@ impl CharacterPreview {
     fn get_elements(
         characters: &PlainRemote<Vec<CharacterInformation>>,
         move_request: &PlainRemote<Option<usize>>,
+        deletion_time: Option<u64>,
         slot: usize,
     ) -> Vec<ElementCell<InterfaceSettings>> {
         if let Some(origin_slot) = *move_request.get() {
@@ -48,28 +55,90 @@ impl CharacterPreview {
         let character_information = characters.iter().find(|character| character.character_number as usize == slot);
 
         if let Some(character_information) = character_information {
-            return vec![
+            let character_id = character_information.character_id;
+            let character_name = character_information.name.clone();
+
+            let mut elements = vec![
                 Text::default()
                     .with_text(character_information.name.clone())
                     .with_foreground_color(|_| Color::rgb_u8(220, 210, 210))
                     .with_font_size(|_| FontSize::new(18.0))
                     .wrap(),
-                ButtonBuilder::new()
-                    .with_text("Switch")
-                    .with_event(UserEvent::RequestSwitchCharacterSlot(slot))
-                    .with_background_color(|_| Color::rgb_u8(161, 141, 141))
-                    .with_width_bound(dimension_bound!(50%))
-                    .build()
-                    .wrap(),
-                ButtonBuilder::new()
-                    .with_text("Delete")
-                    .with_event(UserEvent::DeleteCharacter(character_information.character_id))
-                    .with_background_color(|theme: &InterfaceTheme| theme.close_button.background_color.get())
-                    .with_foreground_color(|theme: &InterfaceTheme| theme.close_button.foreground_color.get())
-                    .with_width_bound(dimension_bound!(50%))
-                    .build()
-                    .wrap(),
             ];
+
+            match deletion_time {
+                Some(deletion_time) => {
+                    let text = match deletion_time {
+                        0 => "Ready to be deleted".to_owned(),
+                        _ => format!(
+                            "Deletable in {:02}:{:02}:{:02}",
+                            deletion_time / 3600,
+                            deletion_time / 60 % 60,
+                            deletion_time % 60
+                        ),
+                    };
+
+                    let accept_action = move || {
+                        vec![ClickAction::OpenWindow(Box::new(CharacterDeletionWindow::new(
+                            character_id,
+                            character_name.clone(),
+                            CharacterDeletionStage::Accept,
+                        )))]
+                    };
+
+                    elements.extend([
+                        Text::default()
+                            .with_text(text)
+                            .with_foreground_color(|_| Color::rgb_u8(220, 120, 120))
+                            .wrap(),
+                        ButtonBuilder::new()
+                            .with_text("Keep")
+                            .with_event(UserEvent::CancelCharacterDeletion(character_id))
+                            .with_background_color(|_| Color::rgb_u8(161, 141, 141))
+                            .with_width_bound(dimension_bound!(50%))
+                            .build()
+                            .wrap(),
+                        ButtonBuilder::new()
+                            .with_text("Delete")
+                            .with_disabled_selector(move || deletion_time == 0)
+                            .with_event(Box::new(accept_action))
+                            .with_background_color(|theme: &InterfaceTheme| theme.close_button.background_color.get())
+                            .with_foreground_color(|theme: &InterfaceTheme| theme.close_button.foreground_color.get())
+                            .with_width_bound(dimension_bound!(50%))
+                            .build()
+                            .wrap(),
+                    ]);
+                }
+                None => {
+                    let reserve_action = move || {
+                        vec![ClickAction::OpenWindow(Box::new(CharacterDeletionWindow::new(
+                            character_id,
+                            character_name.clone(),
+                            CharacterDeletionStage::Reserve,
+                        )))]
+                    };
+
+                    elements.extend([
+                        ButtonBuilder::new()
+                            .with_text("Switch")
+                            .with_event(UserEvent::RequestSwitchCharacterSlot(slot))
+                            .with_background_color(|_| Color::rgb_u8(161, 141, 141))
+                            .with_width_bound(dimension_bound!(50%))
+                            .build()
+                            .wrap(),
+                        ButtonBuilder::new()
+                            .with_text("Delete")
+                            .with_event(Box::new(reserve_action))
+                            .with_background_color(|theme: &InterfaceTheme| theme.close_button.background_color.get())
+                            .with_foreground_color(|theme: &InterfaceTheme| theme.close_button.foreground_color.get())
+                            .with_width_bound(dimension_bound!(50%))
+                            .build()
+                            .wrap(),
+                    ]);
+                }
+            }
+
+            return elements;
         }
 
         vec![
@@ -80,14 +149,39 @@ impl CharacterPreview {
         ]
     }
 
-    pub fn new(characters: PlainRemote<Vec<CharacterInformation>>, move_request: PlainRemote<Option<usize>>, slot: usize) -> Self {
-        let elements = Self::get_elements(&characters, &move_request, slot);
+    /// Seconds until the character in this slot can be deleted, if its
+    /// deletion was reserved.
+    fn get_deletion_time(
+        characters: &PlainRemote<Vec<CharacterInformation>>,
+        deletion_dates: &PlainRemote<HashMap<CharacterId, Instant>>,
+        slot: usize,
+    ) -> Option<u64> {
+        let characters = characters.get();
+        let character_information = characters.iter().find(|character| character.character_number as usize == slot)?;
+        let deletion_date = *deletion_dates.get().get(&character_information.character_id)?;
+
+        // Round up so that the timer only shows zero once the character can actually be
+        // deleted.
+        let remaining = deletion_date.saturating_duration_since(Instant::now());
+        Some(remaining.as_secs() + (remaining.subsec_nanos() > 0) as u64)
+    }
+
+    pub fn new(
+        characters: PlainRemote<Vec<CharacterInformation>>,
+        move_request: PlainRemote<Option<usize>>,
+        deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
+        slot: usize,
+    ) -> Self {
+        let displayed_deletion_time = Self::get_deletion_time(&characters, &deletion_dates, slot);
+        let elements = Self::get_elements(&characters, &move_request, displayed_deletion_time, slot);
         let state = ContainerState::new(elements);
 
         Self {
             characters,
             move_request,
+            deletion_dates,
             slot,
+            displayed_deletion_time,
             state,
         }
     }
@@ -141,12 +235,22 @@ impl Element<InterfaceSettings> for CharacterPreview {
     fn update(&mut self) -> Option<ChangeEvent> {
         let characters_changed = self.characters.consume_changed();
         let move_request_changed = self.move_request.consume_changed();
+        let deletion_dates_changed = self.deletion_dates.consume_changed();
+
+        // Update the deletion timer once per second.
+        let deletion_time_changed =
+            Self::get_deletion_time(&self.characters, &self.deletion_dates, self.slot) != self.displayed_deletion_time;
 
-        if characters_changed || move_request_changed {
+        if characters_changed || move_request_changed || deletion_dates_changed || deletion_time_changed {
             let weak_self = self.state.state.self_element.take().unwrap();
             let weak_parent = self.state.state.parent_element.clone();
 
-            *self = Self::new(self.characters.clone(), self.move_request.clone(), self.slot);
+            *self = Self::new(
+                self.characters.clone(),
+                self.move_request.clone(),
+                self.deletion_dates.clone(),
+                self.slot,
+            );
 
             // important: link back after creating elements, otherwise focus navigation and
             // scrolling would break
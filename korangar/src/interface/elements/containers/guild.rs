@@ -0,0 +1,176 @@
+use korangar_interface::elements::{ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::GuildEmblem;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::GuildDetails;
+
+pub struct GuildContainer {
+    details: PlainRemote<GuildDetails>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl GuildContainer {
+    pub fn new(details: PlainRemote<GuildDetails>) -> Self {
+        let elements = {
+            let details = details.get();
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = Vec::new();
+
+            if details.guild_id.is_none() {
+                elements.push(Text::default().with_text("You are not in a guild").wrap());
+            } else {
+                if let Some(emblem) = &details.emblem {
+                    elements.push(GuildEmblem::new(emblem.clone()).wrap());
+                }
+
+                elements.push(Text::default().with_text(format!("^ffaa00{}^000000", details.guild_name)).wrap());
+
+                if let Some(information) = &details.information {
+                    let lines = [
+                        format!("Level: {}", information.level),
+                        format!("Master: {}", information.master_name),
+                        format!("Members: {}/{}", information.member_count, information.maximum_member_count),
+                        format!("Average level: {}", information.average_level),
+                        format!("Experience: {}/{}", information.experience, information.next_experience),
+                    ];
+
+                    elements.extend(lines.into_iter().map(|text| Text::default().with_text(text).wrap()));
+                }
+
+                if let Some((subject, notice)) = &details.notice {
+                    elements.push(Text::default().with_text(format!("^ffaa00{}^000000", subject)).wrap());
+                    elements.push(Text::default().with_text(notice.clone()).wrap());
+                }
+
+                elements.extend(details.members.iter().map(|member| {
+                    let position_name = details.get_position_name(member.position_id).unwrap_or_default();
+                    let color = match member.is_online {
+                        true => "^000000",
+                        false => "^888888",
+                    };
+
+                    Text::default()
+                        .with_text(format!(
+                            "{}{} (Lv. {}) {}^000000",
+                            color, member.name, member.base_level, position_name
+                        ))
+                        .wrap()
+                }));
+            }
+
+            elements
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { details, weak_self, state }
+    }
+}
+
+impl Element<InterfaceSettings> for GuildContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.details.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.details.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,503 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::{InventoryItem, InventoryItemDetails, Mail, MailPreview};
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ItemBox;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::resource::{ItemSource, Move, PartialMove};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::MailList;
+use crate::loaders::ResourceMetadata;
+
+/// Maximum number of items that can be attached to a single mail.
+const MAXIMUM_ATTACHMENTS: usize = 5;
+
+/// List of all mails in the mailbox.
+pub struct MailboxContainer {
+    list: PlainRemote<MailList>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailboxContainer {
+    pub fn new(list: PlainRemote<MailList>) -> Self {
+        let elements = {
+            let list = list.get();
+            let mut elements = vec![Text::default().with_text(format!("Unread mails: {}", list.unread_count)).wrap()];
+
+            elements.extend(list.mails.iter().map(Self::mail_to_element));
+
+            if !list.is_end {
+                elements.push(
+                    ButtonBuilder::new()
+                        .with_text("Load more")
+                        .with_event(UserEvent::RequestMoreMails)
+                        .build()
+                        .wrap(),
+                );
+            }
+
+            elements
+        };
+
+        let state = ContainerState::new(elements);
+
+        Self { list, state }
+    }
+
+    fn mail_to_element(mail: &MailPreview) -> ElementCell<InterfaceSettings> {
+        let attachments = match (mail.has_zeny, mail.has_items) {
+            (true, true) => "Zeny and items attached",
+            (true, false) => "Zeny attached",
+            (false, true) => "Items attached",
+            (false, false) => "No attachments",
+        };
+
+        let elements = vec![
+            Text::default().with_text(format!("From: {}", mail.sender_name)).wrap(),
+            Text::default().with_text(attachments).wrap(),
+            Text::default()
+                .with_text(format!("Expires in {} days", mail.expiration_time / 86400))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Read")
+                .with_event(UserEvent::ReadMail {
+                    mail_type: mail.mail_type,
+                    mail_id: mail.mail_id,
+                })
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Delete")
+                .with_event(UserEvent::DeleteMail {
+                    mail_type: mail.mail_type,
+                    mail_id: mail.mail_id,
+                })
+                .build()
+                .wrap(),
+        ];
+
+        let display = match mail.is_read {
+            true => mail.title.clone(),
+            false => format!("^ffaa00{}^000000", mail.title),
+        };
+
+        Expandable::new(display, elements, false).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for MailboxContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.list.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.list.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Content of the mail that was opened last.
+pub struct MailContainer {
+    mail: PlainRemote<Option<Mail<ResourceMetadata>>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailContainer {
+    pub fn new(mail: PlainRemote<Option<Mail<ResourceMetadata>>>) -> Self {
+        let elements = {
+            let mail = mail.get();
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = Vec::new();
+
+            match mail.as_ref() {
+                None => elements.push(Text::default().with_text("No mail selected").wrap()),
+                Some(mail) => {
+                    elements.push(Text::default().with_text(mail.text.clone()).wrap());
+
+                    if mail.zeny > 0 {
+                        elements.push(Text::default().with_text(format!("Zeny: {}", mail.zeny)).wrap());
+                        elements.push(
+                            ButtonBuilder::new()
+                                .with_text("Take zeny")
+                                .with_event(UserEvent::TakeMailZeny {
+                                    mail_type: mail.mail_type,
+                                    mail_id: mail.mail_id,
+                                })
+                                .build()
+                                .wrap(),
+                        );
+                    }
+
+                    if !mail.items.is_empty() {
+                        elements.extend(mail.items.iter().map(|item| {
+                            let amount = match item.details {
+                                InventoryItemDetails::Regular { amount, .. } => amount,
+                                InventoryItemDetails::Equippable { .. } => 1,
+                            };
+
+                            Text::default().with_text(format!("{} x{}", item.metadata.name, amount)).wrap()
+                        }));
+                        elements.push(
+                            ButtonBuilder::new()
+                                .with_text("Take items")
+                                .with_event(UserEvent::TakeMailItems {
+                                    mail_type: mail.mail_type,
+                                    mail_id: mail.mail_id,
+                                })
+                                .build()
+                                .wrap(),
+                        );
+                    }
+
+                    elements.push(
+                        ButtonBuilder::new()
+                            .with_text("Delete")
+                            .with_event(UserEvent::DeleteMail {
+                                mail_type: mail.mail_type,
+                                mail_id: mail.mail_id,
+                            })
+                            .build()
+                            .wrap(),
+                    );
+                }
+            }
+
+            elements
+        };
+
+        let state = ContainerState::new(elements);
+
+        Self { mail, state }
+    }
+}
+
+impl Element<InterfaceSettings> for MailContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.mail.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.mail.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
+
+/// Items attached to the mail that is being written. Items can be moved here
+/// from the inventory and back.
+pub struct MailAttachmentContainer {
+    items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl MailAttachmentContainer {
+    pub fn new(items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>) -> Self {
+        let elements = {
+            let items = items.get();
+
+            (0..MAXIMUM_ATTACHMENTS)
+                .map(|index| items.get(index).cloned())
+                .map(|item| ItemBox::new(item, ItemSource::Mail, Box::new(|_| false)))
+                .map(ElementWrap::wrap)
+                .collect()
+        };
+
+        let state = ContainerState::new(elements);
+
+        Self { items, state }
+    }
+}
+
+impl Element<InterfaceSettings> for MailAttachmentContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, ?);
+        self.state
+            .resolve(placement_resolver, application, theme, size_bound, ScreenSize::uniform(3.0));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.items.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.state.state.self_element.take().unwrap();
+
+            *self = Self::new(self.items.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::MoveItem(..) => self.state.state.hovered_element(mouse_position),
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
+        let PartialMove::Item { source, item } = drop_resource else {
+            return None;
+        };
+
+        (source == ItemSource::Inventory).then_some(Move::Item {
+            source,
+            destination: ItemSource::Mail,
+            item,
+        })
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+
+        if matches!(mouse_mode, MouseInputMode::MoveItem(..)) {
+            match self.is_element_self(hovered_element) {
+                true => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(60, 160, 160, 160)),
+                false => renderer.render_background(CornerRadius::uniform(5.0), Color::rgba_u8(160, 160, 60, 160)),
+            }
+        }
+    }
+}
@@ -1,19 +1,35 @@
+mod achievements;
 mod character;
 mod dialog;
 mod equipment;
 mod friends;
+mod guild;
 mod hotbar;
 mod inventory;
+mod mail;
 #[cfg(feature = "debug")]
 mod packet;
+mod party;
+mod quests;
 mod skill_tree;
+mod status;
+mod storage;
+mod trade;
 
+pub use self::achievements::AchievementContainer;
 pub use self::character::CharacterPreview;
 pub use self::dialog::{DialogContainer, DialogElement};
 pub use self::equipment::EquipmentContainer;
 pub use self::friends::FriendView;
+pub use self::guild::GuildContainer;
 pub use self::hotbar::HotbarContainer;
 pub use self::inventory::InventoryContainer;
+pub use self::mail::{MailAttachmentContainer, MailContainer, MailboxContainer};
 #[cfg(feature = "debug")]
 pub use self::packet::{PacketHistoryCallback, PacketHistoryRemote, PacketView};
+pub use self::party::PartyView;
+pub use self::quests::QuestContainer;
 pub use self::skill_tree::SkillTreeContainer;
+pub use self::status::StatusContainer;
+pub use self::storage::StorageContainer;
+pub use self::trade::TradeContainer;
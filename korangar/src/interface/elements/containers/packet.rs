@@ -12,7 +12,7 @@ use korangar_interface::size_bound;
 use korangar_interface::state::{PlainRemote, Remote, RemoteClone};
 use ragnarok_bytes::{ByteStream, ConversionError, ConversionResult, FromBytes};
 use ragnarok_packets::handler::PacketCallback;
-use ragnarok_packets::{Packet, PacketHeader};
+use ragnarok_packets::{Packet, PacketHeader, PacketLength};
 
 use crate::graphics::{InterfaceRenderer, Renderer};
 use crate::input::MouseInputMode;
@@ -30,6 +30,10 @@ impl Packet for UnknownPacket {
     const HEADER: PacketHeader = PacketHeader(0);
     const IS_PING: bool = false;
 
+    fn packet_length() -> PacketLength {
+        PacketLength::Variable
+    }
+
     fn payload_from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
         let _ = byte_stream;
         unimplemented!()
@@ -75,6 +79,10 @@ impl Packet for ErrorPacket {
     const HEADER: PacketHeader = PacketHeader(0);
     const IS_PING: bool = false;
 
+    fn packet_length() -> PacketLength {
+        PacketLength::Variable
+    }
+
     fn payload_from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
         let _ = byte_stream;
         unimplemented!()
@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::PartyMember;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::linked::LinkedElement;
+use crate::interface::theme::InterfaceTheme;
+
+pub struct PartyView {
+    members: PlainRemote<Vec<(PartyMember, LinkedElement)>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl PartyView {
+    pub fn new(members: PlainRemote<Vec<(PartyMember, LinkedElement)>>) -> Self {
+        let elements = {
+            let members = members.get();
+
+            members
+                .iter()
+                .map(|(member, linked_element)| {
+                    let element = Self::member_to_element(member);
+                    linked_element.link(&element);
+                    element
+                })
+                .collect()
+        };
+
+        Self {
+            members,
+            state: ContainerState::new(elements),
+        }
+    }
+
+    fn member_to_element(member: &PartyMember) -> ElementCell<InterfaceSettings> {
+        let mut elements = vec![
+            Text::default().with_text(format!("Map: {}", member.map_name)).wrap(),
+            Text::default().with_text(format!("Base level: {}", member.base_level)).wrap(),
+        ];
+
+        if member.maximum_health_points > 0 {
+            elements.push(
+                Text::default()
+                    .with_text(format!("HP: {} / {}", member.health_points, member.maximum_health_points))
+                    .wrap(),
+            );
+        }
+
+        if !member.is_leader {
+            elements.push(
+                ButtonBuilder::new()
+                    .with_text("make leader")
+                    .with_event(UserEvent::ChangePartyLeader(member.account_id))
+                    .build()
+                    .wrap(),
+            );
+        }
+
+        elements.push(
+            ButtonBuilder::new()
+                .with_text("expel")
+                .with_event(UserEvent::ExpelPartyMember {
+                    account_id: member.account_id,
+                    name: member.name.clone(),
+                })
+                .build()
+                .wrap(),
+        );
+
+        let display_name = match (member.is_leader, member.is_online) {
+            (true, true) => format!("★ {}", member.name),
+            (true, false) => format!("★ {} (offline)", member.name),
+            (false, true) => member.name.clone(),
+            (false, false) => format!("{} (offline)", member.name),
+        };
+
+        Expandable::new(display_name, elements, false).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for PartyView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(
+        &mut self,
+        weak_self: Weak<RefCell<dyn Element<InterfaceSettings>>>,
+        weak_parent: Option<Weak<RefCell<dyn Element<InterfaceSettings>>>>,
+    ) {
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        let mut resolve = false;
+
+        if self.members.consume_changed() {
+            // Remove elements of old members from the start of the list and add new
+            // members to the list. Members that were updated have a new linked element,
+            // so their element is rebuilt as well.
+            self.members.get().iter().enumerate().for_each(|(index, (member, linked_element))| {
+                if linked_element.is_linked() {
+                    while !linked_element.is_linked_to(&self.state.elements[index]) {
+                        self.state.elements.remove(index);
+                    }
+                } else {
+                    let element = Self::member_to_element(member);
+                    let weak_self = self.state.state.self_element.clone();
+
+                    linked_element.link(&element);
+
+                    element.borrow_mut().link_back(Rc::downgrade(&element), weak_self);
+
+                    self.state.elements.insert(index, element);
+                    resolve = true;
+                }
+            });
+
+            // Remove elements of old members from the end of the list.
+            let member_count = self.members.get().len();
+            if member_count < self.state.elements.len() {
+                self.state.elements.truncate(member_count);
+                resolve = true;
+            }
+        }
+
+        match resolve {
+            true => Some(ChangeEvent::RESOLVE_WINDOW),
+            false => None,
+        }
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use korangar_interface::elements::{
+    ContainerState, Element, ElementCell, ElementState, ElementWrap, Expandable, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::QuestInformation;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+
+pub struct QuestContainer {
+    quests: PlainRemote<Vec<QuestInformation>>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl QuestContainer {
+    pub fn new(quests: PlainRemote<Vec<QuestInformation>>) -> Self {
+        let elements = {
+            let quests = quests.get();
+
+            quests.iter().map(Self::quest_to_element).collect()
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { quests, weak_self, state }
+    }
+
+    fn quest_to_element(quest: &QuestInformation) -> ElementCell<InterfaceSettings> {
+        let mut elements: Vec<ElementCell<InterfaceSettings>> = quest
+            .objectives
+            .iter()
+            .map(|objective| {
+                let text = match objective.is_completed() {
+                    true => format!(
+                        "^55ff55{}^000000: {} / {}",
+                        objective.mob_name, objective.current_count, objective.total_count
+                    ),
+                    false => format!(
+                        "{}: {} / {}",
+                        objective.mob_name, objective.current_count, objective.total_count
+                    ),
+                };
+
+                Text::default().with_text(text).wrap()
+            })
+            .collect();
+
+        if elements.is_empty() {
+            elements.push(Text::default().with_text("No hunting objectives".to_owned()).wrap());
+        }
+
+        let title = match quest.active {
+            true => format!("Quest {}", quest.quest_id),
+            false => format!("Quest {} (inactive)", quest.quest_id),
+        };
+
+        Expandable::new(title, elements, quest.active).wrap()
+    }
+}
+
+impl Element<InterfaceSettings> for QuestContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.quests.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.quests.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use korangar_interface::elements::{
+    ButtonBuilder, ContainerState, Element, ElementCell, ElementState, ElementWrap, Focus, Text, WeakElementCell,
+};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::StatusIncreaseType;
+
+use crate::graphics::{InterfaceRenderer, Renderer};
+use crate::input::{MouseInputMode, UserEvent};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::inventory::CharacterStatus;
+
+pub struct StatusContainer {
+    status: PlainRemote<CharacterStatus>,
+    weak_self: Option<WeakElementCell<InterfaceSettings>>,
+    state: ContainerState<InterfaceSettings>,
+}
+
+impl StatusContainer {
+    pub fn new(status: PlainRemote<CharacterStatus>) -> Self {
+        let elements = {
+            let status = status.get();
+
+            let attributes = [
+                ("STR", StatusIncreaseType::Strength),
+                ("AGI", StatusIncreaseType::Agility),
+                ("VIT", StatusIncreaseType::Vitality),
+                ("INT", StatusIncreaseType::Intelligence),
+                ("DEX", StatusIncreaseType::Dexterity),
+                ("LUK", StatusIncreaseType::Luck),
+            ];
+
+            let mut elements: Vec<ElementCell<InterfaceSettings>> = attributes
+                .into_iter()
+                .flat_map(|(name, status_type)| Self::attribute_to_elements(&status, name, status_type))
+                .collect();
+
+            let derived_values = [
+                format!("ATK: {} + {}", status.attack, status.bonus_attack),
+                format!("MATK: {} ~ {}", status.magic_attack, status.bonus_magic_attack),
+                format!("DEF: {} + {}", status.defense, status.bonus_defense),
+                format!("MDEF: {} + {}", status.magic_defense, status.bonus_magic_defense),
+                format!("HIT: {}", status.hit),
+                format!("FLEE: {} + {}", status.flee, status.bonus_flee),
+                format!("CRIT: {}", status.critical),
+                format!("ASPD: {}", status.attack_speed()),
+                format!("Status points: {}", status.status_points),
+            ];
+
+            elements.extend(derived_values.into_iter().map(|text| Text::default().with_text(text).wrap()));
+            elements
+        };
+
+        let weak_self = None;
+        let state = ContainerState::new(elements);
+
+        Self { status, weak_self, state }
+    }
+
+    fn attribute_to_elements(status: &CharacterStatus, name: &str, status_type: StatusIncreaseType) -> [ElementCell<InterfaceSettings>; 2] {
+        let attribute = status.get_attribute(status_type);
+        let disabled = status.status_points < attribute.cost as u32;
+
+        [
+            Text::default()
+                .with_text(format!("{}: {} + {}", name, attribute.base, attribute.bonus))
+                .with_width(dimension_bound!(70%))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text(format!("+ ({})", attribute.cost))
+                .with_event(UserEvent::IncreaseStatus(status_type))
+                .with_disabled_selector(move || disabled)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ]
+    }
+}
+
+impl Element<InterfaceSettings> for StatusContainer {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state.state
+    }
+
+    fn link_back(&mut self, weak_self: WeakElementCell<InterfaceSettings>, weak_parent: Option<WeakElementCell<InterfaceSettings>>) {
+        self.weak_self = Some(weak_self.clone());
+        self.state.link_back(weak_self, weak_parent);
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.state.is_focusable::<false>()
+    }
+
+    fn focus_next(
+        &self,
+        self_cell: ElementCell<InterfaceSettings>,
+        caller_cell: Option<ElementCell<InterfaceSettings>>,
+        focus: Focus,
+    ) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.focus_next::<false>(self_cell, caller_cell, focus)
+    }
+
+    fn restore_focus(&self, self_cell: ElementCell<InterfaceSettings>) -> Option<ElementCell<InterfaceSettings>> {
+        self.state.restore_focus(self_cell)
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(
+            placement_resolver,
+            application,
+            theme,
+            &size_bound!(100%, ?),
+            ScreenSize::default(),
+        );
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        if self.status.consume_changed() {
+            let weak_parent = self.state.state.parent_element.take();
+            let weak_self = self.weak_self.take().unwrap();
+
+            *self = Self::new(self.status.clone());
+            // important: link back after creating elements, otherwise focus navigation and
+            // scrolling would break
+            self.link_back(weak_self, weak_parent);
+
+            return Some(ChangeEvent::RESOLVE_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position, mouse_mode, false),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        focused_element: Option<&dyn Element<InterfaceSettings>>,
+        mouse_mode: &MouseInputMode,
+        second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        self.state.render(
+            &mut renderer,
+            application,
+            theme,
+            hovered_element,
+            focused_element,
+            mouse_mode,
+            second_theme,
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use korangar_interface::application::SizeTraitExt;
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use vulkano::image::view::ImageView;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::Scaling;
+
+/// Displays the emblem of a guild.
+pub struct GuildEmblem {
+    texture: Arc<ImageView>,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl GuildEmblem {
+    pub fn new(texture: Arc<ImageView>) -> Self {
+        Self {
+            texture,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for GuildEmblem {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(24, 24));
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        renderer.renderer.render_sprite(
+            renderer.render_target,
+            self.texture.clone(),
+            renderer.position,
+            ScreenSize::uniform(24.0).scaled(Scaling::new(application.get_scaling_factor())),
+            renderer.clip,
+            Color::monochrome_u8(255),
+            false,
+        );
+    }
+}
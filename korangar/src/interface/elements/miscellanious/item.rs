@@ -7,7 +7,7 @@ use korangar_interface::size_bound;
 use korangar_networking::{InventoryItem, InventoryItemDetails};
 
 use crate::graphics::{Color, InterfaceRenderer, Renderer, SpriteRenderer};
-use crate::input::MouseInputMode;
+use crate::input::{MouseInputMode, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::resource::{ItemSource, Move, PartialMove};
@@ -63,6 +63,17 @@ impl Element<InterfaceSettings> for ItemBox {
         Vec::new()
     }
 
+    fn right_click(&mut self, _force_update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        if let Some(item) = &self.item
+            && self.source == ItemSource::Inventory
+            && item.is_usable()
+        {
+            return vec![ClickAction::Custom(UserEvent::UseItem(item.index))];
+        }
+
+        Vec::new()
+    }
+
     fn drop_resource(&mut self, drop_resource: PartialMove) -> Option<Move> {
         let PartialMove::Item { source, item } = drop_resource else {
             return None;
@@ -1,7 +1,11 @@
 mod chat;
+mod emblem;
 mod item;
 mod skill;
+mod sprite_preview;
 
 pub use self::chat::ChatBuilder;
+pub use self::emblem::GuildEmblem;
 pub use self::item::ItemBox;
 pub use self::skill::SkillBox;
+pub use self::sprite_preview::CharacterSpritePreview;
@@ -0,0 +1,135 @@
+use std::time::Instant;
+
+use korangar_interface::elements::{Element, ElementState};
+use korangar_interface::event::{ChangeEvent, ClickAction, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use ragnarok_packets::ClientTick;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{ScreenClip, ScreenPosition};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::AnimationState;
+use crate::world::CharacterSprites;
+
+/// Animated preview of a character. Clicking the preview turns the character.
+pub struct CharacterSpritePreview {
+    sprites: PlainRemote<Option<CharacterSprites>>,
+    start_time: Instant,
+    direction: usize,
+    state: ElementState<InterfaceSettings>,
+}
+
+impl CharacterSpritePreview {
+    pub fn new(sprites: PlainRemote<Option<CharacterSprites>>) -> Self {
+        Self {
+            sprites,
+            start_time: Instant::now(),
+            direction: 0,
+            state: ElementState::default(),
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for CharacterSpritePreview {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        self.state.resolve(placement_resolver, &size_bound!(100%, 130));
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.sprites.consume_changed();
+
+        // The preview is animated, so it needs to be rendered every frame.
+        Some(ChangeEvent::RENDER_WINDOW)
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn left_click(&mut self, _update: &mut bool) -> Vec<ClickAction<InterfaceSettings>> {
+        self.direction = (self.direction + 1) % 8;
+        Vec::new()
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let sprites = self.sprites.get();
+        let Some(sprites) = sprites.as_ref() else {
+            return;
+        };
+
+        let mut animation_state = AnimationState::new(ClientTick(0));
+        animation_state.update(ClientTick(self.start_time.elapsed().as_millis() as u32));
+
+        // Place the feet of the character close to the bottom of the element.
+        let feet_position = ScreenPosition {
+            left: renderer.position.left + self.state.cached_size.width / 2.0,
+            top: renderer.position.top + self.state.cached_size.height - 15.0,
+        };
+
+        // The head is moved so that its attach point lines up with the one of the
+        // body.
+        let attach_offset = sprites.body_actions.get_attach_point(&animation_state, self.direction)
+            - sprites.head_actions.get_attach_point(&animation_state, self.direction);
+        let head_position = ScreenPosition {
+            left: feet_position.left + attach_offset.x,
+            top: feet_position.top + attach_offset.y,
+        };
+
+        sprites.body_actions.render2(
+            renderer.render_target,
+            renderer.renderer,
+            &sprites.body_sprite,
+            &animation_state,
+            feet_position,
+            self.direction,
+            Color::monochrome_u8(255),
+            application,
+        );
+        sprites.head_actions.render2(
+            renderer.render_target,
+            renderer.renderer,
+            &sprites.head_sprite,
+            &animation_state,
+            head_position,
+            self.direction,
+            Color::monochrome_u8(255),
+            application,
+        );
+    }
+}
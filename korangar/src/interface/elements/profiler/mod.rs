@@ -1,7 +1,9 @@
 mod colors;
 mod frame;
 mod inspector;
+mod network;
 
 use self::colors::ColorLookup;
 pub use self::frame::FrameView;
 pub use self::inspector::FrameInspectorView;
+pub use self::network::NetworkStatisticsView;
@@ -0,0 +1,258 @@
+use korangar_interface::application::FontSizeTrait;
+use korangar_interface::elements::{Element, ElementRenderer, ElementState};
+use korangar_interface::event::{ChangeEvent, HoverInformation};
+use korangar_interface::layout::PlacementResolver;
+use korangar_interface::size_bound;
+use korangar_interface::state::{PlainRemote, Remote};
+use korangar_networking::{NetworkStatisticsHandle, ServerStatistics, STATISTICS_HISTORY_LENGTH};
+use ragnarok_packets::ServerType;
+
+use crate::graphics::{Color, InterfaceRenderer, Renderer};
+use crate::input::MouseInputMode;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::{CornerRadius, ScreenClip, ScreenPosition, ScreenSize};
+use crate::interface::theme::InterfaceTheme;
+use crate::loaders::FontSize;
+
+/// Number of frames between two renders. New samples are only added once per
+/// second, so there is no need to render every frame.
+const UPDATE_FRAME_COUNT: usize = 30;
+/// Height of the bandwidth and the latency graph.
+const GRAPH_HEIGHT: f32 = 120.0;
+/// Vertical space between the graphs and the header list.
+const GRAPH_GAP: f32 = 10.0;
+/// Number of headers shown in the list below the graphs.
+const HEADER_COUNT: usize = 10;
+const LINE_HEIGHT: f32 = 14.0;
+
+fn received_color() -> Color {
+    Color::rgb_u8(90, 170, 230)
+}
+
+fn sent_color() -> Color {
+    Color::rgb_u8(230, 150, 70)
+}
+
+fn latency_color() -> Color {
+    Color::rgb_u8(120, 210, 120)
+}
+
+pub struct NetworkStatisticsView {
+    state: ElementState<InterfaceSettings>,
+    frame_counter: usize,
+    statistics: NetworkStatisticsHandle,
+    visible_server: PlainRemote<ServerType>,
+}
+
+impl NetworkStatisticsView {
+    pub fn new(statistics: NetworkStatisticsHandle, visible_server: PlainRemote<ServerType>) -> Self {
+        Self {
+            state: ElementState::default(),
+            frame_counter: 0,
+            statistics,
+            visible_server,
+        }
+    }
+
+    fn format_bytes(bytes: u64) -> String {
+        match bytes {
+            0..=1023 => format!("{bytes} B"),
+            1024..=1048575 => format!("{:.1} KiB", bytes as f32 / 1024.0),
+            _ => format!("{:.1} MiB", bytes as f32 / 1048576.0),
+        }
+    }
+
+    fn render_label(renderer: &mut ElementRenderer<'_, InterfaceSettings>, text: &str, top: f32, color: Color) {
+        let text_position = ScreenPosition { left: 3.0, top };
+        let shadow_position = text_position + ScreenSize::uniform(1.0);
+
+        // Drop shadow.
+        renderer.render_text(text, shadow_position, Color::monochrome_u8(0), FontSize::new(14.0));
+        // Colored text.
+        renderer.render_text(text, text_position, color, FontSize::new(14.0));
+    }
+
+    fn render_graphs(&self, renderer: &mut ElementRenderer<'_, InterfaceSettings>, statistics: &ServerStatistics) {
+        let bar_width = (self.state.cached_size.width - 50.0) / STATISTICS_HISTORY_LENGTH as f32;
+        let gap_width = 50.0 / STATISTICS_HISTORY_LENGTH as f32;
+        let latency_bottom = GRAPH_HEIGHT * 2.0 + GRAPH_GAP;
+
+        let most_bytes = statistics
+            .history
+            .iter()
+            .map(|sample| sample.bytes_received + sample.bytes_sent)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let longest_latency = statistics
+            .history
+            .iter()
+            .filter_map(|sample| sample.latency)
+            .max()
+            .unwrap_or_default()
+            .as_secs_f32()
+            .max(0.001);
+
+        let byte_unit = GRAPH_HEIGHT / most_bytes as f32;
+        let latency_unit = GRAPH_HEIGHT / longest_latency;
+
+        // Newer samples are on the right, like in the frame view.
+        let mut x_position = (STATISTICS_HISTORY_LENGTH - statistics.history.len()) as f32 * (bar_width + gap_width);
+
+        for sample in &statistics.history {
+            let received_height = byte_unit * sample.bytes_received as f32;
+            let sent_height = byte_unit * sample.bytes_sent as f32;
+            let bar_size = |height| ScreenSize { width: bar_width, height };
+
+            renderer.render_rectangle(
+                ScreenPosition {
+                    left: x_position,
+                    top: GRAPH_HEIGHT - received_height,
+                },
+                bar_size(received_height),
+                CornerRadius::default(),
+                received_color(),
+            );
+            renderer.render_rectangle(
+                ScreenPosition {
+                    left: x_position,
+                    top: GRAPH_HEIGHT - received_height - sent_height,
+                },
+                bar_size(sent_height),
+                CornerRadius::default(),
+                sent_color(),
+            );
+
+            if let Some(latency) = sample.latency {
+                let latency_height = latency_unit * latency.as_secs_f32();
+
+                renderer.render_rectangle(
+                    ScreenPosition {
+                        left: x_position,
+                        top: latency_bottom - latency_height,
+                    },
+                    bar_size(latency_height),
+                    CornerRadius::default(),
+                    latency_color(),
+                );
+            }
+
+            x_position += bar_width + gap_width;
+        }
+
+        let last_sample = statistics.history.back().copied().unwrap_or_default();
+        let received_text = format!(
+            "received {}/s, {} total ({} packets)",
+            Self::format_bytes(last_sample.bytes_received),
+            Self::format_bytes(statistics.bytes_received),
+            statistics.packets_received
+        );
+        let sent_text = format!(
+            "sent {}/s, {} total ({} packets)",
+            Self::format_bytes(last_sample.bytes_sent),
+            Self::format_bytes(statistics.bytes_sent),
+            statistics.packets_sent
+        );
+        let latency_text = match statistics.latency {
+            Some(latency) => format!(
+                "latency {:?} (max {:.0?})",
+                latency,
+                std::time::Duration::from_secs_f32(longest_latency)
+            ),
+            None => "latency unknown".to_owned(),
+        };
+
+        Self::render_label(renderer, &received_text, 0.0, received_color());
+        Self::render_label(renderer, &sent_text, LINE_HEIGHT, sent_color());
+        Self::render_label(renderer, &latency_text, GRAPH_HEIGHT + GRAPH_GAP, latency_color());
+    }
+
+    fn render_headers(renderer: &mut ElementRenderer<'_, InterfaceSettings>, statistics: &ServerStatistics) {
+        let mut headers: Vec<_> = statistics
+            .received_packets
+            .iter()
+            .map(|(header, count)| (*header, *count, "in"))
+            .chain(statistics.sent_packets.iter().map(|(header, count)| (*header, *count, "out")))
+            .collect();
+        headers.sort_by(|left, right| right.1.cmp(&left.1).then(left.0.cmp(&right.0)));
+
+        let mut y_position = (GRAPH_HEIGHT + GRAPH_GAP) * 2.0;
+
+        for (header, count, direction) in headers.into_iter().take(HEADER_COUNT) {
+            let text = format!("0x{:0>4x} {direction:<3} {count}", header.0);
+            let color = match direction {
+                "in" => received_color(),
+                _ => sent_color(),
+            };
+
+            Self::render_label(renderer, &text, y_position, color);
+            y_position += LINE_HEIGHT;
+        }
+    }
+}
+
+impl Element<InterfaceSettings> for NetworkStatisticsView {
+    fn get_state(&self) -> &ElementState<InterfaceSettings> {
+        &self.state
+    }
+
+    fn get_state_mut(&mut self) -> &mut ElementState<InterfaceSettings> {
+        &mut self.state
+    }
+
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    fn resolve(
+        &mut self,
+        placement_resolver: &mut PlacementResolver<InterfaceSettings>,
+        _application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+    ) {
+        let size_bound = &size_bound!(100%, 410);
+        self.state.resolve(placement_resolver, size_bound);
+    }
+
+    fn update(&mut self) -> Option<ChangeEvent> {
+        self.frame_counter += 1;
+
+        if self.visible_server.consume_changed() || self.frame_counter == UPDATE_FRAME_COUNT {
+            self.frame_counter = 0;
+            return Some(ChangeEvent::RENDER_WINDOW);
+        }
+
+        None
+    }
+
+    fn hovered_element(&self, mouse_position: ScreenPosition, mouse_mode: &MouseInputMode) -> HoverInformation<InterfaceSettings> {
+        match mouse_mode {
+            MouseInputMode::None => self.state.hovered_element(mouse_position),
+            _ => HoverInformation::Missed,
+        }
+    }
+
+    fn render(
+        &self,
+        render_target: &mut <InterfaceRenderer as Renderer>::Target,
+        renderer: &InterfaceRenderer,
+        application: &InterfaceSettings,
+        _theme: &InterfaceTheme,
+        parent_position: ScreenPosition,
+        screen_clip: ScreenClip,
+        _hovered_element: Option<&dyn Element<InterfaceSettings>>,
+        _focused_element: Option<&dyn Element<InterfaceSettings>>,
+        _mouse_mode: &MouseInputMode,
+        _second_theme: bool,
+    ) {
+        let mut renderer = self
+            .state
+            .element_renderer(render_target, renderer, application, parent_position, screen_clip);
+
+        let statistics = self.statistics.get();
+        let server_statistics = statistics.server(*self.visible_server.get());
+
+        self.render_graphs(&mut renderer, server_statistics);
+        Self::render_headers(&mut renderer, server_statistics);
+    }
+}
@@ -8,6 +8,9 @@ use crate::loaders::ResourceMetadata;
 pub enum ItemSource {
     Inventory,
     Equipment { position: EquipPosition },
+    Trade,
+    Storage,
+    Mail,
 }
 
 #[derive(Debug, Clone)]
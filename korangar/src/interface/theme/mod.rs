@@ -868,6 +868,7 @@ pub struct ChatTheme {
     pub server_color: Mutable<Color, Render>,
     pub error_color: Mutable<Color, Render>,
     pub information_color: Mutable<Color, Render>,
+    pub whisper_color: Mutable<Color, Render>,
 }
 
 impl ThemeDefault<DefaultMenu> for ChatTheme {
@@ -879,6 +880,7 @@ impl ThemeDefault<DefaultMenu> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 0)),
         }
     }
 }
@@ -892,6 +894,7 @@ impl ThemeDefault<DefaultMain> for ChatTheme {
             server_color: Mutable::new(Color::rgb_u8(255, 255, 210)),
             error_color: Mutable::new(Color::rgb_u8(255, 150, 150)),
             information_color: Mutable::new(Color::rgb_u8(200, 255, 200)),
+            whisper_color: Mutable::new(Color::rgb_u8(255, 255, 0)),
         }
     }
 }
@@ -1060,14 +1063,21 @@ pub struct StatusBarTheme {
     pub enemy_health_color: Mutable<Color, Nothing>,
     pub spell_point_color: Mutable<Color, Nothing>,
     pub activity_point_color: Mutable<Color, Nothing>,
+    pub base_experience_color: Mutable<Color, Nothing>,
+    pub job_experience_color: Mutable<Color, Nothing>,
     pub player_bar_width: MutableRange<f32, Render>,
     pub enemy_bar_width: MutableRange<f32, Render>,
     pub health_height: MutableRange<f32, Render>,
     pub enemy_health_height: MutableRange<f32, Render>,
     pub spell_point_height: MutableRange<f32, Render>,
     pub activity_point_height: MutableRange<f32, Render>,
+    pub experience_height: MutableRange<f32, Render>,
     pub border_size: MutableRange<ScreenSize, Render>,
     pub gap: MutableRange<f32, Render>,
+    pub status_effect_icon_size: MutableRange<f32, Render>,
+    pub buff_bar_icon_size: MutableRange<f32, Render>,
+    pub buff_bar_offset: MutableRange<ScreenPosition, Render>,
+    pub buff_bar_text_color: Mutable<Color, Nothing>,
 }
 
 impl Default for StatusBarTheme {
@@ -1078,18 +1088,29 @@ impl Default for StatusBarTheme {
             enemy_health_color: Mutable::new(Color::rgb_u8(206, 49, 116)),
             spell_point_color: Mutable::new(Color::rgb_u8(0, 129, 163)),
             activity_point_color: Mutable::new(Color::rgb_u8(218, 145, 81)),
+            base_experience_color: Mutable::new(Color::rgb_u8(224, 200, 80)),
+            job_experience_color: Mutable::new(Color::rgb_u8(150, 110, 220)),
             player_bar_width: MutableRange::new(85.0, 20.0, 300.0),
             enemy_bar_width: MutableRange::new(60.0, 20.0, 300.0),
             health_height: MutableRange::new(8.0, 2.0, 30.0),
             enemy_health_height: MutableRange::new(6.0, 2.0, 30.0),
             spell_point_height: MutableRange::new(4.0, 2.0, 30.0),
             activity_point_height: MutableRange::new(4.0, 2.0, 30.0),
+            experience_height: MutableRange::new(4.0, 2.0, 30.0),
             border_size: MutableRange::new(
                 ScreenSize { width: 2.0, height: 1.0 },
                 ScreenSize::default(),
                 ScreenSize::uniform(20.0),
             ),
             gap: MutableRange::new(1.0, 0.0, 10.0),
+            status_effect_icon_size: MutableRange::new(12.0, 4.0, 40.0),
+            buff_bar_icon_size: MutableRange::new(24.0, 8.0, 64.0),
+            buff_bar_offset: MutableRange::new(
+                ScreenPosition { left: 10.0, top: 10.0 },
+                ScreenPosition::default(),
+                ScreenPosition::uniform(500.0),
+            ),
+            buff_bar_text_color: Mutable::new(Color::monochrome_u8(255)),
         }
     }
 }
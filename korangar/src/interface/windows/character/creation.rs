@@ -1,26 +1,34 @@
-use derive_new::new;
-use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder};
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, FocusMode, InputFieldBuilder, PickList, Text};
 use korangar_interface::event::ClickAction;
 use korangar_interface::state::{PlainTrackedState, TrackedState, TrackedStateClone};
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
 use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::Sex;
 
 use crate::input::UserEvent;
 use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::CharacterSpritePreview;
 use crate::interface::layout::ScreenSize;
 use crate::interface::theme::InterfaceThemeKind;
 use crate::interface::windows::WindowCache;
+use crate::world::{CharacterSprites, DEFAULT_HAIR_COLOR, DEFAULT_HAIR_STYLE, DEFAULT_SEX, NOVICE_JOB_ID, SUMMONER_JOB_ID};
 
 const MINIMUM_NAME_LENGTH: usize = 4;
 const MAXIMUM_NAME_LENGTH: usize = 24;
+const MAXIMUM_HAIR_STYLE: u16 = 23;
+const MAXIMUM_HAIR_COLOR: u16 = 8;
 
-#[derive(new)]
 pub struct CharacterCreationWindow {
     slot: usize,
+    preview: PlainTrackedState<Option<CharacterSprites>>,
 }
 
 impl CharacterCreationWindow {
     pub const WINDOW_CLASS: &'static str = "character_creation";
+
+    pub fn new(slot: usize, preview: PlainTrackedState<Option<CharacterSprites>>) -> Self {
+        Self { slot, preview }
+    }
 }
 
 impl PrototypeWindow<InterfaceSettings> for CharacterCreationWindow {
@@ -35,22 +43,63 @@ impl PrototypeWindow<InterfaceSettings> for CharacterCreationWindow {
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
         let name = PlainTrackedState::<String>::default();
+        let sex = PlainTrackedState::new(DEFAULT_SEX);
+        let hair_style = PlainTrackedState::new(DEFAULT_HAIR_STYLE);
+        let hair_color = PlainTrackedState::new(DEFAULT_HAIR_COLOR);
+        let start_job = PlainTrackedState::new(NOVICE_JOB_ID);
 
         let selector = {
             let name = name.clone();
             move || name.get().len() >= MINIMUM_NAME_LENGTH
         };
 
+        let update_preview = {
+            let sex = sex.clone();
+            let hair_style = hair_style.clone();
+            let hair_color = hair_color.clone();
+            let start_job = start_job.clone();
+
+            move || {
+                vec![ClickAction::Custom(UserEvent::UpdateCharacterPreview {
+                    start_job: start_job.cloned(),
+                    sex: sex.cloned(),
+                    hair_style: hair_style.cloned(),
+                    hair_color: hair_color.cloned(),
+                })]
+            }
+        };
+
         let action = {
             let slot = self.slot;
             let name = name.clone();
+            let sex = sex.clone();
+            let hair_style = hair_style.clone();
+            let hair_color = hair_color.clone();
+            let start_job = start_job.clone();
 
-            move || vec![ClickAction::Custom(UserEvent::CreateCharacter(slot, name.cloned()))]
+            move || {
+                vec![ClickAction::Custom(UserEvent::CreateCharacter {
+                    slot,
+                    name: name.cloned(),
+                    hair_color: hair_color.cloned(),
+                    hair_style: hair_style.cloned(),
+                    start_job: start_job.cloned(),
+                    sex: sex.cloned(),
+                })]
+            }
         };
 
         let input_action = Box::new(move || vec![ClickAction::FocusNext(FocusMode::FocusNext)]);
 
+        let hair_style_options = (1..=MAXIMUM_HAIR_STYLE)
+            .map(|hair_style| (format!("Style {hair_style}"), hair_style))
+            .collect();
+        let hair_color_options = (0..=MAXIMUM_HAIR_COLOR)
+            .map(|hair_color| (format!("Color {hair_color}"), hair_color))
+            .collect();
+
         let elements = vec![
+            CharacterSpritePreview::new(self.preview.new_remote()).wrap(),
             InputFieldBuilder::new()
                 .with_state(name)
                 .with_ghost_text("Character name")
@@ -58,6 +107,34 @@ impl PrototypeWindow<InterfaceSettings> for CharacterCreationWindow {
                 .with_length(MAXIMUM_NAME_LENGTH)
                 .build()
                 .wrap(),
+            Text::default().with_text("Sex").with_width(dimension_bound!(40%)).wrap(),
+            PickList::default()
+                .with_options(vec![("Male", Sex::Male), ("Female", Sex::Female)])
+                .with_selected(sex)
+                .with_event(Box::new(update_preview.clone()))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+            Text::default().with_text("Hair style").with_width(dimension_bound!(40%)).wrap(),
+            PickList::default()
+                .with_options(hair_style_options)
+                .with_selected(hair_style)
+                .with_event(Box::new(update_preview.clone()))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+            Text::default().with_text("Hair color").with_width(dimension_bound!(40%)).wrap(),
+            PickList::default()
+                .with_options(hair_color_options)
+                .with_selected(hair_color)
+                .with_event(Box::new(update_preview.clone()))
+                .with_width(dimension_bound!(!))
+                .wrap(),
+            Text::default().with_text("Job").with_width(dimension_bound!(40%)).wrap(),
+            PickList::default()
+                .with_options(vec![("Novice", NOVICE_JOB_ID), ("Summoner", SUMMONER_JOB_ID)])
+                .with_selected(start_job)
+                .with_event(Box::new(update_preview))
+                .with_width(dimension_bound!(!))
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("done")
                 .with_disabled_selector(selector)
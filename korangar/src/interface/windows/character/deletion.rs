@@ -0,0 +1,140 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::CharacterId;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceThemeKind;
+use crate::interface::windows::WindowCache;
+
+const BIRTHDATE_LENGTH: usize = 6;
+
+/// Step of the delayed character deletion that needs to be confirmed.
+#[derive(Clone, Copy)]
+pub enum CharacterDeletionStage {
+    /// Start the waiting time before the character can be deleted.
+    Reserve,
+    /// Delete the character after the waiting time is over.
+    Accept,
+}
+
+pub struct CharacterDeletionWindow {
+    character_id: CharacterId,
+    character_name: String,
+    stage: CharacterDeletionStage,
+}
+
+impl CharacterDeletionWindow {
+    pub const WINDOW_CLASS: &'static str = "character_deletion";
+
+    pub fn new(character_id: CharacterId, character_name: String, stage: CharacterDeletionStage) -> Self {
+        Self {
+            character_id,
+            character_name,
+            stage,
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for CharacterDeletionWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let cancel_button = ButtonBuilder::new()
+            .with_text("cancel")
+            .with_event(Box::new(|| vec![ClickAction::CloseWindow]))
+            .with_width_bound(dimension_bound!(50%))
+            .build()
+            .wrap();
+
+        let elements = match self.stage {
+            CharacterDeletionStage::Reserve => vec![
+                Text::default()
+                    .with_text(format!(
+                        "Do you really want to delete ^ffaa00{}^000000? The character can be deleted once the waiting time is over.",
+                        self.character_name
+                    ))
+                    .wrap(),
+                cancel_button,
+                ButtonBuilder::new()
+                    .with_text("delete")
+                    .with_event(UserEvent::ReserveCharacterDeletion(self.character_id))
+                    .with_width_bound(dimension_bound!(!))
+                    .build()
+                    .wrap(),
+            ],
+            CharacterDeletionStage::Accept => {
+                let birthdate = PlainTrackedState::<String>::default();
+
+                let selector = {
+                    let birthdate = birthdate.clone();
+                    move || parse_birthdate(&birthdate.get()).is_some()
+                };
+
+                let action = {
+                    let character_id = self.character_id;
+                    let birthdate = birthdate.clone();
+
+                    move || {
+                        let Some(birthdate) = parse_birthdate(&birthdate.get()) else {
+                            return Vec::new();
+                        };
+
+                        vec![ClickAction::Custom(UserEvent::AcceptCharacterDeletion { character_id, birthdate })]
+                    }
+                };
+
+                vec![
+                    Text::default()
+                        .with_text(format!(
+                            "Enter your birthdate to delete ^ffaa00{}^000000. This can not be undone.",
+                            self.character_name
+                        ))
+                        .wrap(),
+                    InputFieldBuilder::new()
+                        .with_state(birthdate)
+                        .with_ghost_text("Birthdate (YYMMDD)")
+                        .with_enter_action(action.clone())
+                        .with_length(BIRTHDATE_LENGTH)
+                        .build()
+                        .wrap(),
+                    cancel_button,
+                    ButtonBuilder::new()
+                        .with_text("delete")
+                        .with_disabled_selector(selector)
+                        .with_event(Box::new(action))
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                ]
+            }
+        };
+
+        WindowBuilder::new()
+            .with_title("Delete Character".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .with_theme_kind(InterfaceThemeKind::Menu)
+            .build(window_cache, application, available_space)
+    }
+}
+
+fn parse_birthdate(birthdate: &str) -> Option<[u8; BIRTHDATE_LENGTH]> {
+    match birthdate.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => birthdate.as_bytes().try_into().ok(),
+        false => None,
+    }
+}
@@ -1,15 +1,25 @@
+mod achievements;
 mod creation;
+mod deletion;
 mod equipment;
 mod hotbar;
 mod inventory;
 mod overview;
+mod quests;
 mod selection;
 mod skill_tree;
+mod status;
+mod storage;
 
+pub use self::achievements::AchievementWindow;
 pub use self::creation::CharacterCreationWindow;
+pub use self::deletion::{CharacterDeletionStage, CharacterDeletionWindow};
 pub use self::equipment::EquipmentWindow;
 pub use self::hotbar::HotbarWindow;
 pub use self::inventory::InventoryWindow;
 pub use self::overview::CharacterOverviewWindow;
+pub use self::quests::QuestWindow;
 pub use self::selection::CharacterSelectionWindow;
 pub use self::skill_tree::SkillTreeWindow;
+pub use self::status::StatusWindow;
+pub use self::storage::StorageWindow;
@@ -38,6 +38,11 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
                 .with_event(UserEvent::OpenInventoryWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Status")
+                .with_event(UserEvent::OpenStatusWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Equipment")
                 .with_event(UserEvent::OpenEquipmentWindow)
@@ -48,11 +53,36 @@ impl PrototypeWindow<InterfaceSettings> for CharacterOverviewWindow {
                 .with_event(UserEvent::OpenSkillTreeWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Quests")
+                .with_event(UserEvent::OpenQuestWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Achievements")
+                .with_event(UserEvent::OpenAchievementWindow)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Friends")
                 .with_event(UserEvent::OpenFriendsWindow)
                 .build()
                 .wrap(),
+            ButtonBuilder::new()
+                .with_text("Party")
+                .with_event(UserEvent::OpenPartyWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Guild")
+                .with_event(UserEvent::OpenGuildWindow)
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Mail")
+                .with_event(UserEvent::OpenMailbox)
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Menu")
                 .with_event(UserEvent::OpenMenuWindow)
@@ -0,0 +1,43 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::QuestInformation;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::QuestContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct QuestWindow {
+    quests: PlainRemote<Vec<QuestInformation>>,
+}
+
+impl QuestWindow {
+    pub const WINDOW_CLASS: &'static str = "quests";
+}
+
+impl PrototypeWindow<InterfaceSettings> for QuestWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![QuestContainer::new(self.quests.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Quests".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ? < 80%))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
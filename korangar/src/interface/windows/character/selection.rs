@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
 use derive_new::new;
 use korangar_interface::elements::ElementWrap;
 use korangar_interface::size_bound;
 use korangar_interface::state::PlainRemote;
 use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
-use ragnarok_packets::CharacterInformation;
+use ragnarok_packets::{CharacterId, CharacterInformation};
 
 use crate::interface::application::InterfaceSettings;
 use crate::interface::elements::CharacterPreview;
@@ -15,6 +18,7 @@ use crate::interface::windows::WindowCache;
 pub struct CharacterSelectionWindow {
     characters: PlainRemote<Vec<CharacterInformation>>,
     move_request: PlainRemote<Option<usize>>,
+    deletion_dates: PlainRemote<HashMap<CharacterId, Instant>>,
     slot_count: usize,
 }
 
@@ -34,7 +38,15 @@ impl PrototypeWindow<InterfaceSettings> for CharacterSelectionWindow {
         available_space: ScreenSize,
     ) -> Window<InterfaceSettings> {
         let elements = (0..self.slot_count)
-            .map(|slot| CharacterPreview::new(self.characters.clone(), self.move_request.clone(), slot).wrap())
+            .map(|slot| {
+                CharacterPreview::new(
+                    self.characters.clone(),
+                    self.move_request.clone(),
+                    self.deletion_dates.clone(),
+                    slot,
+                )
+                .wrap()
+            })
             .collect();
 
         WindowBuilder::new()
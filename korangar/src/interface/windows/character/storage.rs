@@ -0,0 +1,51 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::InventoryItem;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::StorageContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+#[derive(new)]
+pub struct StorageWindow {
+    items: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+}
+
+impl StorageWindow {
+    pub const WINDOW_CLASS: &'static str = "storage";
+}
+
+impl PrototypeWindow<InterfaceSettings> for StorageWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            StorageContainer::new(self.items.clone()).wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseStorage)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Storage".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 400 < 500, ? < 80%))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
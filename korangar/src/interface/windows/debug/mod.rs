@@ -1,6 +1,7 @@
 mod commands;
 mod inspector;
 mod maps;
+mod network;
 mod packet;
 mod profiler;
 mod time;
@@ -8,6 +9,7 @@ mod time;
 pub use self::commands::CommandsWindow;
 pub use self::inspector::FrameInspectorWindow;
 pub use self::maps::MapsWindow;
+pub use self::network::NetworkStatisticsWindow;
 pub use self::packet::PacketWindow;
 pub use self::profiler::ProfilerWindow;
 pub use self::time::TimeWindow;
@@ -0,0 +1,78 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, PickList};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::NetworkStatisticsHandle;
+use ragnarok_packets::ServerType;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::NetworkStatisticsView;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+pub struct NetworkStatisticsWindow {
+    statistics: NetworkStatisticsHandle,
+    visible_server: PlainTrackedState<ServerType>,
+}
+
+impl NetworkStatisticsWindow {
+    pub const WINDOW_CLASS: &'static str = "network_statistics";
+
+    pub fn new(statistics: NetworkStatisticsHandle) -> Self {
+        Self {
+            statistics,
+            visible_server: PlainTrackedState::new(ServerType::Map),
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for NetworkStatisticsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let reset_action = {
+            let statistics = self.statistics.clone();
+
+            move || -> Vec<ClickAction<InterfaceSettings>> {
+                statistics.reset();
+                Vec::new()
+            }
+        };
+
+        let elements = vec![
+            PickList::default()
+                .with_options(vec![
+                    ("Login server", ServerType::Login),
+                    ("Character server", ServerType::Character),
+                    ("Map server", ServerType::Map),
+                ])
+                .with_selected(self.visible_server.clone())
+                .with_width(dimension_bound!(150))
+                .with_event(Box::new(Vec::new))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Reset")
+                .with_event(Box::new(reset_action))
+                .with_width_bound(dimension_bound!(150))
+                .build()
+                .wrap(),
+            NetworkStatisticsView::new(self.statistics.clone(), self.visible_server.new_remote()).wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Network statistics".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(300 > 500 < 900, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,50 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::{UserEvent, EMOTION_COMMANDS};
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Palette of all emotions that the player can show. Clicking an emotion has
+/// the same effect as typing its chat command.
+#[derive(Default)]
+pub struct EmotionsWindow;
+
+impl EmotionsWindow {
+    pub const WINDOW_CLASS: &'static str = "emotions";
+}
+
+impl PrototypeWindow<InterfaceSettings> for EmotionsWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = EMOTION_COMMANDS
+            .iter()
+            .map(|&(command, emotion)| {
+                ButtonBuilder::new()
+                    .with_text(command)
+                    .with_event(UserEvent::SendEmotion(emotion))
+                    .with_width_bound(dimension_bound!(20%))
+                    .build()
+                    .wrap()
+            })
+            .collect();
+
+        WindowBuilder::new()
+            .with_title("Emotions".to_string())
+            .with_class(Self::WINDOW_CLASS.to_string())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -93,6 +93,13 @@ impl PrototypeWindow<InterfaceSettings> for MenuWindow {
                 .with_foreground_color(|theme: &InterfaceTheme| theme.button.debug_foreground_color.get())
                 .build()
                 .wrap(),
+            #[cfg(feature = "debug")]
+            ButtonBuilder::new()
+                .with_text("Network statistics")
+                .with_event(UserEvent::OpenNetworkStatisticsWindow)
+                .with_foreground_color(|theme: &InterfaceTheme| theme.button.debug_foreground_color.get())
+                .build()
+                .wrap(),
             ButtonBuilder::new()
                 .with_text("Log out")
                 .with_event(UserEvent::LogOut)
@@ -1,9 +1,13 @@
 mod chat;
 mod dialog;
+mod emotion;
 mod error;
 mod menu;
+mod whisper;
 
 pub use self::chat::{ChatMessage, ChatWindow};
 pub use self::dialog::DialogWindow;
+pub use self::emotion::EmotionsWindow;
 pub use self::error::ErrorWindow;
 pub use self::menu::MenuWindow;
+pub use self::whisper::WhisperWindow;
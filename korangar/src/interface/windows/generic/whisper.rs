@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use super::ChatMessage;
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::ChatBuilder;
+use crate::interface::layout::ScreenSize;
+use crate::interface::theme::InterfaceTheme;
+use crate::interface::windows::WindowCache;
+use crate::loaders::FontLoader;
+
+/// Private conversation with a single player.
+pub struct WhisperWindow {
+    name: String,
+    window_class: String,
+    messages: PlainRemote<Vec<ChatMessage>>,
+    font_loader: Rc<RefCell<FontLoader>>,
+}
+
+impl WhisperWindow {
+    pub const WINDOW_CLASS_PREFIX: &'static str = "whisper_";
+
+    pub fn new(name: String, messages: PlainRemote<Vec<ChatMessage>>, font_loader: Rc<RefCell<FontLoader>>) -> Self {
+        // Every conversation gets its own class so we can have multiple of them open at
+        // the same time but never more than one per player.
+        let window_class = format!("{}{}", Self::WINDOW_CLASS_PREFIX, name);
+
+        Self {
+            name,
+            window_class,
+            messages,
+            font_loader,
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for WhisperWindow {
+    fn window_class(&self) -> Option<&str> {
+        Some(&self.window_class)
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let input_text = PlainTrackedState::<String>::default();
+
+        let button_selector = {
+            let input_text = input_text.clone();
+
+            move || !input_text.get().is_empty()
+        };
+
+        let button_action = {
+            let mut input_text = input_text.clone();
+            let name = self.name.clone();
+
+            move || {
+                let message = input_text.take();
+                vec![ClickAction::Custom(UserEvent::SendWhisper {
+                    name: name.clone(),
+                    message,
+                })]
+            }
+        };
+
+        let input_action = {
+            let mut input_text = input_text.clone();
+            let name = self.name.clone();
+
+            Box::new(move || {
+                let message = input_text.take();
+
+                (!message.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::SendWhisper {
+                        name: name.clone(),
+                        message,
+                    })])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(input_text)
+                .with_ghost_text("Write message")
+                .with_enter_action(input_action)
+                .with_length(80)
+                .with_width_bound(dimension_bound!(75%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_disabled_selector(button_selector)
+                .with_event(Box::new(button_action))
+                .with_width_bound(dimension_bound!(25%))
+                .build()
+                .wrap(),
+            ScrollView::new(
+                vec![
+                    ChatBuilder::new()
+                        .with_messages(self.messages.clone())
+                        .with_font_loader(self.font_loader.clone())
+                        .build()
+                        .wrap(),
+                ],
+                size_bound!(100%, !),
+            )
+            .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title(self.name.clone())
+            .with_class(self.window_class.clone())
+            .with_size_bound(size_bound!(200 > 300 < 600, 100 > 150 < 400))
+            .with_background_color(Box::new(|theme: &InterfaceTheme| theme.chat.background_color.get()))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,80 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::GuildContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::GuildDetails;
+
+#[derive(new)]
+pub struct GuildWindow {
+    details: PlainRemote<GuildDetails>,
+}
+
+impl GuildWindow {
+    pub const WINDOW_CLASS: &'static str = "guild";
+}
+
+impl PrototypeWindow<InterfaceSettings> for GuildWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let character_name = PlainTrackedState::<String>::default();
+
+        let invite_action = {
+            let mut character_name = character_name.clone();
+
+            Box::new(move || {
+                let taken_string = character_name.take();
+
+                (!taken_string.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::InviteToGuild(taken_string))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            GuildContainer::new(self.details.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(character_name)
+                .with_ghost_text("Name")
+                .with_enter_action(invite_action.clone())
+                .with_length(24)
+                .with_width_bound(dimension_bound!(80%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Invite")
+                .with_event(invite_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Leave guild")
+                .with_event(UserEvent::LeaveGuild)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Guild".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(200 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,5 @@
+mod invitation;
+mod list;
+
+pub use self::invitation::GuildInvitationWindow;
+pub use self::list::GuildWindow;
@@ -0,0 +1,61 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, ScrollView};
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailboxContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::MailList;
+
+#[derive(new)]
+pub struct MailboxWindow {
+    list: PlainRemote<MailList>,
+}
+
+impl MailboxWindow {
+    pub const WINDOW_CLASS: &'static str = "mailbox";
+}
+
+impl PrototypeWindow<InterfaceSettings> for MailboxWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            ScrollView::new(
+                vec![MailboxContainer::new(self.list.clone()).wrap()],
+                size_bound!(100%, ? < 60%),
+            )
+            .wrap(),
+            ButtonBuilder::new()
+                .with_text("Write")
+                .with_event(UserEvent::OpenWriteMail)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Close")
+                .with_event(UserEvent::CloseMailbox)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Mailbox".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
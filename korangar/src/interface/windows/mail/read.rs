@@ -0,0 +1,44 @@
+use derive_new::new;
+use korangar_interface::elements::ElementWrap;
+use korangar_interface::size_bound;
+use korangar_interface::state::PlainRemote;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_networking::Mail;
+
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+#[derive(new)]
+pub struct MailWindow {
+    mail: PlainRemote<Option<Mail<ResourceMetadata>>>,
+}
+
+impl MailWindow {
+    pub const WINDOW_CLASS: &'static str = "mail";
+}
+
+impl PrototypeWindow<InterfaceSettings> for MailWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![MailContainer::new(self.mail.clone()).wrap()];
+
+        WindowBuilder::new()
+            .with_title("Mail".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,115 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::InventoryItem;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::MailAttachmentContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+#[derive(new)]
+pub struct WriteMailWindow {
+    attachments: PlainRemote<Vec<InventoryItem<ResourceMetadata>>>,
+}
+
+impl WriteMailWindow {
+    pub const WINDOW_CLASS: &'static str = "write_mail";
+}
+
+impl PrototypeWindow<InterfaceSettings> for WriteMailWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let receiver_name = PlainTrackedState::<String>::default();
+        let title = PlainTrackedState::<String>::default();
+        let text = PlainTrackedState::<String>::default();
+        let zeny = PlainTrackedState::<String>::default();
+
+        let send_action = {
+            let receiver_name = receiver_name.clone();
+            let title = title.clone();
+            let text = text.clone();
+            let zeny = zeny.clone();
+
+            Box::new(move || {
+                let receiver_name = receiver_name.get().clone();
+                let title = title.get().clone();
+
+                if receiver_name.is_empty() || title.is_empty() {
+                    return Vec::new();
+                }
+
+                let zeny = match zeny.get().as_str() {
+                    "" => 0,
+                    amount => match amount.parse::<u64>() {
+                        Ok(amount) => amount,
+                        Err(_) => return Vec::new(),
+                    },
+                };
+
+                vec![ClickAction::Custom(UserEvent::SendMail {
+                    receiver_name,
+                    title,
+                    text: text.get().clone(),
+                    zeny,
+                })]
+            })
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(receiver_name)
+                .with_ghost_text("Receiver")
+                .with_length(24)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(title)
+                .with_ghost_text("Title")
+                .with_length(40)
+                .build()
+                .wrap(),
+            InputFieldBuilder::new()
+                .with_state(text)
+                .with_ghost_text("Text")
+                .with_length(200)
+                .build()
+                .wrap(),
+            MailAttachmentContainer::new(self.attachments.clone()).wrap(),
+            InputFieldBuilder::new()
+                .with_state(zeny)
+                .with_ghost_text("Zeny")
+                .with_length(10)
+                .with_width_bound(dimension_bound!(60%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Send")
+                .with_event(send_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Write mail".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 300 < 400, ?))
+            .with_elements(elements)
+            .closable()
+            .build(window_cache, application, available_space)
+    }
+}
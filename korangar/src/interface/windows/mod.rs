@@ -5,9 +5,14 @@ mod character;
 mod debug;
 mod friends;
 mod generic;
+mod guild;
+mod mail;
 mod mutable;
+mod party;
 mod settings;
 mod shop;
+mod trade;
+mod vending;
 
 pub use self::account::*;
 pub use self::cache::WindowCache;
@@ -16,6 +21,11 @@ pub use self::character::*;
 pub use self::debug::*;
 pub use self::friends::*;
 pub use self::generic::*;
+pub use self::guild::*;
+pub use self::mail::*;
 pub use self::mutable::*;
+pub use self::party::*;
 pub use self::settings::*;
 pub use self::shop::*;
+pub use self::trade::*;
+pub use self::vending::*;
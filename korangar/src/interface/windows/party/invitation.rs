@@ -0,0 +1,60 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use ragnarok_packets::PartyId;
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+#[derive(new)]
+pub struct PartyInvitationWindow {
+    party_id: PartyId,
+    party_name: String,
+}
+
+impl PartyInvitationWindow {
+    pub const WINDOW_CLASS: &'static str = "party_invitation";
+}
+
+impl PrototypeWindow<InterfaceSettings> for PartyInvitationWindow {
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            Text::default()
+                .with_text(format!(
+                    "You have been invited to join the party ^ffaa00{}^000000",
+                    self.party_name
+                ))
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("reject")
+                .with_event(UserEvent::RejectPartyInvitation(self.party_id))
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("accept")
+                .with_event(UserEvent::AcceptPartyInvitation(self.party_id))
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Party invitation".to_string())
+            // We give the builder a class but we don't implement the `window_class` method
+            // of the trait. This way we can open multiple windows of this type but we can still
+            // close them with the class name.
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(250 > 250 < 250, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
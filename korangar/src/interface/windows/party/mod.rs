@@ -0,0 +1,5 @@
+mod invitation;
+mod list;
+
+pub use self::invitation::PartyInvitationWindow;
+pub use self::list::PartyWindow;
@@ -0,0 +1,5 @@
+mod request;
+mod window;
+
+pub use self::request::TradeRequestWindow;
+pub use self::window::TradeWindow;
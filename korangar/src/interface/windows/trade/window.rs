@@ -0,0 +1,99 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedStateTake};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::elements::TradeContainer;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::inventory::TradeOffer;
+
+#[derive(new)]
+pub struct TradeWindow {
+    partner_name: String,
+    own_offer: PlainRemote<TradeOffer>,
+    partner_offer: PlainRemote<TradeOffer>,
+}
+
+impl TradeWindow {
+    pub const WINDOW_CLASS: &'static str = "trade";
+}
+
+impl PrototypeWindow<InterfaceSettings> for TradeWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let zeny = PlainTrackedState::<String>::default();
+
+        let offer_zeny_action = {
+            let mut zeny = zeny.clone();
+
+            Box::new(move || {
+                zeny.take()
+                    .parse::<u32>()
+                    .map(|amount| vec![ClickAction::Custom(UserEvent::OfferTradeZeny(amount))])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            Text::default()
+                .with_text(self.partner_name.clone())
+                .with_width(dimension_bound!(50%))
+                .wrap(),
+            Text::default().with_text("You").with_width(dimension_bound!(!)).wrap(),
+            TradeContainer::new(self.partner_offer.clone(), false).wrap(),
+            TradeContainer::new(self.own_offer.clone(), true).wrap(),
+            InputFieldBuilder::new()
+                .with_state(zeny)
+                .with_ghost_text("Zeny")
+                .with_enter_action(offer_zeny_action.clone())
+                .with_length(10)
+                .with_width_bound(dimension_bound!(80%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Offer")
+                .with_event(offer_zeny_action)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Lock")
+                .with_event(UserEvent::LockTrade)
+                .with_width_bound(dimension_bound!(33%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Trade")
+                .with_event(UserEvent::CommitTrade)
+                .with_width_bound(dimension_bound!(33%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(UserEvent::CancelTrade)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Trade".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(300 > 350 < 450, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,5 @@
+mod setup;
+mod shop;
+
+pub use self::setup::VendingSetupWindow;
+pub use self::shop::VendingShopWindow;
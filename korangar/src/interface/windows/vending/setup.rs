@@ -0,0 +1,144 @@
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, InputFieldBuilder, ScrollView, Text};
+use korangar_interface::event::ClickAction;
+use korangar_interface::state::{PlainTrackedState, TrackedState};
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+use korangar_interface::{dimension_bound, size_bound};
+use korangar_networking::{InventoryItem, InventoryItemDetails};
+use ragnarok_packets::{Price, VendingShopItemInformation};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+use crate::loaders::ResourceMetadata;
+
+/// Lets the player set a price for the items in the cart before opening a
+/// vending shop. Items without a valid price are not put up for sale.
+pub struct VendingSetupWindow {
+    items: Vec<InventoryItem<ResourceMetadata>>,
+    maximum_items: usize,
+}
+
+impl VendingSetupWindow {
+    pub const WINDOW_CLASS: &'static str = "vending_setup";
+
+    pub fn new(items: Vec<InventoryItem<ResourceMetadata>>, maximum_items: u16) -> Self {
+        Self {
+            items,
+            maximum_items: maximum_items as usize,
+        }
+    }
+}
+
+impl PrototypeWindow<InterfaceSettings> for VendingSetupWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let title = PlainTrackedState::<String>::default();
+        let items: Vec<(VendingShopItemInformation, PlainTrackedState<String>)> = self
+            .items
+            .iter()
+            .map(|item| {
+                let amount = match item.details {
+                    InventoryItemDetails::Regular { amount, .. } => amount,
+                    InventoryItemDetails::Equippable { .. } => 1,
+                };
+
+                let information = VendingShopItemInformation {
+                    index: item.index,
+                    amount,
+                    price: Price(0),
+                };
+
+                (information, PlainTrackedState::default())
+            })
+            .collect();
+
+        let item_elements = self
+            .items
+            .iter()
+            .zip(items.iter())
+            .flat_map(|(item, (information, price))| {
+                [
+                    Text::default()
+                        .with_text(format!("{} x{}", item.metadata.name, information.amount))
+                        .with_width(dimension_bound!(60%))
+                        .wrap(),
+                    InputFieldBuilder::new()
+                        .with_state(price.clone())
+                        .with_ghost_text("Price")
+                        .with_length(9)
+                        .with_width_bound(dimension_bound!(!))
+                        .build()
+                        .wrap(),
+                ]
+            })
+            .collect();
+
+        let open_action = {
+            let title = title.clone();
+            let maximum_items = self.maximum_items;
+
+            Box::new(move || {
+                let title = title.get().clone();
+
+                if title.is_empty() {
+                    return Vec::new();
+                }
+
+                let items: Vec<VendingShopItemInformation> = items
+                    .iter()
+                    .filter_map(|(information, price)| {
+                        let price = price.get().parse::<u32>().ok().filter(|price| *price > 0)?;
+
+                        Some(VendingShopItemInformation {
+                            price: Price(price),
+                            ..information.clone()
+                        })
+                    })
+                    .take(maximum_items)
+                    .collect();
+
+                (!items.is_empty())
+                    .then_some(vec![ClickAction::Custom(UserEvent::OpenVendingShop { title, items })])
+                    .unwrap_or_default()
+            })
+        };
+
+        let elements = vec![
+            InputFieldBuilder::new()
+                .with_state(title)
+                .with_ghost_text("Shop title")
+                .with_length(79)
+                .build()
+                .wrap(),
+            ScrollView::new(item_elements, size_bound!(100%, ? < 60%)).wrap(),
+            ButtonBuilder::new()
+                .with_text("Open")
+                .with_event(open_action)
+                .with_width_bound(dimension_bound!(50%))
+                .build()
+                .wrap(),
+            ButtonBuilder::new()
+                .with_text("Cancel")
+                .with_event(UserEvent::CancelVendingSetup)
+                .with_width_bound(dimension_bound!(!))
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title(format!("Vending (up to {} items)", self.maximum_items))
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(300 > 350 < 450, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
@@ -0,0 +1,48 @@
+use derive_new::new;
+use korangar_interface::elements::{ButtonBuilder, ElementWrap, Text};
+use korangar_interface::size_bound;
+use korangar_interface::windows::{PrototypeWindow, Window, WindowBuilder};
+
+use crate::input::UserEvent;
+use crate::interface::application::InterfaceSettings;
+use crate::interface::layout::ScreenSize;
+use crate::interface::windows::WindowCache;
+
+/// Shown while the player has an open vending shop.
+#[derive(new)]
+pub struct VendingShopWindow {
+    title: String,
+}
+
+impl VendingShopWindow {
+    pub const WINDOW_CLASS: &'static str = "vending_shop";
+}
+
+impl PrototypeWindow<InterfaceSettings> for VendingShopWindow {
+    fn window_class(&self) -> Option<&str> {
+        Self::WINDOW_CLASS.into()
+    }
+
+    fn to_window(
+        &self,
+        window_cache: &WindowCache,
+        application: &InterfaceSettings,
+        available_space: ScreenSize,
+    ) -> Window<InterfaceSettings> {
+        let elements = vec![
+            Text::default().with_text(self.title.clone()).wrap(),
+            ButtonBuilder::new()
+                .with_text("Close shop")
+                .with_event(UserEvent::CloseVendingShop)
+                .build()
+                .wrap(),
+        ];
+
+        WindowBuilder::new()
+            .with_title("Vending".to_string())
+            .with_class(Self::WINDOW_CLASS.to_owned())
+            .with_size_bound(size_bound!(200 > 250 < 350, ?))
+            .with_elements(elements)
+            .build(window_cache, application, available_space)
+    }
+}
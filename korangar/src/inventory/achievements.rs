@@ -0,0 +1,103 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::{Achievement, AchievementSummary};
+
+use crate::loaders::{AchievementMetadata, ScriptLoader};
+
+#[derive(Clone)]
+pub struct AchievementEntry {
+    pub achievement: Achievement,
+    /// `None` if the client has no information about the achievement.
+    pub metadata: Option<AchievementMetadata>,
+}
+
+impl AchievementEntry {
+    pub fn get_title(&self) -> String {
+        self.metadata
+            .as_ref()
+            .map(|metadata| metadata.title.clone())
+            .unwrap_or_else(|| format!("Achievement {}", self.achievement.achievement_id))
+    }
+
+    pub fn get_category(&self) -> u32 {
+        self.metadata.as_ref().map(|metadata| metadata.category).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct AchievementList {
+    pub summary: AchievementSummary,
+    pub achievements: Vec<AchievementEntry>,
+}
+
+#[derive(Default)]
+pub struct AchievementStore {
+    list: PlainTrackedState<AchievementList>,
+}
+
+impl AchievementStore {
+    pub fn fill(&mut self, script_loader: &ScriptLoader, summary: AchievementSummary, achievements: Vec<Achievement>) {
+        let achievements = achievements
+            .into_iter()
+            .map(|achievement| AchievementEntry {
+                metadata: script_loader.get_achievement_metadata(achievement.achievement_id),
+                achievement,
+            })
+            .collect();
+
+        self.list.set(AchievementList { summary, achievements });
+    }
+
+    /// Update the progress of a single achievement. Returns the entry if the
+    /// achievement was completed by this update.
+    pub fn update(
+        &mut self,
+        script_loader: &ScriptLoader,
+        summary: AchievementSummary,
+        achievement: Achievement,
+    ) -> Option<AchievementEntry> {
+        self.list.mutate(|list| {
+            list.summary = summary;
+
+            let achievement_id = achievement.achievement_id;
+            let is_completed = achievement.is_completed;
+
+            match list
+                .achievements
+                .iter_mut()
+                .find(|entry| entry.achievement.achievement_id == achievement_id)
+            {
+                Some(entry) => {
+                    let was_completed = entry.achievement.is_completed;
+                    entry.achievement = achievement;
+
+                    (is_completed && !was_completed).then(|| entry.clone())
+                }
+                None => {
+                    let entry = AchievementEntry {
+                        metadata: script_loader.get_achievement_metadata(achievement_id),
+                        achievement,
+                    };
+                    list.achievements.push(entry.clone());
+
+                    is_completed.then_some(entry)
+                }
+            }
+        })
+    }
+
+    pub fn set_rewarded(&mut self, achievement_id: u32) {
+        self.list.mutate(|list| {
+            if let Some(entry) = list
+                .achievements
+                .iter_mut()
+                .find(|entry| entry.achievement.achievement_id == achievement_id)
+            {
+                entry.achievement.is_rewarded = true;
+            }
+        });
+    }
+
+    pub fn get_remote(&self) -> PlainRemote<AchievementList> {
+        self.list.new_remote()
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt, ValueState};
+use korangar_networking::{GuildInformation, GuildMember};
+use ragnarok_packets::{AccountId, GuildId, GuildPositionName};
+use vulkano::image::view::ImageView;
+
+#[derive(Clone, Default)]
+pub struct GuildDetails {
+    pub guild_id: Option<GuildId>,
+    pub guild_name: String,
+    pub is_master: bool,
+    pub information: Option<GuildInformation>,
+    pub members: Vec<GuildMember>,
+    pub positions: HashMap<u32, String>,
+    pub notice: Option<(String, String)>,
+    pub emblem: Option<Arc<ImageView>>,
+}
+
+impl GuildDetails {
+    pub fn get_position_name(&self, position_id: u32) -> Option<&str> {
+        self.positions.get(&position_id).map(String::as_str)
+    }
+}
+
+#[derive(Default)]
+pub struct GuildRoster {
+    details: PlainTrackedState<GuildDetails>,
+}
+
+impl GuildRoster {
+    pub fn set_guild(&mut self, guild_id: GuildId, guild_name: String, is_master: bool) {
+        self.details.mutate(|details| {
+            details.guild_id = Some(guild_id);
+            details.guild_name = guild_name;
+            details.is_master = is_master;
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.details.set(GuildDetails::default());
+    }
+
+    pub fn get_guild_id(&self) -> Option<GuildId> {
+        self.details.get().guild_id
+    }
+
+    pub fn set_information(&mut self, information: GuildInformation) {
+        self.details.mutate(|details| {
+            details.guild_name = information.name.clone();
+            details.information = Some(information);
+        });
+    }
+
+    pub fn set_members(&mut self, members: Vec<GuildMember>) {
+        self.details.mutate(|details| details.members = members);
+    }
+
+    pub fn set_positions(&mut self, positions: Vec<GuildPositionName>) {
+        self.details.mutate(|details| {
+            details.positions = positions
+                .into_iter()
+                .map(|position| (position.position_id, position.name))
+                .collect();
+        });
+    }
+
+    pub fn set_notice(&mut self, subject: String, notice: String) {
+        self.details.mutate(|details| details.notice = Some((subject, notice)));
+    }
+
+    pub fn set_emblem(&mut self, emblem: Arc<ImageView>) {
+        self.details.mutate(|details| details.emblem = Some(emblem));
+    }
+
+    pub fn set_member_online(&mut self, account_id: AccountId, is_online: bool) {
+        self.details.with_mut(|details| {
+            let Some(member) = details.members.iter_mut().find(|member| member.account_id == account_id) else {
+                return ValueState::Unchanged(());
+            };
+
+            member.is_online = is_online;
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn remove_member(&mut self, name: &str) {
+        self.details.with_mut(|details| {
+            let previous_length = details.members.len();
+            details.members.retain(|member| member.name != name);
+
+            match details.members.len() != previous_length {
+                true => ValueState::Mutated(()),
+                false => ValueState::Unchanged(()),
+            }
+        });
+    }
+
+    pub fn find_member(&self, account_id: AccountId) -> Option<GuildMember> {
+        self.details
+            .get()
+            .members
+            .iter()
+            .find(|member| member.account_id == account_id)
+            .cloned()
+    }
+
+    pub fn get_details(&self) -> PlainRemote<GuildDetails> {
+        self.details.new_remote()
+    }
+}
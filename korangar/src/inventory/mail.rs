@@ -0,0 +1,135 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt, TrackedStateVec};
+use korangar_networking::{InventoryItem, Mail, MailPreview};
+use ragnarok_packets::{CharacterId, InventoryIndex, MailId};
+
+use crate::loaders::ResourceMetadata;
+
+#[derive(Clone, Default)]
+pub struct MailList {
+    pub mails: Vec<MailPreview>,
+    pub unread_count: usize,
+    /// `false` if there are more mails that can be requested from the server.
+    pub is_end: bool,
+}
+
+/// A mail that was written by the player and is waiting for the server to
+/// look up the receiver.
+pub struct PendingMail {
+    pub receiver_name: String,
+    pub title: String,
+    pub text: String,
+    pub zeny: u64,
+}
+
+/// State of the RODEX mailbox.
+#[derive(Default)]
+pub struct Mailbox {
+    list: PlainTrackedState<MailList>,
+    opened_mail: PlainTrackedState<Option<Mail<ResourceMetadata>>>,
+    attachments: PlainTrackedState<Vec<InventoryItem<ResourceMetadata>>>,
+    pending_mail: Option<PendingMail>,
+}
+
+impl Mailbox {
+    pub fn clear(&mut self) {
+        self.list.set(MailList::default());
+        self.opened_mail.set(None);
+    }
+
+    pub fn add_mails(&mut self, mails: Vec<MailPreview>, is_end: bool) {
+        self.list.mutate(|list| {
+            list.mails.extend(mails);
+            list.is_end = is_end;
+        });
+    }
+
+    pub fn set_unread_count(&mut self, count: usize) {
+        self.list.mutate(|list| list.unread_count = count);
+    }
+
+    /// The oldest mail that was received so far, used to request more mails.
+    pub fn get_last_mail(&self) -> Option<MailPreview> {
+        self.list.get().mails.last().cloned()
+    }
+
+    pub fn open_mail(&mut self, mail: Mail<ResourceMetadata>) {
+        let mail_id = mail.mail_id;
+
+        self.list.mutate(|list| {
+            if let Some(preview) = list.mails.iter_mut().find(|preview| preview.mail_id == mail_id && !preview.is_read) {
+                preview.is_read = true;
+                list.unread_count = list.unread_count.saturating_sub(1);
+            }
+        });
+        self.opened_mail.set(Some(mail));
+    }
+
+    pub fn remove_mail(&mut self, mail_id: MailId) {
+        self.list.mutate(|list| list.mails.retain(|mail| mail.mail_id != mail_id));
+
+        if self.opened_mail.get().as_ref().is_some_and(|mail| mail.mail_id == mail_id) {
+            self.opened_mail.set(None);
+        }
+    }
+
+    pub fn zeny_taken(&mut self, mail_id: MailId) {
+        self.list.mutate(|list| {
+            if let Some(preview) = list.mails.iter_mut().find(|preview| preview.mail_id == mail_id) {
+                preview.has_zeny = false;
+            }
+        });
+        self.opened_mail.mutate(|mail| {
+            if let Some(mail) = mail.as_mut().filter(|mail| mail.mail_id == mail_id) {
+                mail.zeny = 0;
+            }
+        });
+    }
+
+    pub fn items_taken(&mut self, mail_id: MailId) {
+        self.list.mutate(|list| {
+            if let Some(preview) = list.mails.iter_mut().find(|preview| preview.mail_id == mail_id) {
+                preview.has_items = false;
+            }
+        });
+        self.opened_mail.mutate(|mail| {
+            if let Some(mail) = mail.as_mut().filter(|mail| mail.mail_id == mail_id) {
+                mail.items.clear();
+            }
+        });
+    }
+
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    pub fn add_attachment(&mut self, item: InventoryItem<ResourceMetadata>) {
+        self.attachments.push(item);
+    }
+
+    pub fn remove_attachment(&mut self, index: InventoryIndex) {
+        self.attachments.retain(|item| item.index != index);
+    }
+
+    pub fn set_pending_mail(&mut self, pending_mail: PendingMail) {
+        self.pending_mail = Some(pending_mail);
+    }
+
+    /// Take the mail that is waiting for the receiver lookup. Returns `None` if
+    /// the receiver doesn't exist or no mail is waiting.
+    pub fn take_pending_mail(&mut self, character_id: Option<CharacterId>) -> Option<(PendingMail, CharacterId)> {
+        let pending_mail = self.pending_mail.take()?;
+        character_id.map(|character_id| (pending_mail, character_id))
+    }
+
+    pub fn list_remote(&self) -> PlainRemote<MailList> {
+        self.list.new_remote()
+    }
+
+    pub fn opened_mail_remote(&self) -> PlainRemote<Option<Mail<ResourceMetadata>>> {
+        self.opened_mail.new_remote()
+    }
+
+    pub fn attachments_remote(&self) -> PlainRemote<Vec<InventoryItem<ResourceMetadata>>> {
+        self.attachments.new_remote()
+    }
+}
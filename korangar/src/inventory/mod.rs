@@ -1,5 +1,12 @@
+mod achievements;
+mod guild;
 mod hotbar;
+mod mail;
+mod quests;
 mod skills;
+mod status;
+mod trade;
+mod vending;
 
 use std::cell::Ref;
 
@@ -7,8 +14,15 @@ use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, Tr
 use korangar_networking::{InventoryItem, InventoryItemDetails, NoMetadata};
 use ragnarok_packets::{EquipPosition, InventoryIndex};
 
+pub use self::achievements::{AchievementEntry, AchievementList, AchievementStore};
+pub use self::guild::{GuildDetails, GuildRoster};
 pub use self::hotbar::Hotbar;
+pub use self::mail::{MailList, Mailbox, PendingMail};
+pub use self::quests::QuestJournal;
 pub use self::skills::{Skill, SkillTree};
+pub use self::status::{Attribute, CharacterStatus, StatusTracker};
+pub use self::trade::{Trade, TradeOffer};
+pub use self::vending::VendingShop;
 use crate::loaders::{GameFileLoader, ResourceMetadata, ScriptLoader, TextureLoader};
 
 #[derive(Default)]
@@ -77,6 +91,22 @@ impl Inventory {
         });
     }
 
+    pub fn update_item_amount(&mut self, index: InventoryIndex, new_amount: u16) {
+        self.items.with_mut(|items| {
+            let Some(position) = items.iter().position(|item| item.index == index) else {
+                return ValueState::Unchanged(());
+            };
+
+            if new_amount == 0 {
+                items.remove(position);
+            } else if let InventoryItemDetails::Regular { amount, .. } = &mut items[position].details {
+                *amount = new_amount;
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
     pub fn update_equipped_position(&mut self, index: InventoryIndex, new_equipped_position: EquipPosition) {
         self.items.mutate(|items| {
             let item = items.iter_mut().find(|item| item.index == index).unwrap();
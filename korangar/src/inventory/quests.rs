@@ -0,0 +1,70 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, ValueState};
+use korangar_networking::{QuestInformation, QuestObjective};
+use ragnarok_packets::HuntingObjective;
+
+#[derive(Default)]
+pub struct QuestJournal {
+    quests: PlainTrackedState<Vec<QuestInformation>>,
+}
+
+impl QuestJournal {
+    pub fn fill(&mut self, quests: Vec<QuestInformation>) {
+        self.quests.set(quests);
+    }
+
+    pub fn add_quest(&mut self, quest: QuestInformation) {
+        self.quests.with_mut(|quests| {
+            quests.retain(|existing_quest| existing_quest.quest_id != quest.quest_id);
+            quests.push(quest);
+
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn remove_quest(&mut self, quest_id: u32) {
+        self.quests.with_mut(|quests| {
+            let previous_length = quests.len();
+            quests.retain(|quest| quest.quest_id != quest_id);
+
+            match quests.len() != previous_length {
+                true => ValueState::Mutated(()),
+                false => ValueState::Unchanged(()),
+            }
+        });
+    }
+
+    /// Update the kill counts of the journal and return every objective that
+    /// made progress.
+    pub fn update_objectives(&mut self, hunting_objectives: Vec<HuntingObjective>) -> Vec<QuestObjective> {
+        self.quests.with_mut(|quests| {
+            let mut updated_objectives = Vec::new();
+
+            for hunting_objective in hunting_objectives {
+                let objective = quests
+                    .iter_mut()
+                    .flat_map(|quest| quest.objectives.iter_mut())
+                    .find(|objective| objective.hunt_identification == hunting_objective.hunt_identification);
+
+                if let Some(objective) = objective {
+                    let made_progress = objective.current_count != hunting_objective.current_count;
+
+                    objective.current_count = hunting_objective.current_count;
+                    objective.total_count = hunting_objective.total_count;
+
+                    if made_progress {
+                        updated_objectives.push(objective.clone());
+                    }
+                }
+            }
+
+            match updated_objectives.is_empty() {
+                true => ValueState::Unchanged(updated_objectives),
+                false => ValueState::Mutated(updated_objectives),
+            }
+        })
+    }
+
+    pub fn get_quests(&self) -> PlainRemote<Vec<QuestInformation>> {
+        self.quests.new_remote()
+    }
+}
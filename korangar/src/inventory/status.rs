@@ -0,0 +1,100 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, ValueState};
+use ragnarok_packets::{StatusIncreaseType, StatusType};
+
+#[derive(Debug, Clone, Default)]
+pub struct Attribute {
+    pub base: u32,
+    pub bonus: u32,
+    /// Status points required to raise the base value by one.
+    pub cost: u8,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CharacterStatus {
+    pub status_points: u32,
+    pub strength: Attribute,
+    pub agility: Attribute,
+    pub vitality: Attribute,
+    pub intelligence: Attribute,
+    pub dexterity: Attribute,
+    pub luck: Attribute,
+    pub attack: u32,
+    pub bonus_attack: u32,
+    pub magic_attack: u32,
+    pub bonus_magic_attack: u32,
+    pub defense: u32,
+    pub bonus_defense: u32,
+    pub magic_defense: u32,
+    pub bonus_magic_defense: u32,
+    pub hit: u32,
+    pub flee: u32,
+    pub bonus_flee: u32,
+    pub critical: u32,
+    /// Delay between two attacks in milliseconds, as sent by the server.
+    pub attack_delay: u32,
+}
+
+impl CharacterStatus {
+    pub fn get_attribute(&self, status_type: StatusIncreaseType) -> &Attribute {
+        match status_type {
+            StatusIncreaseType::Strength => &self.strength,
+            StatusIncreaseType::Agility => &self.agility,
+            StatusIncreaseType::Vitality => &self.vitality,
+            StatusIncreaseType::Intelligence => &self.intelligence,
+            StatusIncreaseType::Dexterity => &self.dexterity,
+            StatusIncreaseType::Luck => &self.luck,
+        }
+    }
+
+    /// The attack speed as displayed by the official client.
+    pub fn attack_speed(&self) -> u32 {
+        (2000 - self.attack_delay.min(2000)) / 10
+    }
+}
+
+#[derive(Default)]
+pub struct StatusTracker {
+    status: PlainTrackedState<CharacterStatus>,
+}
+
+impl StatusTracker {
+    pub fn update(&mut self, status_type: &StatusType) {
+        self.status.with_mut(|status| {
+            match *status_type {
+                StatusType::StatusPoint(value) => status.status_points = value,
+                StatusType::Strength(base, bonus) => (status.strength.base, status.strength.bonus) = (base, bonus),
+                StatusType::Agility(base, bonus) => (status.agility.base, status.agility.bonus) = (base, bonus),
+                StatusType::Vitality(base, bonus) => (status.vitality.base, status.vitality.bonus) = (base, bonus),
+                StatusType::Intelligence(base, bonus) => (status.intelligence.base, status.intelligence.bonus) = (base, bonus),
+                StatusType::Dexterity(base, bonus) => (status.dexterity.base, status.dexterity.bonus) = (base, bonus),
+                StatusType::Luck(base, bonus) => (status.luck.base, status.luck.bonus) = (base, bonus),
+                StatusType::SpUstr(cost) => status.strength.cost = cost,
+                StatusType::SpUagi(cost) => status.agility.cost = cost,
+                StatusType::SpUvit(cost) => status.vitality.cost = cost,
+                StatusType::SpUint(cost) => status.intelligence.cost = cost,
+                StatusType::SpUdex(cost) => status.dexterity.cost = cost,
+                StatusType::SpUluk(cost) => status.luck.cost = cost,
+                StatusType::Attack1(value) => status.attack = value,
+                StatusType::Attack2(value) => status.bonus_attack = value,
+                StatusType::MagicAttack1(value) => status.magic_attack = value,
+                StatusType::MagicAttack2(value) => status.bonus_magic_attack = value,
+                StatusType::Defense1(value) => status.defense = value,
+                StatusType::Defense2(value) => status.bonus_defense = value,
+                StatusType::MagicDefense1(value) => status.magic_defense = value,
+                StatusType::MagicDefense2(value) => status.bonus_magic_defense = value,
+                StatusType::Hit(value) => status.hit = value,
+                StatusType::Flee1(value) => status.flee = value,
+                StatusType::Flee2(value) => status.bonus_flee = value,
+                StatusType::Critical(value) => status.critical = value,
+                StatusType::AttackSpeed(value) => status.attack_delay = value,
+                _ => return ValueState::Unchanged(()),
+            }
+
+            ValueState::Mutated(())
+        });
+    }
+
+    pub fn get_status(&self) -> PlainRemote<CharacterStatus> {
+        self.status.new_remote()
+    }
+}
@@ -0,0 +1,89 @@
+use korangar_interface::state::{PlainRemote, PlainTrackedState, TrackedState, TrackedStateExt};
+use korangar_networking::InventoryItem;
+use ragnarok_packets::InventoryIndex;
+
+use crate::loaders::ResourceMetadata;
+
+#[derive(Clone, Default)]
+pub struct TradeOffer {
+    pub items: Vec<InventoryItem<ResourceMetadata>>,
+    pub zeny: u32,
+    pub is_locked: bool,
+}
+
+/// State of the trade with another player. Offers made by the player are
+/// only shown once the server accepted them.
+#[derive(Default)]
+pub struct Trade {
+    partner_name: String,
+    own_offer: PlainTrackedState<TradeOffer>,
+    partner_offer: PlainTrackedState<TradeOffer>,
+    pending_items: Vec<InventoryItem<ResourceMetadata>>,
+    pending_zeny: u32,
+}
+
+impl Trade {
+    pub fn set_partner_name(&mut self, partner_name: String) {
+        self.partner_name = partner_name;
+    }
+
+    pub fn get_partner_name(&self) -> &str {
+        &self.partner_name
+    }
+
+    pub fn clear(&mut self) {
+        self.own_offer.set(TradeOffer::default());
+        self.partner_offer.set(TradeOffer::default());
+        self.pending_items.clear();
+        self.pending_zeny = 0;
+    }
+
+    pub fn add_pending_item(&mut self, item: InventoryItem<ResourceMetadata>) {
+        self.pending_items.retain(|pending_item| pending_item.index != item.index);
+        self.pending_items.push(item);
+    }
+
+    pub fn set_pending_zeny(&mut self, amount: u32) {
+        self.pending_zeny = amount;
+    }
+
+    pub fn offer_accepted(&mut self, index: Option<InventoryIndex>) {
+        let Some(index) = index else {
+            let zeny = self.pending_zeny;
+            self.own_offer.mutate(|offer| offer.zeny = zeny);
+            return;
+        };
+
+        if let Some(position) = self.pending_items.iter().position(|item| item.index == index) {
+            let item = self.pending_items.remove(position);
+            self.own_offer.mutate(|offer| offer.items.push(item));
+        }
+    }
+
+    pub fn add_partner_item(&mut self, mut item: InventoryItem<ResourceMetadata>) {
+        self.partner_offer.mutate(|offer| {
+            // The server does not send an index, so we number the items ourselves.
+            item.index = InventoryIndex(offer.items.len() as u16);
+            offer.items.push(item);
+        });
+    }
+
+    pub fn set_partner_zeny(&mut self, amount: u32) {
+        self.partner_offer.mutate(|offer| offer.zeny = amount);
+    }
+
+    pub fn set_locked(&mut self, is_partner: bool) {
+        match is_partner {
+            true => self.partner_offer.mutate(|offer| offer.is_locked = true),
+            false => self.own_offer.mutate(|offer| offer.is_locked = true),
+        }
+    }
+
+    pub fn get_own_offer(&self) -> PlainRemote<TradeOffer> {
+        self.own_offer.new_remote()
+    }
+
+    pub fn get_partner_offer(&self) -> PlainRemote<TradeOffer> {
+        self.partner_offer.new_remote()
+    }
+}
@@ -0,0 +1,46 @@
+use korangar_networking::{ShopItem, VendingItem};
+use ragnarok_packets::{EntityId, ItemId, VendingPurchaseInformation};
+
+/// The vending shop of another player that the player is currently browsing.
+pub struct VendingShop {
+    entity_id: EntityId,
+    unique_id: u32,
+    indices: Vec<(ItemId, u16)>,
+}
+
+impl VendingShop {
+    pub fn new(entity_id: EntityId, unique_id: u32, items: &[VendingItem]) -> Self {
+        let indices = items.iter().map(|item| (item.item.item_id, item.index)).collect();
+
+        Self {
+            entity_id,
+            unique_id,
+            indices,
+        }
+    }
+
+    pub fn get_entity_id(&self) -> EntityId {
+        self.entity_id
+    }
+
+    pub fn get_unique_id(&self) -> u32 {
+        self.unique_id
+    }
+
+    /// Translate the items in the cart into the vending shop indices. The cart
+    /// groups items by id, so if a shop sells the same item multiple times
+    /// only the first one can be bought.
+    pub fn purchase_information(&self, items: &[ShopItem<u32>]) -> Vec<VendingPurchaseInformation> {
+        items
+            .iter()
+            .filter_map(|item| {
+                let (_, index) = self.indices.iter().find(|(item_id, _)| *item_id == item.item_id)?;
+
+                Some(VendingPurchaseInformation {
+                    amount: item.metadata as u16,
+                    index: *index,
+                })
+            })
+            .collect()
+    }
+}
@@ -8,7 +8,7 @@ use derive_new::new;
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use korangar_interface::elements::PrototypeElement;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::action::{Action, ActionsData};
+use ragnarok_formats::action::{Action, ActionsData, Motion};
 use ragnarok_formats::version::InternalVersion;
 use ragnarok_packets::ClientTick;
 use vulkano::image::view::ImageView;
@@ -78,17 +78,17 @@ pub struct Actions {
 }
 
 impl Actions {
-    pub fn render(
-        &self,
-        sprite: &Sprite,
-        animation_state: &AnimationState,
-        camera_direction: usize,
-        head_direction: usize,
-    ) -> (Arc<ImageView>, Vector2<f32>, bool) {
-        let direction = (camera_direction + head_direction) % 8;
-        let aa = animation_state.action * 8 + direction;
-        let a = &self.actions[aa % self.actions.len()];
-        let delay = self.delays[aa % self.delays.len()];
+    fn get_motion(&self, animation_state: &AnimationState, direction: usize) -> &Motion {
+        self.get_action_motion(animation_state.action * 8 + direction, animation_state)
+    }
+
+    /// Get the current motion of the action at the given index. Unlike
+    /// [`get_motion`](Self::get_motion) this ignores the action of the
+    /// animation state, which is useful for files that don't group their
+    /// actions by direction.
+    fn get_action_motion(&self, action_index: usize, animation_state: &AnimationState) -> &Motion {
+        let a = &self.actions[action_index % self.actions.len()];
+        let delay = self.delays[action_index % self.delays.len()];
 
         let factor = animation_state
             .factor
@@ -102,7 +102,38 @@ impl Actions {
         // TODO: work out how to avoid losing digits when casting timg to an f32. When
         // fixed remove set_start_time in MouseCursor.
 
-        let fs = &a.motions[frame as usize % a.motions.len()];
+        &a.motions[frame as usize % a.motions.len()]
+    }
+
+    /// Time in milliseconds that it takes to play the action at the given
+    /// index once at its normal speed.
+    pub fn action_duration(&self, action_index: usize) -> u32 {
+        let action = &self.actions[action_index % self.actions.len()];
+        let delay = self.delays[action_index % self.delays.len()];
+
+        (action.motions.len() as f32 * delay * 50.0) as u32
+    }
+
+    /// Position of the first attach point of the current frame. Layered
+    /// sprites like heads are aligned by moving their attach point onto the
+    /// attach point of the body.
+    pub fn get_attach_point(&self, animation_state: &AnimationState, direction: usize) -> Vector2<f32> {
+        self.get_motion(animation_state, direction % 8)
+            .attach_points
+            .first()
+            .map(|attach_point| attach_point.position.map(|component| component as f32))
+            .unwrap_or_else(|| Vector2::from_value(0.0))
+    }
+
+    pub fn render(
+        &self,
+        sprite: &Sprite,
+        animation_state: &AnimationState,
+        camera_direction: usize,
+        head_direction: usize,
+    ) -> (Arc<ImageView>, Vector2<f32>, bool) {
+        let direction = (camera_direction + head_direction) % 8;
+        let fs = self.get_motion(animation_state, direction);
 
         let texture = sprite.textures[fs.sprite_clips[0].sprite_number as usize].clone();
         let texture_size = texture.image().extent().map(|component| component as f32);
@@ -129,25 +160,43 @@ impl Actions {
         T: Renderer + SpriteRenderer,
     {
         let direction = camera_direction % 8;
-        let aa = animation_state.action * 8 + direction;
-        let a = &self.actions[aa % self.actions.len()];
-        let delay = self.delays[aa % self.delays.len()];
+        let motion = self.get_motion(animation_state, direction);
 
-        let factor = animation_state
-            .factor
-            .map(|factor| delay * (factor / 5.0))
-            .unwrap_or_else(|| delay * 50.0);
+        Self::render_motion(render_target, renderer, sprite, motion, position, color, application);
+    }
 
-        let frame = animation_state
-            .duration
-            .map(|duration| animation_state.time * a.motions.len() as u32 / duration)
-            .unwrap_or_else(|| (animation_state.time as f32 / factor) as u32);
-        // TODO: work out how to avoid losing digits when casting timg to an f32. When
-        // fixed remove set_start_time in MouseCursor.
+    /// Render the action at the given index, regardless of the action of the
+    /// animation state.
+    pub fn render_action<T>(
+        &self,
+        render_target: &mut T::Target,
+        renderer: &T,
+        sprite: &Sprite,
+        animation_state: &AnimationState,
+        action_index: usize,
+        position: ScreenPosition,
+        color: Color,
+        application: &InterfaceSettings,
+    ) where
+        T: Renderer + SpriteRenderer,
+    {
+        let motion = self.get_action_motion(action_index, animation_state);
 
-        let fs = &a.motions[frame as usize % a.motions.len()];
+        Self::render_motion(render_target, renderer, sprite, motion, position, color, application);
+    }
 
-        for sprite_clip in &fs.sprite_clips {
+    fn render_motion<T>(
+        render_target: &mut T::Target,
+        renderer: &T,
+        sprite: &Sprite,
+        motion: &Motion,
+        position: ScreenPosition,
+        color: Color,
+        application: &InterfaceSettings,
+    ) where
+        T: Renderer + SpriteRenderer,
+    {
+        for sprite_clip in &motion.sprite_clips {
             // `get` instead of a direct index in case a fallback was loaded
             let Some(texture) = sprite.textures.get(sprite_clip.sprite_number as usize) else {
                 return;
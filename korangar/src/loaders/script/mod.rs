@@ -6,7 +6,7 @@ use ragnarok_packets::ItemId;
 use vulkano::image::view::ImageView;
 
 use super::TextureLoader;
-use crate::loaders::GameFileLoader;
+use crate::loaders::{ActionLoader, Actions, GameFileLoader, Sprite, SpriteLoader};
 
 #[derive(Debug, Clone)]
 pub struct ResourceMetadata {
@@ -14,6 +14,18 @@ pub struct ResourceMetadata {
     pub name: String,
 }
 
+/// Client side information about an achievement.
+#[derive(Debug, Clone)]
+pub struct AchievementMetadata {
+    pub title: String,
+    pub summary: String,
+    /// Index of the category of the achievement.
+    pub category: u32,
+    /// Description and required count of every objective.
+    pub objectives: Vec<(String, u32)>,
+    pub score: u32,
+}
+
 pub struct ScriptLoader {
     state: Lua,
 }
@@ -60,6 +72,41 @@ end
 
         state.load(job_id_function).exec().unwrap();
 
+        // The status icon tables are not present in every client, so we don't
+        // require them to be present.
+        let status_icon_files = [
+            "data\\luafiles514\\lua files\\stateicon\\efstids.lub",
+            "data\\luafiles514\\lua files\\stateicon\\stateiconimginfo.lub",
+        ];
+
+        for file in status_icon_files {
+            if let Ok(data) = game_file_loader.get(file) {
+                let _ = state.load(&data).exec();
+            }
+        }
+
+        let status_icon_function = r#"
+function get_status_icon_from_id(id)
+  if StateIconImgList == nil then
+    return nil
+  end
+  for _,icons in pairs(StateIconImgList) do
+    if icons[id] ~= nil then
+      return icons[id]
+    end
+  end
+  return nil
+end
+"#;
+
+        state.load(status_icon_function).exec().unwrap();
+
+        // The achievement list is only shipped with newer clients, so we don't
+        // require it to be present.
+        if let Ok(data) = game_file_loader.get("system\\achievement_list.lub") {
+            let _ = state.load(&data).exec();
+        }
+
         Self { state }
     }
 
@@ -115,6 +162,79 @@ end
             .unwrap_or_else(|_| "»ç°ú".to_owned())
     }
 
+    // TODO: move this to a different class that utilizes the script loader
+    fn get_status_icon_from_id(&self, status_id: u16) -> Option<String> {
+        use mlua::prelude::*;
+        use mlua::Function;
+
+        let globals = self.state.globals();
+
+        let lookup: Function = globals.get("get_status_icon_from_id").unwrap();
+        lookup
+            .call::<_, Option<LuaString>>(status_id)
+            .ok()
+            .flatten()
+            .and_then(|name| name.to_str().ok().map(str::to_owned))
+    }
+
+    pub fn load_status_icon(
+        &self,
+        game_file_loader: &mut GameFileLoader,
+        texture_loader: &mut TextureLoader,
+        status_id: u16,
+    ) -> Option<Arc<ImageView>> {
+        let icon_name = self.get_status_icon_from_id(status_id)?;
+        let full_path = format!("effect\\{icon_name}");
+
+        texture_loader.get(&full_path, game_file_loader).ok()
+    }
+
+    // TODO: move this to a different class that utilizes the script loader
+    pub fn get_achievement_metadata(&self, achievement_id: u32) -> Option<AchievementMetadata> {
+        use mlua::prelude::*;
+
+        let to_string = |string: LuaString| string.to_str().ok().map(str::to_owned);
+
+        let globals = self.state.globals();
+        let table = globals
+            .get::<_, LuaTable>("achievement_tbl")
+            .ok()?
+            .get::<_, LuaTable>(achievement_id)
+            .ok()?;
+
+        let title = table.get::<_, LuaString>("title").ok().and_then(to_string)?;
+        let summary = table
+            .get::<_, LuaTable>("content")
+            .and_then(|content| content.get::<_, LuaString>("summary"))
+            .ok()
+            .and_then(to_string)
+            .unwrap_or_default();
+        let category = table.get::<_, u32>("major").unwrap_or_default();
+        let score = table.get::<_, u32>("score").unwrap_or_default();
+        let objectives = table
+            .get::<_, LuaTable>("resource")
+            .map(|resource| {
+                resource
+                    .sequence_values::<LuaTable>()
+                    .filter_map(Result::ok)
+                    .map(|objective| {
+                        let text = objective.get::<_, LuaString>("text").ok().and_then(to_string).unwrap_or_default();
+                        let count = objective.get::<_, u32>("count").unwrap_or(1);
+                        (text, count)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(AchievementMetadata {
+            title,
+            summary,
+            category,
+            objectives,
+            score,
+        })
+    }
+
     pub fn load_inventory_item_metadata(
         &self,
         game_file_loader: &mut GameFileLoader,
@@ -148,4 +268,20 @@ end
 
         ShopItem { metadata, ..item }
     }
+
+    pub fn load_ground_item_sprite(
+        &self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        item_id: ItemId,
+        is_identified: bool,
+    ) -> (Arc<Sprite>, Arc<Actions>) {
+        let resource_name = self.get_item_resource_from_id(item_id, is_identified);
+        let file_path = format!("¾ÆÀÌÅÛ\\{resource_name}");
+        let sprite = sprite_loader.get(&format!("{file_path}.spr"), game_file_loader).unwrap();
+        let actions = action_loader.get(&format!("{file_path}.act"), game_file_loader).unwrap();
+
+        (sprite, actions)
+    }
 }
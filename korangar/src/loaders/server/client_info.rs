@@ -93,6 +93,12 @@ pub struct Service {
     /// login_athena.conf.
     pub version: i8,
 
+    /// Client date (`PACKETVER`) that the server is configured for. This is
+    /// not part of the official format and defaults to the client date that
+    /// rAthena uses.
+    #[serde(default, alias = "packetver")]
+    pub packet_version: Option<u32>,
+
     /// Uses the same value from `ServiceType` enum.
     #[serde(default, alias = "langtype", deserialize_with = "language_type_from_index")]
     pub language_type: Option<ServiceType>,
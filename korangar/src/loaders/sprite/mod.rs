@@ -6,7 +6,7 @@ use derive_new::new;
 use korangar_debug::logging::{print_debug, Colorize, Timer};
 use korangar_interface::elements::PrototypeElement;
 use ragnarok_bytes::{ByteStream, FromBytes};
-use ragnarok_formats::sprite::{PaletteColor, RgbaImageData, SpriteData};
+use ragnarok_formats::sprite::{Palette, PaletteColor, RgbaImageData, SpriteData};
 use ragnarok_formats::version::InternalVersion;
 use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage};
 use vulkano::command_buffer::{
@@ -44,14 +44,14 @@ pub struct SpriteLoader {
 }
 
 impl SpriteLoader {
-    fn load(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
+    fn load(&mut self, path: &str, palette_path: Option<&str>, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
         #[cfg(feature = "debug")]
         let timer = Timer::new_dynamic(format!("load sprite from {}", path.magenta()));
 
         let bytes = game_file_loader.get(&format!("data\\sprite\\{path}")).map_err(LoadError::File)?;
         let mut byte_stream: ByteStream<Option<InternalVersion>> = ByteStream::without_metadata(&bytes);
 
-        let sprite_data = match SpriteData::from_bytes(&mut byte_stream) {
+        let mut sprite_data = match SpriteData::from_bytes(&mut byte_stream) {
             Ok(sprite_data) => sprite_data,
             Err(_error) => {
                 #[cfg(feature = "debug")]
@@ -64,6 +64,23 @@ impl SpriteLoader {
             }
         };
 
+        // Palettes replace the colors of the palette images, for example to give hair a
+        // different color.
+        if let Some(palette_path) = palette_path {
+            let bytes = game_file_loader
+                .get(&format!("data\\palette\\{palette_path}"))
+                .map_err(LoadError::File)?;
+            let mut byte_stream: ByteStream<()> = ByteStream::without_metadata(&bytes);
+
+            match Palette::from_bytes(&mut byte_stream) {
+                Ok(palette) => sprite_data.palette = Some(palette),
+                Err(_error) => {
+                    #[cfg(feature = "debug")]
+                    print_debug!("Failed to load palette: {:?}", _error);
+                }
+            }
+        }
+
         #[cfg(feature = "debug")]
         let cloned_sprite_data = sprite_data.clone();
 
@@ -152,7 +169,7 @@ impl SpriteLoader {
             sprite_data: cloned_sprite_data,
         });
 
-        self.cache.insert(path.to_string(), sprite.clone());
+        self.cache.insert(Self::cache_key(path, palette_path), sprite.clone());
 
         #[cfg(feature = "debug")]
         timer.stop();
@@ -160,10 +177,31 @@ impl SpriteLoader {
         Ok(sprite)
     }
 
+    fn cache_key(path: &str, palette_path: Option<&str>) -> String {
+        match palette_path {
+            Some(palette_path) => format!("{path}:{palette_path}"),
+            None => path.to_string(),
+        }
+    }
+
     pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<Sprite>, LoadError> {
         match self.cache.get(path) {
             Some(sprite) => Ok(sprite.clone()),
-            None => self.load(path, game_file_loader),
+            None => self.load(path, None, game_file_loader),
+        }
+    }
+
+    /// Get a sprite with the colors of the palette at `palette_path` (relative
+    /// to `data\\palette`) instead of its own.
+    pub fn get_with_palette(
+        &mut self,
+        path: &str,
+        palette_path: &str,
+        game_file_loader: &mut GameFileLoader,
+    ) -> Result<Arc<Sprite>, LoadError> {
+        match self.cache.get(&Self::cache_key(path, Some(palette_path))) {
+            Some(sprite) => Ok(sprite.clone()),
+            None => self.load(path, Some(palette_path), game_file_loader),
         }
     }
 
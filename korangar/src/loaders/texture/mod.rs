@@ -4,9 +4,10 @@ use std::sync::Arc;
 
 use derive_new::new;
 use image::io::Reader as ImageReader;
-use image::{EncodableLayout, ImageFormat, Rgba};
+use image::{EncodableLayout, ImageFormat, Rgba, RgbaImage};
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{print_debug, Colorize, Timer};
+use ragnarok_packets::GuildId;
 use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage};
 use vulkano::command_buffer::{
     AutoCommandBufferBuilder, CommandBufferUsage, CopyBufferToImageInfo, PrimaryAutoCommandBuffer, PrimaryCommandBufferAbstract,
@@ -18,6 +19,7 @@ use vulkano::image::{Image, ImageCreateInfo, ImageUsage};
 use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter};
 use vulkano::sync::future::FenceSignalFuture;
 use vulkano::sync::GpuFuture;
+use yazi::{decompress, Format as CompressionFormat};
 
 use super::error::LoadError;
 use super::{FALLBACK_BMP_FILE, FALLBACK_PNG_FILE, FALLBACK_TGA_FILE};
@@ -70,13 +72,19 @@ impl TextureLoader {
         };
 
         if image_format == ImageFormat::Bmp {
-            // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
-            image_buffer
-                .pixels_mut()
-                .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
-                .for_each(|pixel| *pixel = Rgba([0; 4]));
+            remove_bitmap_background(&mut image_buffer);
         }
 
+        let texture = self.upload(image_buffer);
+        self.cache.insert(path.to_string(), texture.clone());
+
+        #[cfg(feature = "debug")]
+        timer.stop();
+
+        Ok(texture)
+    }
+
+    fn upload(&mut self, image_buffer: RgbaImage) -> Arc<ImageView> {
         let load_buffer = self.load_buffer.get_or_insert_with(|| {
             AutoCommandBufferBuilder::primary(
                 &*self.memory_allocator,
@@ -116,13 +124,7 @@ impl TextureLoader {
             .copy_buffer_to_image(CopyBufferToImageInfo::buffer_image(buffer, image.clone()))
             .unwrap();
 
-        let texture = ImageView::new_default(image).unwrap();
-        self.cache.insert(path.to_string(), texture.clone());
-
-        #[cfg(feature = "debug")]
-        timer.stop();
-
-        Ok(texture)
+        ImageView::new_default(image).unwrap()
     }
 
     pub fn get(&mut self, path: &str, game_file_loader: &mut GameFileLoader) -> Result<Arc<ImageView>, LoadError> {
@@ -132,6 +134,35 @@ impl TextureLoader {
         }
     }
 
+    /// Create a texture from the emblem data sent by the map server. The data
+    /// is a zlib compressed bitmap (or gif for some servers).
+    pub fn get_guild_emblem(&mut self, guild_id: GuildId, emblem_id: u32, emblem_data: &[u8]) -> Result<Arc<ImageView>, LoadError> {
+        let cache_key = format!("guild_emblem_{}_{}", guild_id.0, emblem_id);
+
+        if let Some(texture) = self.cache.get(&cache_key) {
+            return Ok(texture.clone());
+        }
+
+        let (decompressed, _checksum) =
+            decompress(emblem_data, CompressionFormat::Zlib).map_err(|_| LoadError::UnsupportedFormat("compressed emblem".to_owned()))?;
+        let image_format = image::guess_format(&decompressed).map_err(|_| LoadError::UnsupportedFormat("emblem".to_owned()))?;
+        let reader = ImageReader::with_format(Cursor::new(decompressed), image_format);
+
+        let mut image_buffer = reader
+            .decode()
+            .map_err(|_| LoadError::UnsupportedFormat("emblem".to_owned()))?
+            .to_rgba8();
+
+        if image_format == ImageFormat::Bmp {
+            remove_bitmap_background(&mut image_buffer);
+        }
+
+        let texture = self.upload(image_buffer);
+        self.cache.insert(cache_key, texture.clone());
+
+        Ok(texture)
+    }
+
     pub fn submit_load_buffer(&mut self) -> Option<FenceSignalFuture<Box<dyn GpuFuture>>> {
         self.load_buffer.take().map(|buffer| {
             buffer
@@ -145,3 +176,12 @@ impl TextureLoader {
         })
     }
 }
+
+/// Make the magenta background of bitmaps transparent.
+fn remove_bitmap_background(image_buffer: &mut RgbaImage) {
+    // These numbers are taken from https://github.com/Duckwhale/RagnarokFileFormats
+    image_buffer
+        .pixels_mut()
+        .filter(|pixel| pixel.0[0] > 0xF0 && pixel.0[1] < 0x10 && pixel.0[2] > 0x0F)
+        .for_each(|pixel| *pixel = Rgba([0; 4]));
+}
@@ -27,10 +27,12 @@ mod loaders;
 mod world;
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::net::ToSocketAddrs;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use cgmath::{Vector2, Vector3};
 use image::io::Reader as ImageReader;
@@ -42,14 +44,18 @@ use korangar_debug::profile_block;
 #[cfg(feature = "debug")]
 use korangar_debug::profiling::Profiler;
 use korangar_interface::application::{Application, FocusState, FontSizeTrait, FontSizeTraitExt, PositionTraitExt};
-use korangar_interface::state::{PlainTrackedState, Remote, RemoteClone, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec};
+use korangar_interface::state::{
+    PlainTrackedState, Remote, RemoteClone, TrackedState, TrackedStateExt, TrackedStateTake, TrackedStateVec, ValueState,
+};
 use korangar_interface::Interface;
 use korangar_networking::{
-    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem, SellItem, ShopItem,
+    DisconnectReason, HotkeyState, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem, PartyMember, ReconnectPolicy,
+    SellItem, ShopItem,
 };
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, Friend, HotbarSlot, SellItemsResult, SkillId,
-    SkillType, TilePosition, UnitId, WorldPosition,
+    BuyShopItemsResult, CharacterId, CharacterInformation, CharacterServerInformation, EntityId, Friend, GuildMenuType, HotbarSlot,
+    InventoryIndex, PacketLengthTable, PacketLengthTableError, PacketVersion, SellItemsResult, SkillId, SkillType, StorageIndex,
+    TilePosition, UnitId, WhisperResult, WorldPosition,
 };
 use vulkano::device::{Device, DeviceCreateInfo, QueueCreateInfo};
 #[cfg(feature = "debug")]
@@ -65,7 +71,7 @@ use winit::event_loop::{ControlFlow, EventLoop};
 use winit::window::{Icon, WindowBuilder};
 
 use crate::graphics::*;
-use crate::input::{InputSystem, UserEvent};
+use crate::input::{ChatCommand, InputSystem, UserEvent};
 use crate::interface::application::InterfaceSettings;
 use crate::interface::cursor::{MouseCursor, MouseCursorState};
 use crate::interface::dialog::DialogSystem;
@@ -73,7 +79,9 @@ use crate::interface::layout::{ScreenPosition, ScreenSize};
 use crate::interface::linked::LinkedElement;
 use crate::interface::resource::{ItemSource, Move, SkillSource};
 use crate::interface::windows::*;
-use crate::inventory::{Hotbar, Inventory, SkillTree};
+use crate::inventory::{
+    AchievementStore, GuildRoster, Hotbar, Inventory, Mailbox, PendingMail, QuestJournal, SkillTree, StatusTracker, Trade, VendingShop,
+};
 use crate::loaders::*;
 #[cfg(feature = "debug")]
 use crate::system::vulkan_message_callback;
@@ -387,13 +395,30 @@ fn main() {
     #[cfg(feature = "debug")]
     let mut networking_system = NetworkingSystem::spawn_with_callback(packet_callback.clone());
 
+    networking_system.set_reconnect_policy(Some(ReconnectPolicy::default()));
+
+    // Custom server builds can override the lengths of the built-in table, so
+    // packets that the client doesn't know are skipped instead of corrupting the
+    // stream.
+    match PacketLengthTable::load("client/packet_lengths.txt") {
+        Ok(packet_lengths) => networking_system.set_packet_length_overrides(packet_lengths),
+        Err(PacketLengthTableError::Io(..)) => {}
+        #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+        Err(error) => {
+            #[cfg(feature = "debug")]
+            print_debug!("[{}] {}", "error".red(), error);
+        }
+    }
+
     let mut friend_list: PlainTrackedState<Vec<(Friend, LinkedElement)>> = PlainTrackedState::default();
+    let mut party_members: PlainTrackedState<Vec<(PartyMember, LinkedElement)>> = PlainTrackedState::default();
     let mut saved_login_data: Option<LoginServerLoginData> = None;
     let mut saved_character_server: Option<CharacterServerInformation> = None;
     let mut saved_characters: PlainTrackedState<Vec<CharacterInformation>> = PlainTrackedState::default();
     let mut shop_items: PlainTrackedState<Vec<ShopItem<ResourceMetadata>>> = PlainTrackedState::default();
     let mut sell_items: PlainTrackedState<Vec<SellItem<(ResourceMetadata, u16)>>> = PlainTrackedState::default();
-    let mut currently_deleting: Option<CharacterId> = None;
+    let mut character_deletion_dates: PlainTrackedState<HashMap<CharacterId, Instant>> = PlainTrackedState::default();
+    let mut character_preview: PlainTrackedState<Option<CharacterSprites>> = PlainTrackedState::default();
     let mut saved_player_name = String::new();
     let mut move_request: PlainTrackedState<Option<usize>> = PlainTrackedState::default();
     let mut saved_login_server_address = None;
@@ -409,9 +434,21 @@ fn main() {
     let mut particle_holder = ParticleHolder::default();
     let mut effect_holder = EffectHolder::default();
     let mut entities = Vec::<Entity>::new();
+    let mut ground_items = Vec::<GroundItem>::new();
     let mut player_inventory = Inventory::default();
     let mut player_skill_tree = SkillTree::default();
     let mut hotbar = Hotbar::default();
+    let mut quest_journal = QuestJournal::default();
+    let mut status_tracker = StatusTracker::default();
+    let mut guild_roster = GuildRoster::default();
+    let mut trade = Trade::default();
+    let mut storage = Inventory::default();
+    let mut merchant_cart = Inventory::default();
+    let mut vending_title = String::new();
+    let mut vending_shop: Option<VendingShop> = None;
+    let mut mailbox = Mailbox::default();
+    let mut achievements = AchievementStore::default();
+    let mut achievement_toast = None;
 
     let welcome_string = format!(
         "Welcome to ^ffff00★^000000 ^ff8800Korangar^000000 ^ffff00★^000000 version ^ff8800{}^000000!",
@@ -422,6 +459,8 @@ fn main() {
         color: MessageColor::Server,
     }]);
 
+    let mut whisper_conversations = HashMap::<String, PlainTrackedState<Vec<ChatMessage>>>::new();
+
     let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
 
     event_loop.run(move |event, _, control_flow| {
@@ -563,7 +602,7 @@ fn main() {
                             if reason != DisconnectReason::ClosedByClient {
                                 // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
-                                print_debug!("Disconnection from the character server with error");
+                                print_debug!("Disconnection from the login server with error: {:?}", reason);
 
                                 let socket_address = saved_login_server_address.unwrap();
                                 networking_system.connect_to_login_server(socket_address, &saved_username, &saved_password);
@@ -581,7 +620,7 @@ fn main() {
                             if reason != DisconnectReason::ClosedByClient {
                                 // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
-                                print_debug!("Disconnection from the character server with error");
+                                print_debug!("Disconnection from the character server with error: {:?}", reason);
 
                                 let login_data = saved_login_data.as_ref().unwrap();
                                 let server = saved_character_server.clone().unwrap();
@@ -592,7 +631,7 @@ fn main() {
                             if reason != DisconnectReason::ClosedByClient {
                                 // TODO: Make this an on-screen popup.
                                 #[cfg(feature = "debug")]
-                                print_debug!("Disconnection from the map server with error");
+                                print_debug!("Disconnection from the map server with error: {:?}", reason);
                             }
 
                             let login_data = saved_login_data.as_ref().unwrap();
@@ -600,8 +639,10 @@ fn main() {
                             networking_system.connect_to_character_server(login_data, server);
 
                             entities.clear();
+                            ground_items.clear();
                             particle_holder.clear();
                             effect_holder.clear();
+                            party_members.clear();
 
                             map = map_loader
                                 .get(
@@ -615,17 +656,61 @@ fn main() {
 
                             interface.close_all_windows_except(&mut focus_state);
 
-                            let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), saved_slot_count);
+                            let character_selection_window = CharacterSelectionWindow::new(
+                                saved_characters.new_remote(),
+                                move_request.new_remote(),
+                                character_deletion_dates.new_remote(),
+                                saved_slot_count,
+                            );
                             interface.open_window(&application, &mut focus_state, &character_selection_window);
 
                             start_camera.set_focus_point(cgmath::Point3::new(600.0, 0.0, 240.0));
                             directional_shadow_camera.set_focus_point(cgmath::Point3::new(600.0, 0.0, 240.0));
 
                         },
+                        NetworkEvent::MapServerReconnecting { attempt, max_attempts, delay } => {
+                            chat_messages.push(ChatMessage {
+                                text: format!(
+                                    "Lost connection to the map server, reconnecting in {:.1}s ({attempt}/{max_attempts})",
+                                    delay.as_secs_f32()
+                                ),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::MapServerReconnected => {
+                            // The server sends all entities again after the map is loaded, so
+                            // only the player is kept.
+                            entities.truncate(1);
+                            ground_items.clear();
+                            let _ = networking_system.map_loaded();
+
+                            chat_messages.push(ChatMessage {
+                                text: "Reconnected to the map server.".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
                         NetworkEvent::AccountId(..) => {},
                         NetworkEvent::CharacterList { characters } => {
+                            // The character list contains the seconds until a reserved deletion can be
+                            // accepted.
+                            let now = Instant::now();
+                            let deletion_dates = characters
+                                .iter()
+                                .filter(|character| character.deletion_reverse_date > 0)
+                                .map(|character| {
+                                    let deletion_time = Duration::from_secs(character.deletion_reverse_date as u64);
+                                    (character.character_id, now + deletion_time)
+                                })
+                                .collect();
+
                             saved_characters.set(characters);
-                            let character_selection_window = CharacterSelectionWindow::new(saved_characters.new_remote(), move_request.new_remote(), saved_slot_count);
+                            character_deletion_dates.set(deletion_dates);
+                            let character_selection_window = CharacterSelectionWindow::new(
+                                saved_characters.new_remote(),
+                                move_request.new_remote(),
+                                character_deletion_dates.new_remote(),
+                                saved_slot_count,
+                            );
 
                             // TODO: this will do one unnecessary restore_focus. check if
                             // that will be problematic
@@ -635,13 +720,33 @@ fn main() {
                         NetworkEvent::CharacterSelectionFailed { message, .. } => {
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()))
                         }
-                        NetworkEvent::CharacterDeleted => {
-                            let character_id = currently_deleting.take().unwrap();
-
-                            saved_characters.retain(|character| character.character_id != character_id);
-                        },
+                        // Only sent when deleting characters right away, which servers with delayed
+                        // deletion don't support.
+                        NetworkEvent::CharacterDeleted => {}
                         NetworkEvent::CharacterDeletionFailed { message, .. } => {
-                            currently_deleting = None;
+                            interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()))
+                        }
+                        NetworkEvent::CharacterDeletionReserved { character_id, deletion_time } => {
+                            interface.close_window_with_class(&mut focus_state, CharacterDeletionWindow::WINDOW_CLASS);
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.insert(character_id, Instant::now() + deletion_time);
+                            });
+                        }
+                        NetworkEvent::CharacterDeletionAccepted { character_id } => {
+                            interface.close_window_with_class(&mut focus_state, CharacterDeletionWindow::WINDOW_CLASS);
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.remove(&character_id);
+                            });
+                            saved_characters.retain(|character| character.character_id != character_id);
+                        }
+                        NetworkEvent::CharacterDeletionCanceled { character_id } => {
+                            character_deletion_dates.mutate(|deletion_dates| {
+                                deletion_dates.remove(&character_id);
+                            });
+                        }
+                        NetworkEvent::CharacterDeletionReservationFailed { message, .. }
+                        | NetworkEvent::CharacterDeletionAcceptFailed { message, .. }
+                        | NetworkEvent::CharacterDeletionCancelFailed { message, .. } => {
                             interface.open_window(&application, &mut focus_state, &ErrorWindow::new(message.to_owned()))
                         }
                         NetworkEvent::CharacterSelected { login_data, map_name } => {
@@ -761,6 +866,7 @@ fn main() {
                         }
                         NetworkEvent::ChangeMap(map_name, player_position) => {
                             entities.truncate(1);
+                            ground_items.clear();
 
                             map = map_loader
                                 .get(
@@ -792,6 +898,9 @@ fn main() {
                         NetworkEvent::UpdateClientTick(client_tick) => {
                             game_timer.set_client_tick(client_tick);
                         }
+                        NetworkEvent::TickSample(tick_sample) => {
+                            game_timer.add_tick_sample(tick_sample);
+                        }
                         NetworkEvent::ChatMessage { text, color } => {
                             chat_messages.push(ChatMessage { text, color });
                         }
@@ -825,13 +934,44 @@ fn main() {
                                 entity.update_health(health_points, maximum_health_points);
                             }
                         }
+                        NetworkEvent::AddStatusEffect { entity_id, status_effect } => {
+                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+
+                            if let Some(entity) = entity {
+                                let icon = script_loader.load_status_icon(&mut game_file_loader, &mut texture_loader, status_effect.status_id);
+                                entity.add_status_effect(status_effect, icon, client_tick);
+                            }
+                        }
+                        NetworkEvent::EntityEmotion { entity_id, emotion } => {
+                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+
+                            if let Some(entity) = entity {
+                                entity.set_emotion(&mut game_file_loader, &mut sprite_loader, &mut action_loader, emotion, client_tick);
+                            }
+                        }
+                        NetworkEvent::RemoveStatusEffect { entity_id, status_id } => {
+                            let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
+
+                            if let Some(entity) = entity {
+                                entity.remove_status_effect(status_id);
+                            }
+                        }
                         NetworkEvent::UpdateStatus(status_type) => {
                             let Entity::Player(player) = &mut entities[0] else {
                                 panic!();
                             };
 
+                            status_tracker.update(&status_type);
                             player.update_status(status_type);
                         }
+                        NetworkEvent::GainedExperience { account_id, amount, experience_type, .. } => {
+                            let entity = entities.iter().find(|entity| entity.get_entity_id() == EntityId(account_id.0));
+
+                            if let Some(entity) = entity {
+                                let experience_gain = ExperienceGain::from_experience(entity.get_position(), amount, experience_type);
+                                particle_holder.spawn_particle(Box::new(experience_gain));
+                            }
+                        }
                         NetworkEvent::OpenDialog(text, npc_id) => {
                             if let Some(dialog_window) = dialog_system.open_dialog_window(text, npc_id) {
                                 interface.open_window(&application, &mut focus_state, &dialog_window);
@@ -866,6 +1006,375 @@ fn main() {
                                 index, amount,
                             );
                         }
+                        NetworkEvent::AddGroundItem(ground_item_data) => {
+                            ground_items.retain(|ground_item| ground_item.get_ground_item_id() != ground_item_data.ground_item_id);
+
+                            let ground_item = GroundItem::new(
+                                &mut game_file_loader,
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                &script_loader,
+                                &map,
+                                ground_item_data,
+                                client_tick,
+                            );
+
+                            ground_items.push(ground_item);
+                        }
+                        NetworkEvent::RemoveGroundItem(ground_item_id) => {
+                            ground_items.retain(|ground_item| ground_item.get_ground_item_id() != ground_item_id);
+                        }
+                        NetworkEvent::ItemUsed { entity_id, index, remaining_amount, .. } => {
+                            // Other players using items nearby are also reported, but only our own
+                            // inventory needs to be updated.
+                            if entities.first().is_some_and(|player| player.get_entity_id() == entity_id) {
+                                player_inventory.update_item_amount(index, remaining_amount);
+                            }
+                        }
+                        NetworkEvent::ItemUseFailed { .. } => {
+                            chat_messages.push(ChatMessage {
+                                text: "The item could not be used.".to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::ItemPickupFailed { message, .. } => {
+                            chat_messages.push(ChatMessage {
+                                text: message.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::PartyInvitation { party_id, party_name } => {
+                            interface.open_window(&application, &mut focus_state, &PartyInvitationWindow::new(party_id, party_name))
+                        }
+                        NetworkEvent::SetPartyMembers { party_name: _party_name, members } => {
+                            party_members.mutate(|party_members| {
+                                *party_members = members.into_iter().map(|member| (member, LinkedElement::new())).collect();
+                            });
+                        }
+                        NetworkEvent::PartyMemberJoined { member } => {
+                            party_members.retain(|(party_member, _)| party_member.account_id != member.account_id);
+                            party_members.push((member, LinkedElement::new()));
+                        }
+                        NetworkEvent::PartyMemberLeft { account_id } => {
+                            let own_account_id = saved_login_data.as_ref().unwrap().account_id;
+
+                            match account_id == own_account_id {
+                                true => party_members.clear(),
+                                false => party_members.retain(|(party_member, _)| party_member.account_id != account_id),
+                            }
+                        }
+                        NetworkEvent::PartyMemberHealth { account_id, health_points, maximum_health_points } => {
+                            party_members.with_mut(|party_members| {
+                                let Some((member, linked_element)) =
+                                    party_members.iter_mut().find(|(member, _)| member.account_id == account_id)
+                                else {
+                                    return ValueState::Unchanged(());
+                                };
+
+                                member.health_points = health_points;
+                                member.maximum_health_points = maximum_health_points;
+                                // Unlink the element so the party view rebuilds it.
+                                *linked_element = LinkedElement::new();
+
+                                ValueState::Mutated(())
+                            });
+                        }
+                        NetworkEvent::PartyMemberPosition { account_id: _account_id, position: _position } => {
+                            // TODO: Show the position of party members on the minimap once we
+                            // have one.
+                        }
+                        NetworkEvent::PartyMemberJobLevel { account_id, job, base_level } => {
+                            party_members.with_mut(|party_members| {
+                                let Some((member, linked_element)) =
+                                    party_members.iter_mut().find(|(member, _)| member.account_id == account_id)
+                                else {
+                                    return ValueState::Unchanged(());
+                                };
+
+                                member.job = job;
+                                member.base_level = base_level;
+                                *linked_element = LinkedElement::new();
+
+                                ValueState::Mutated(())
+                            });
+                        }
+                        NetworkEvent::SetQuestList { quests } => {
+                            quest_journal.fill(quests);
+                        }
+                        NetworkEvent::QuestAdded { quest } => {
+                            quest_journal.add_quest(quest);
+                        }
+                        NetworkEvent::QuestRemoved { quest_id } => {
+                            quest_journal.remove_quest(quest_id);
+                        }
+                        NetworkEvent::UpdateQuestObjectives { objectives } => {
+                            let updated_objectives = quest_journal.update_objectives(objectives);
+
+                            if let Some(player) = entities.first() {
+                                updated_objectives.iter().for_each(|objective| {
+                                    particle_holder.spawn_particle(Box::new(QuestProgress::from_objective(player.get_position(), objective)));
+                                });
+                            }
+                        }
+                        NetworkEvent::WhisperReceived { sender_name, message } => {
+                            chat_messages.push(ChatMessage {
+                                text: format!("(From {sender_name}) : {message}"),
+                                color: MessageColor::Whisper,
+                            });
+
+                            let conversation = whisper_conversations.entry(sender_name.clone()).or_default();
+                            conversation.push(ChatMessage {
+                                text: format!("{sender_name} : {message}"),
+                                color: MessageColor::Whisper,
+                            });
+
+                            let whisper_window = WhisperWindow::new(sender_name, conversation.new_remote(), font_loader.clone());
+                            interface.open_window(&application, &mut focus_state, &whisper_window);
+                        }
+                        NetworkEvent::WhisperFailed { reason } => {
+                            let text = match reason {
+                                WhisperResult::Success => "The message was delivered",
+                                WhisperResult::TargetNotOnline => "The character is not online",
+                                WhisperResult::TargetIgnoresYou => "The character is ignoring your messages",
+                                WhisperResult::TargetIgnoresEveryone => "The character is ignoring all private messages",
+                            };
+
+                            chat_messages.push(ChatMessage {
+                                text: text.to_owned(),
+                                color: MessageColor::Error,
+                            });
+                        }
+                        NetworkEvent::PartyLeaderChanged { account_id } => {
+                            party_members.mutate(|party_members| {
+                                party_members.iter_mut().for_each(|(member, linked_element)| {
+                                    member.is_leader = member.account_id == account_id;
+                                    *linked_element = LinkedElement::new();
+                                });
+                            });
+                        }
+                        NetworkEvent::UpdateEntityGuild { entity_id, guild_name, position_name: _position_name } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_guild_name(guild_name);
+                            }
+                        }
+                        NetworkEvent::UpdateGuild { guild_id, guild_name, emblem_id: _emblem_id, is_master } => {
+                            guild_roster.set_guild(guild_id, guild_name, is_master);
+
+                            let _ = networking_system.request_guild_menu(GuildMenuType::Information);
+                            let _ = networking_system.request_guild_menu(GuildMenuType::MemberList);
+                            let _ = networking_system.request_guild_menu(GuildMenuType::Positions);
+                            let _ = networking_system.request_guild_emblem(guild_id);
+                        }
+                        NetworkEvent::SetGuildInformation { information } => {
+                            guild_roster.set_information(information);
+                        }
+                        NetworkEvent::SetGuildMembers { members } => {
+                            guild_roster.set_members(members);
+                        }
+                        NetworkEvent::SetGuildPositions { positions } => {
+                            guild_roster.set_positions(positions);
+                        }
+                        NetworkEvent::GuildNotice { subject, notice } => {
+                            guild_roster.set_notice(subject, notice);
+                        }
+                        NetworkEvent::GuildMemberOnlineStatus { account_id, is_online } => {
+                            guild_roster.set_member_online(account_id, is_online);
+                        }
+                        NetworkEvent::GuildInvitation { guild_id, guild_name } => {
+                            interface.open_window(&application, &mut focus_state, &GuildInvitationWindow::new(guild_id, guild_name))
+                        }
+                        NetworkEvent::GuildMemberLeft { name, reason } => {
+                            chat_messages.push(ChatMessage {
+                                text: format!("{name} has left the guild ({reason})"),
+                                color: MessageColor::Information,
+                            });
+
+                            match name == saved_player_name {
+                                true => guild_roster.clear(),
+                                false => guild_roster.remove_member(&name),
+                            }
+                        }
+                        NetworkEvent::GuildEmblem { guild_id, emblem_id, emblem_data } => {
+                            // Emblems of other guilds are not displayed anywhere yet.
+                            if guild_roster.get_guild_id() == Some(guild_id) {
+                                if let Ok(emblem) = texture_loader.get_guild_emblem(guild_id, emblem_id, &emblem_data) {
+                                    guild_roster.set_emblem(emblem);
+                                }
+                            }
+                        }
+                        NetworkEvent::TradeRequested { name, character_id: _character_id, base_level } => {
+                            trade.set_partner_name(name.clone());
+                            interface.open_window(&application, &mut focus_state, &TradeRequestWindow::new(name, base_level));
+                        }
+                        NetworkEvent::TradeStarted => {
+                            trade.clear();
+
+                            let partner_name = trade.get_partner_name().to_owned();
+                            let trade_window = TradeWindow::new(partner_name, trade.get_own_offer(), trade.get_partner_offer());
+                            interface.open_window(&application, &mut focus_state, &trade_window);
+                        }
+                        NetworkEvent::TradeOfferAccepted { index } => {
+                            trade.offer_accepted(index);
+                        }
+                        NetworkEvent::TradePartnerItemAdded { item } => {
+                            let item = script_loader.load_inventory_item_metadata(&mut game_file_loader, &mut texture_loader, item);
+                            trade.add_partner_item(item);
+                        }
+                        NetworkEvent::TradePartnerZenyAdded { amount } => {
+                            trade.set_partner_zeny(amount);
+                        }
+                        NetworkEvent::TradeLocked { is_partner } => {
+                            trade.set_locked(is_partner);
+                        }
+                        NetworkEvent::TradeCancelled => {
+                            trade.clear();
+                            interface.close_window_with_class(&mut focus_state, TradeWindow::WINDOW_CLASS);
+
+                            chat_messages.push(ChatMessage {
+                                text: "The trade was cancelled.".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::TradeCompleted => {
+                            trade.clear();
+                            interface.close_window_with_class(&mut focus_state, TradeWindow::WINDOW_CLASS);
+
+                            chat_messages.push(ChatMessage {
+                                text: "The trade was completed.".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::SetStorage { items } => {
+                            storage.fill(&mut game_file_loader, &mut texture_loader, &script_loader, items);
+                            interface.open_window(&application, &mut focus_state, &StorageWindow::new(storage.item_remote()));
+                        }
+                        NetworkEvent::StorageItemAdded { item } => {
+                            storage.add_item(&mut game_file_loader, &mut texture_loader, &script_loader, item);
+                        }
+                        NetworkEvent::StorageItemRemoved { index, amount } => {
+                            storage.remove_item(InventoryIndex(index.0), amount as u16);
+                        }
+                        NetworkEvent::StorageClosed => {
+                            interface.close_window_with_class(&mut focus_state, StorageWindow::WINDOW_CLASS);
+                        }
+                        NetworkEvent::SetCart { items } => {
+                            merchant_cart.fill(&mut game_file_loader, &mut texture_loader, &script_loader, items);
+                        }
+                        NetworkEvent::CartItemRemoved { index, amount } => {
+                            merchant_cart.remove_item(index, amount as u16);
+                        }
+                        NetworkEvent::OpenVendingSetup { maximum_items } => {
+                            let items = merchant_cart.get_items().clone();
+                            interface.open_window(&application, &mut focus_state, &VendingSetupWindow::new(items, maximum_items));
+                        }
+                        NetworkEvent::VendingShopOpened => {
+                            interface.close_window_with_class(&mut focus_state, VendingSetupWindow::WINDOW_CLASS);
+                            interface.open_window(&application, &mut focus_state, &VendingShopWindow::new(vending_title.clone()));
+                        }
+                        NetworkEvent::VendingShopBoard { entity_id, title } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_vending_title(Some(title));
+                            }
+                        }
+                        NetworkEvent::VendingShopBoardRemoved { entity_id } => {
+                            if let Some(entity) = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id) {
+                                entity.set_vending_title(None);
+                            }
+                        }
+                        NetworkEvent::VendingItemList { entity_id, unique_id, items } => {
+                            vending_shop = Some(VendingShop::new(entity_id, unique_id, &items));
+
+                            shop_items.mutate(|shop_items| *shop_items = items.into_iter().map(|item| {
+                                script_loader.load_market_item_metadata(&mut game_file_loader, &mut texture_loader, item.item)
+                            }).collect());
+
+                            let cart = PlainTrackedState::default();
+
+                            interface.open_window(&application, &mut focus_state, &BuyWindow::new(shop_items.new_remote(), cart.clone()));
+                            interface.open_window(&application, &mut focus_state, &BuyCartWindow::new(cart));
+                        }
+                        NetworkEvent::NewMail => {
+                            chat_messages.push(ChatMessage {
+                                text: "You have unread mail.".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::UnreadMailCount { count } => {
+                            mailbox.set_unread_count(count);
+                        }
+                        NetworkEvent::MailList { mails, is_end } => {
+                            mailbox.add_mails(mails, is_end);
+                        }
+                        NetworkEvent::MailContent { mail } => {
+                            let items = mail.items.into_iter().map(|item| {
+                                script_loader.load_inventory_item_metadata(&mut game_file_loader, &mut texture_loader, item)
+                            }).collect();
+                            let mail = korangar_networking::Mail {
+                                mail_type: mail.mail_type,
+                                mail_id: mail.mail_id,
+                                text: mail.text,
+                                zeny: mail.zeny,
+                                items,
+                            };
+
+                            mailbox.open_mail(mail);
+                            interface.open_window(&application, &mut focus_state, &MailWindow::new(mailbox.opened_mail_remote()));
+                        }
+                        NetworkEvent::MailDeleted { mail_id } => {
+                            mailbox.remove_mail(mail_id);
+                        }
+                        NetworkEvent::MailZenyTaken { mail_id } => {
+                            mailbox.zeny_taken(mail_id);
+                        }
+                        NetworkEvent::MailItemsTaken { mail_id } => {
+                            mailbox.items_taken(mail_id);
+                        }
+                        NetworkEvent::MailReceiver { character_id } => match mailbox.take_pending_mail(character_id) {
+                            Some((mail, character_id)) => {
+                                let PendingMail { receiver_name, title, text, zeny } = mail;
+                                let sender_name = saved_player_name.clone();
+                                let _ = networking_system.send_mail(receiver_name, sender_name, character_id, title, text, zeny);
+                            }
+                            None => {
+                                chat_messages.push(ChatMessage {
+                                    text: "The receiver of the mail does not exist.".to_owned(),
+                                    color: MessageColor::Error,
+                                });
+                            }
+                        },
+                        NetworkEvent::MailItemAttached { item } => {
+                            let item = script_loader.load_inventory_item_metadata(&mut game_file_loader, &mut texture_loader, item);
+                            mailbox.add_attachment(item);
+                        }
+                        NetworkEvent::MailItemDetached { index, .. } => {
+                            mailbox.remove_attachment(index);
+                        }
+                        NetworkEvent::MailSent => {
+                            mailbox.clear_attachments();
+                            interface.close_window_with_class(&mut focus_state, WriteMailWindow::WINDOW_CLASS);
+
+                            chat_messages.push(ChatMessage {
+                                text: "The mail was sent.".to_owned(),
+                                color: MessageColor::Information,
+                            });
+                        }
+                        NetworkEvent::AchievementList { summary, achievements: list } => {
+                            achievements.fill(&script_loader, summary, list);
+                        }
+                        NetworkEvent::AchievementUpdate { summary, achievement } => {
+                            if let Some(entry) = achievements.update(&script_loader, summary, achievement) {
+                                let title = entry.get_title();
+
+                                chat_messages.push(ChatMessage {
+                                    text: format!("Achievement unlocked: {title}"),
+                                    color: MessageColor::Information,
+                                });
+                                achievement_toast = Some((title, client_tick));
+                            }
+                        }
+                        NetworkEvent::AchievementRewarded { achievement_id } => {
+                            achievements.set_rewarded(achievement_id);
+                        }
                         NetworkEvent::SkillTree(skill_information) => {
                             player_skill_tree.fill(&mut game_file_loader, &mut sprite_loader, &mut action_loader, skill_information);
                         }
@@ -1083,6 +1592,8 @@ fn main() {
                             saved_username = username.clone();
                             saved_password = password.clone();
 
+                            let packet_version = service.packet_version.map(PacketVersion).unwrap_or_default();
+                            networking_system.set_packet_version(packet_version);
                             networking_system.connect_to_login_server(socket_address, username, password);
                         }
                         UserEvent::SelectServer(server) => {
@@ -1143,6 +1654,30 @@ fn main() {
                         UserEvent::OpenFriendsWindow => {
                             interface.open_window(&application, &mut focus_state, &FriendsWindow::new(friend_list.new_remote()));
                         }
+                        UserEvent::OpenEmotionsWindow => interface.open_window(&application, &mut focus_state, &EmotionsWindow),
+                        UserEvent::OpenStatusWindow => {
+                            interface.open_window(&application, &mut focus_state, &StatusWindow::new(status_tracker.get_status()));
+                        }
+                        UserEvent::OpenQuestWindow => {
+                            interface.open_window(&application, &mut focus_state, &QuestWindow::new(quest_journal.get_quests()));
+                        }
+                        UserEvent::OpenAchievementWindow => {
+                            interface.open_window(&application, &mut focus_state, &AchievementWindow::new(achievements.get_remote()));
+                        }
+                        UserEvent::ClaimAchievementReward(achievement_id) => {
+                            let _ = networking_system.request_achievement_reward(achievement_id);
+                        }
+                        UserEvent::OpenPartyWindow => {
+                            interface.open_window(&application, &mut focus_state, &PartyWindow::new(party_members.new_remote()));
+                        }
+                        UserEvent::OpenGuildWindow => {
+                            if guild_roster.get_guild_id().is_some() {
+                                let _ = networking_system.request_guild_menu(GuildMenuType::Information);
+                                let _ = networking_system.request_guild_menu(GuildMenuType::MemberList);
+                            }
+
+                            interface.open_window(&application, &mut focus_state, &GuildWindow::new(guild_roster.get_details()));
+                        }
                         UserEvent::ToggleShowInterface => show_interface = !show_interface,
                         UserEvent::SetThemeFile { theme_file, theme_kind } => application.set_theme_file(theme_file, theme_kind),
                         UserEvent::SaveTheme { theme_kind } => application.save_theme(theme_kind),
@@ -1151,16 +1686,58 @@ fn main() {
                             let _ = networking_system.select_character(character_slot);
                         },
                         UserEvent::OpenCharacterCreationWindow(character_slot) => {
-                            interface.open_window(&application, &mut focus_state, &CharacterCreationWindow::new(character_slot))
-                        }
-                        UserEvent::CreateCharacter(character_slot, name) => {
-                            let _ = networking_system.create_character(character_slot, name);
+                            let sprites = CharacterSprites::load(
+                                &mut game_file_loader,
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                NOVICE_JOB_ID,
+                                DEFAULT_SEX,
+                                DEFAULT_HAIR_STYLE,
+                                DEFAULT_HAIR_COLOR,
+                            );
+                            character_preview.set(sprites.ok());
+
+                            interface.open_window(
+                                &application,
+                                &mut focus_state,
+                                &CharacterCreationWindow::new(character_slot, character_preview.clone()),
+                            )
+                        }
+                        UserEvent::UpdateCharacterPreview {
+                            start_job,
+                            sex,
+                            hair_style,
+                            hair_color,
+                        } => {
+                            let sprites = CharacterSprites::load(
+                                &mut game_file_loader,
+                                &mut sprite_loader,
+                                &mut action_loader,
+                                start_job,
+                                sex,
+                                hair_style,
+                                hair_color,
+                            );
+                            character_preview.set(sprites.ok());
+                        }
+                        UserEvent::CreateCharacter {
+                            slot,
+                            name,
+                            hair_color,
+                            hair_style,
+                            start_job,
+                            sex,
+                        } => {
+                            let _ = networking_system.create_character(slot, name, hair_color, hair_style, start_job, sex);
                         },
-                        UserEvent::DeleteCharacter(character_id) => {
-                            if currently_deleting.is_none() {
-                                let _ = networking_system.delete_character(character_id);
-                                currently_deleting = Some(character_id);
-                            }
+                        UserEvent::ReserveCharacterDeletion(character_id) => {
+                            let _ = networking_system.reserve_character_deletion(character_id);
+                        },
+                        UserEvent::AcceptCharacterDeletion { character_id, birthdate } => {
+                            let _ = networking_system.accept_character_deletion(character_id, birthdate);
+                        },
+                        UserEvent::CancelCharacterDeletion(character_id) => {
+                            let _ = networking_system.cancel_character_deletion(character_id);
                         },
                         UserEvent::RequestSwitchCharacterSlot(origin_slot) => move_request.set(Some(origin_slot)),
                         UserEvent::CancelSwitchCharacterSlot => move_request.set(None),
@@ -1173,6 +1750,7 @@ fn main() {
                             }
                         }
                         UserEvent::RequestPlayerInteract(entity_id) => {
+                            let player_entity_id = entities.first().map(Entity::get_entity_id);
                             let entity = entities.iter_mut().find(|entity| entity.get_entity_id() == entity_id);
 
                             if let Some(entity) = entity {
@@ -1183,6 +1761,14 @@ fn main() {
                                         let position = entity.get_grid_position();
                                         WorldPosition { x: position.x, y: position.y }
                                     }),
+                                    EntityType::Player if entity.get_vending_title().is_some() => {
+                                        networking_system.request_vending_items(entity_id)
+                                    }
+                                    EntityType::Player if player_entity_id != Some(entity_id) => {
+                                        let name = entity.get_details().and_then(|details| details.split('#').next());
+                                        trade.set_partner_name(name.unwrap_or_default().to_owned());
+                                        networking_system.request_trade(entity_id)
+                                    }
                                     _ => Ok(())
                                 };
                             }
@@ -1191,11 +1777,58 @@ fn main() {
                             let _ = networking_system.warp_to_map(map_name, position);
                         },
                         UserEvent::SendMessage(message) => {
-                            let _ = networking_system.send_chat_message(&saved_player_name, &message);
+                            match ChatCommand::parse(&message) {
+                                Ok(ChatCommand::Message(message)) => {
+                                    let _ = networking_system.send_chat_message(&saved_player_name, message);
+                                }
+                                Ok(ChatCommand::PartyMessage(message)) => {
+                                    let _ = networking_system.send_party_message(&saved_player_name, message);
+                                }
+                                Ok(ChatCommand::GuildMessage(message)) => {
+                                    let _ = networking_system.send_guild_message(&saved_player_name, message);
+                                }
+                                Ok(ChatCommand::Emotion(emotion)) => {
+                                    let _ = networking_system.send_emotion(emotion);
+                                }
+                                Ok(ChatCommand::Whisper { name, message }) => {
+                                    let _ = networking_system.send_whisper(name.to_owned(), message.to_owned());
+
+                                    let conversation = whisper_conversations.entry(name.to_owned()).or_default();
+                                    conversation.push(ChatMessage {
+                                        text: format!("{saved_player_name} : {message}"),
+                                        color: MessageColor::Whisper,
+                                    });
+
+                                    chat_messages.push(ChatMessage {
+                                        text: format!("(To {name}) : {message}"),
+                                        color: MessageColor::Whisper,
+                                    });
+
+                                    let whisper_window = WhisperWindow::new(name.to_owned(), conversation.new_remote(), font_loader.clone());
+                                    interface.open_window(&application, &mut focus_state, &whisper_window);
+                                }
+                                Err(usage) => chat_messages.push(ChatMessage {
+                                    text: usage.to_owned(),
+                                    color: MessageColor::Error,
+                                }),
+                            }
+
                             // TODO: maybe find a better solution for unfocusing the message box if
                             // this becomes problematic
                             focus_state.remove_focus();
                         }
+                        UserEvent::SendEmotion(emotion) => {
+                            let _ = networking_system.send_emotion(emotion);
+                        }
+                        UserEvent::SendWhisper { name, message } => {
+                            let _ = networking_system.send_whisper(name.clone(), message.clone());
+
+                            let conversation = whisper_conversations.entry(name).or_default();
+                            conversation.push(ChatMessage {
+                                text: format!("{saved_player_name} : {message}"),
+                                color: MessageColor::Whisper,
+                            });
+                        }
                         UserEvent::NextDialog(npc_id) => {
                             let _ = networking_system.next_dialog(npc_id);
                         },
@@ -1212,6 +1845,16 @@ fn main() {
                                 interface.close_window_with_class(&mut focus_state, DialogWindow::WINDOW_CLASS);
                             }
                         }
+                        UserEvent::UseItem(index) => {
+                            let account_id = saved_login_data.as_ref().unwrap().account_id;
+                            let _ = networking_system.use_item(index, account_id);
+                        }
+                        UserEvent::DropItem { index, amount } => {
+                            let _ = networking_system.drop_item(index, amount);
+                        }
+                        UserEvent::PickUpItem(ground_item_id) => {
+                            let _ = networking_system.pick_up_item(ground_item_id);
+                        }
                         UserEvent::MoveResource(r#move) => {
                             match r#move {
                                 Move::Item { source, destination, item } => match (source, destination) {
@@ -1221,6 +1864,47 @@ fn main() {
                                     (ItemSource::Equipment { .. }, ItemSource::Inventory) => {
                                         let _ = networking_system.request_item_unequip(item.index);
                                     }
+                                    (ItemSource::Inventory, ItemSource::Trade) => {
+                                        let amount = match &item.details {
+                                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.add_trade_item(item.index, amount);
+                                        trade.add_pending_item(item);
+                                    }
+                                    (ItemSource::Inventory, ItemSource::Storage) => {
+                                        let amount = match &item.details {
+                                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.move_item_to_storage(item.index, amount);
+                                    }
+                                    (ItemSource::Storage, ItemSource::Inventory) => {
+                                        let amount = match &item.details {
+                                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount as u32,
+                                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.move_item_from_storage(StorageIndex(item.index.0), amount);
+                                    }
+                                    (ItemSource::Inventory, ItemSource::Mail) => {
+                                        let amount = match &item.details {
+                                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.attach_mail_item(item.index, amount);
+                                    }
+                                    (ItemSource::Mail, ItemSource::Inventory) => {
+                                        let amount = match &item.details {
+                                            korangar_networking::InventoryItemDetails::Regular { amount, .. } => *amount,
+                                            korangar_networking::InventoryItemDetails::Equippable { .. } => 1,
+                                        };
+
+                                        let _ = networking_system.detach_mail_item(item.index, amount);
+                                    }
                                     _ => {}
                                 },
                                 Move::Skill {
@@ -1300,11 +1984,155 @@ fn main() {
                             let _ = networking_system.accept_friend_request(account_id, character_id);
                             interface.close_window_with_class(&mut focus_state, FriendRequestWindow::WINDOW_CLASS);
                         }
-                        UserEvent::BuyItems { items } => {
-                            let _ = networking_system.purchase_items(items);
+                        UserEvent::CreateParty(name) => {
+                            if name.len() > 24 {
+                                #[cfg(feature = "debug")]
+                                print_debug!("[{}] party name {} is too long", "error".red(), name.magenta());
+                            } else {
+                                let _ = networking_system.create_party(name);
+                            }
                         }
+                        UserEvent::InviteToParty(name) => {
+                            if name.len() > 24 {
+                                #[cfg(feature = "debug")]
+                                print_debug!("[{}] character name {} is too long", "error".red(), name.magenta());
+                            } else {
+                                let _ = networking_system.invite_to_party(name);
+                            }
+                        }
+                        UserEvent::LeaveParty => {
+                            let _ = networking_system.leave_party();
+                        }
+                        UserEvent::ExpelPartyMember { account_id, name } => {
+                            let _ = networking_system.expel_party_member(account_id, name);
+                        }
+                        UserEvent::ChangePartyLeader(account_id) => {
+                            let _ = networking_system.change_party_leader(account_id);
+                        }
+                        UserEvent::RejectPartyInvitation(party_id) => {
+                            let _ = networking_system.reject_party_invitation(party_id);
+                            interface.close_window_with_class(&mut focus_state, PartyInvitationWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::AcceptPartyInvitation(party_id) => {
+                            let _ = networking_system.accept_party_invitation(party_id);
+                            interface.close_window_with_class(&mut focus_state, PartyInvitationWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::InviteToGuild(name) => {
+                            let _ = networking_system.invite_to_guild(name);
+                        }
+                        UserEvent::LeaveGuild => {
+                            let account_id = saved_login_data.as_ref().unwrap().account_id;
+
+                            if let (Some(guild_id), Some(member)) = (guild_roster.get_guild_id(), guild_roster.find_member(account_id)) {
+                                let _ = networking_system.leave_guild(guild_id, account_id, member.character_id, String::new());
+                            }
+                        }
+                        UserEvent::RejectGuildInvitation(guild_id) => {
+                            let _ = networking_system.reject_guild_invitation(guild_id);
+                            interface.close_window_with_class(&mut focus_state, GuildInvitationWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::AcceptGuildInvitation(guild_id) => {
+                            let _ = networking_system.accept_guild_invitation(guild_id);
+                            interface.close_window_with_class(&mut focus_state, GuildInvitationWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::RejectTradeRequest => {
+                            let _ = networking_system.reject_trade_request();
+                            interface.close_window_with_class(&mut focus_state, TradeRequestWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::AcceptTradeRequest => {
+                            let _ = networking_system.accept_trade_request();
+                            interface.close_window_with_class(&mut focus_state, TradeRequestWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::OfferTradeZeny(amount) => {
+                            trade.set_pending_zeny(amount);
+                            let _ = networking_system.add_trade_zeny(amount);
+                        }
+                        UserEvent::LockTrade => {
+                            let _ = networking_system.lock_trade();
+                        }
+                        UserEvent::CommitTrade => {
+                            let _ = networking_system.commit_trade();
+                        }
+                        UserEvent::CancelTrade => {
+                            let _ = networking_system.cancel_trade();
+                        }
+                        UserEvent::CloseStorage => {
+                            let _ = networking_system.close_storage();
+                        }
+                        UserEvent::OpenVendingShop { title, items } => {
+                            let _ = networking_system.open_vending_shop(title.clone(), items);
+                            vending_title = title;
+                        }
+                        UserEvent::CancelVendingSetup => {
+                            let _ = networking_system.cancel_vending_setup();
+                            interface.close_window_with_class(&mut focus_state, VendingSetupWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::CloseVendingShop => {
+                            let _ = networking_system.close_vending_shop();
+                            interface.close_window_with_class(&mut focus_state, VendingShopWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::OpenMailbox => {
+                            mailbox.clear();
+
+                            let _ = networking_system.open_mailbox();
+                            interface.open_window(&application, &mut focus_state, &MailboxWindow::new(mailbox.list_remote()));
+                        }
+                        UserEvent::CloseMailbox => {
+                            let _ = networking_system.close_mailbox();
+                            mailbox.clear_attachments();
+
+                            interface.close_window_with_class(&mut focus_state, MailboxWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, MailWindow::WINDOW_CLASS);
+                            interface.close_window_with_class(&mut focus_state, WriteMailWindow::WINDOW_CLASS);
+                        }
+                        UserEvent::RequestMoreMails => {
+                            if let Some(mail) = mailbox.get_last_mail() {
+                                let _ = networking_system.request_more_mails(mail.mail_type, mail.mail_id);
+                            }
+                        }
+                        UserEvent::ReadMail { mail_type, mail_id } => {
+                            let _ = networking_system.read_mail(mail_type, mail_id);
+                        }
+                        UserEvent::DeleteMail { mail_type, mail_id } => {
+                            let _ = networking_system.delete_mail(mail_type, mail_id);
+                        }
+                        UserEvent::TakeMailZeny { mail_type, mail_id } => {
+                            let _ = networking_system.take_mail_zeny(mail_type, mail_id);
+                        }
+                        UserEvent::TakeMailItems { mail_type, mail_id } => {
+                            let _ = networking_system.take_mail_items(mail_type, mail_id);
+                        }
+                        UserEvent::OpenWriteMail => {
+                            let _ = networking_system.start_writing_mail();
+                            mailbox.clear_attachments();
+
+                            interface.open_window(&application, &mut focus_state, &WriteMailWindow::new(mailbox.attachments_remote()));
+                        }
+                        UserEvent::SendMail { receiver_name, title, text, zeny } => {
+                            let _ = networking_system.check_mail_receiver(receiver_name.clone());
+                            mailbox.set_pending_mail(PendingMail { receiver_name, title, text, zeny });
+                        }
+                        UserEvent::IncreaseStatus(status_type) => {
+                            let _ = networking_system.request_status_increase(status_type);
+                        }
+                        UserEvent::BuyItems { items } => match vending_shop.take() {
+                            Some(vending_shop) => {
+                                let items = vending_shop.purchase_information(&items);
+                                let entity_id = vending_shop.get_entity_id();
+                                let _ = networking_system.buy_vending_items(entity_id, vending_shop.get_unique_id(), items);
+
+                                interface.close_window_with_class(&mut focus_state, BuyWindow::WINDOW_CLASS);
+                                interface.close_window_with_class(&mut focus_state, BuyCartWindow::WINDOW_CLASS);
+                            }
+                            None => {
+                                let _ = networking_system.purchase_items(items);
+                            }
+                        },
                         UserEvent::CloseShop => {
-                            let _ = networking_system.close_shop();
+                            // Vending shops of other players don't need to be closed on the server.
+                            if vending_shop.take().is_none() {
+                                let _ = networking_system.close_shop();
+                            }
 
                             interface.close_window_with_class(&mut focus_state, BuyWindow::WINDOW_CLASS);
                             interface.close_window_with_class(&mut focus_state, BuyCartWindow::WINDOW_CLASS);
@@ -1360,6 +2188,12 @@ fn main() {
                         #[cfg(feature = "debug")]
                         UserEvent::ClearPacketHistory => packet_callback.clear_all(),
                         #[cfg(feature = "debug")]
+                        UserEvent::OpenNetworkStatisticsWindow => interface.open_window(
+                            &application,
+                            &mut focus_state,
+                            &NetworkStatisticsWindow::new(networking_system.statistics_handle()),
+                        ),
+                        #[cfg(feature = "debug")]
                         UserEvent::CameraLookAround(offset) => debug_camera.look_around(offset),
                         #[cfg(feature = "debug")]
                         UserEvent::CameraMoveForward => debug_camera.move_forward(delta_time as f32),
@@ -1392,6 +2226,8 @@ fn main() {
                     .iter_mut()
                     .for_each(|entity| entity.update(&map, delta_time as f32, client_tick));
 
+                ground_items.iter_mut().for_each(|ground_item| ground_item.update(client_tick));
+
                 #[cfg(feature = "debug")]
                 update_entities_measurement.stop();
 
@@ -1544,6 +2380,7 @@ fn main() {
                 let window_size = swapchain_holder.window_screen_size();
                 let window_size_u32 = swapchain_holder.window_size_u32();
                 let entities = &entities[..];
+                let ground_items = &ground_items[..];
                 #[cfg(feature = "debug")]
                 let hovered_marker_identifier = match mouse_target {
                     Some(PickerTarget::Marker(marker_identifier)) => Some(marker_identifier),
@@ -1565,6 +2402,9 @@ fn main() {
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_map))]
                         map.render_tiles(picker_target, &picker_renderer, current_camera);
 
+                        #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
+                        map.render_ground_items(ground_items, picker_target, &picker_renderer, current_camera);
+
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
                         map.render_entities(entities, picker_target, &picker_renderer, current_camera, false);
 
@@ -1658,6 +2498,9 @@ fn main() {
                             render_settings.frustum_culling,
                         );
 
+                        #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
+                        map.render_ground_items(ground_items, screen_target, &deferred_renderer, current_camera);
+
                         #[cfg_attr(feature = "debug", korangar_debug::debug_condition(render_settings.show_entities))]
                         map.render_entities(entities, screen_target, &deferred_renderer, current_camera, true);
 
@@ -1806,7 +2649,77 @@ fn main() {
                                 Color::monochrome_u8(255),
                                 FontSize::new(12.0),
                             );
+
+                            if let Some(guild_name) = entity.get_guild_name() {
+                                let offset = ScreenPosition {
+                                    left: guild_name.len() as f32 * -3.0,
+                                    top: 34.0,
+                                };
+
+                                deferred_renderer.render_text(
+                                    screen_target,
+                                    guild_name,
+                                    input_system.get_mouse_position() + offset + ScreenPosition::uniform(1.0),
+                                    Color::monochrome_u8(0),
+                                    FontSize::new(12.0),
+                                );
+
+                                deferred_renderer.render_text(
+                                    screen_target,
+                                    guild_name,
+                                    input_system.get_mouse_position() + offset,
+                                    Color::rgb_u8(180, 255, 180),
+                                    FontSize::new(12.0),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render vending boards");
+
+                    entities
+                        .iter()
+                        .for_each(|entity| entity.render_vending_board(screen_target, &deferred_renderer, current_camera, window_size));
+                }
+
+                {
+                    #[cfg(feature = "debug")]
+                    profile_block!("render emotions");
+
+                    entities.iter().for_each(|entity| {
+                        entity.render_emotion(screen_target, &deferred_renderer, current_camera, window_size, &application)
+                    });
+                }
+
+                if let Some((title, start_tick)) = &achievement_toast {
+                    // TODO: move variables into theme
+                    const TOAST_DURATION: u32 = 5000;
+
+                    match client_tick.0.wrapping_sub(start_tick.0) < TOAST_DURATION {
+                        true => {
+                            let text = format!("Achievement unlocked: {title}");
+                            let toast_size = ScreenSize {
+                                width: text.len() as f32 * 8.0 + 20.0,
+                                height: 30.0,
+                            };
+                            let toast_position = ScreenPosition {
+                                left: (window_size.width - toast_size.width) / 2.0,
+                                top: 60.0,
+                            };
+
+                            deferred_renderer.render_rectangle(screen_target, toast_position, toast_size, Color::rgba_u8(40, 40, 40, 220));
+                            deferred_renderer.render_text(
+                                screen_target,
+                                &text,
+                                toast_position + ScreenPosition { left: 10.0, top: 7.0 },
+                                Color::rgb_u8(255, 220, 120),
+                                FontSize::new(16.0),
+                            );
                         }
+                        false => achievement_toast = None,
                     }
                 }
 
@@ -1821,6 +2734,9 @@ fn main() {
                         application.get_game_theme(),
                         window_size,
                     );
+
+                    entities[0].render_buff_bar(screen_target, &deferred_renderer, application.get_game_theme(), client_tick);
+                    entities[0].render_experience_bars(screen_target, &deferred_renderer, application.get_game_theme(), window_size);
                 }
 
                 #[cfg(feature = "debug")]
@@ -1,6 +1,7 @@
 use std::time::Instant;
 
 use chrono::prelude::*;
+use korangar_networking::{TickSample, TickSynchronizer};
 use ragnarok_packets::ClientTick;
 
 pub struct GameTimer {
@@ -11,11 +12,21 @@ pub struct GameTimer {
     frames_per_second: usize,
     animation_timer: f32,
     day_timer: f32,
-    last_client_tick: Instant,
-    base_client_tick: u32,
+    /// Difference between the client tick and the milliseconds elapsed on
+    /// the global timer.
+    tick_offset: f64,
+    /// Offset that the [`tick_offset`](Self::tick_offset) is slewed towards.
+    target_tick_offset: f64,
+    tick_synchronizer: TickSynchronizer,
 }
 
 const TIME_FACTOR: f32 = 1000.0;
+/// Fraction by which the client tick may run faster or slower than real time
+/// while it is catching up with the server.
+const SLEW_RATE: f64 = 0.1;
+/// Differences to the server tick that are larger than this (in milliseconds)
+/// are corrected immediately instead of being slewed.
+const MAXIMUM_SLEW: f64 = 1000.0;
 
 impl GameTimer {
     pub fn new() -> Self {
@@ -30,19 +41,58 @@ impl GameTimer {
             frames_per_second: Default::default(),
             animation_timer: Default::default(),
             day_timer,
-            last_client_tick: Instant::now(),
-            base_client_tick: 0,
+            tick_offset: 0.0,
+            target_tick_offset: 0.0,
+            tick_synchronizer: TickSynchronizer::default(),
         }
     }
 
+    fn elapsed_milliseconds(&self, instant: Instant) -> f64 {
+        instant.saturating_duration_since(self.global_timer).as_secs_f64() * 1000.0
+    }
+
+    fn client_tick_at(&self, instant: Instant) -> ClientTick {
+        ClientTick((self.elapsed_milliseconds(instant) + self.tick_offset) as i64 as u32)
+    }
+
+    /// Jump to the given tick. Previous samples are discarded, since the tick
+    /// is usually set after connecting to a new map server.
     pub fn set_client_tick(&mut self, client_tick: ClientTick) {
-        self.last_client_tick = Instant::now();
-        self.base_client_tick = client_tick.0;
+        let current_tick = self.client_tick_at(Instant::now());
+        let difference = client_tick.0.wrapping_sub(current_tick.0) as i32 as f64;
+
+        self.tick_offset += difference;
+        self.target_tick_offset = self.tick_offset;
+        self.tick_synchronizer.clear();
+    }
+
+    /// Update the estimate of the server tick. The client tick is slewed
+    /// towards the new estimate in [`update`](Self::update), so that it never
+    /// jumps or runs backwards for small corrections.
+    pub fn add_tick_sample(&mut self, tick_sample: TickSample) {
+        self.add_tick_sample_at(tick_sample, Instant::now());
+    }
+
+    fn add_tick_sample_at(&mut self, tick_sample: TickSample, now: Instant) {
+        self.tick_synchronizer.add_sample(tick_sample);
+
+        let Some(server_tick) = self.tick_synchronizer.server_tick_at(now) else {
+            return;
+        };
+
+        let current_tick = self.client_tick_at(now);
+        let difference = server_tick.0.wrapping_sub(current_tick.0) as i32 as f64;
+
+        self.target_tick_offset = self.tick_offset + difference;
+
+        if difference.abs() > MAXIMUM_SLEW {
+            self.tick_offset = self.target_tick_offset;
+        }
     }
 
     #[cfg_attr(feature = "debug", korangar_debug::profile)]
     pub fn get_client_tick(&self) -> ClientTick {
-        ClientTick(self.last_client_tick.elapsed().as_millis() as u32 + self.base_client_tick)
+        self.client_tick_at(Instant::now())
     }
 
     #[cfg(feature = "debug")]
@@ -68,6 +118,8 @@ impl GameTimer {
         self.animation_timer += delta_time as f32;
         self.previous_elapsed = new_elapsed;
 
+        self.slew_tick_offset(delta_time);
+
         if self.accumulate_second > 1.0 {
             self.frames_per_second = self.frame_counter;
             self.accumulate_second -= 1.0;
@@ -77,6 +129,14 @@ impl GameTimer {
         delta_time
     }
 
+    /// Move the tick offset towards the target offset, by at most the slew
+    /// rate of the time that passed (in seconds).
+    fn slew_tick_offset(&mut self, delta_time: f64) {
+        let maximum_correction = delta_time * 1000.0 * SLEW_RATE;
+        let correction = (self.target_tick_offset - self.tick_offset).clamp(-maximum_correction, maximum_correction);
+        self.tick_offset += correction;
+    }
+
     #[cfg(feature = "debug")]
     pub fn last_frames_per_second(&self) -> usize {
         self.frames_per_second
@@ -111,3 +171,66 @@ mod increment {
         assert!(updated_animation_timer > animation_timer);
     }
 }
+
+#[cfg(test)]
+mod synchronization {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// A sample that was answered instantly by a server that is
+    /// `server_offset` milliseconds ahead.
+    fn sample_at(game_timer: &GameTimer, instant: Instant, server_offset: i64) -> TickSample {
+        let server_tick = game_timer.client_tick_at(instant).0 as i64 + server_offset;
+
+        TickSample {
+            sent_at: instant,
+            received_at: instant,
+            server_tick: ClientTick(server_tick as u32),
+        }
+    }
+
+    fn tick_difference(left: ClientTick, right: ClientTick) -> i32 {
+        left.0.wrapping_sub(right.0) as i32
+    }
+
+    #[test]
+    fn set_client_tick_jumps() {
+        let mut game_timer = GameTimer::new();
+
+        game_timer.set_client_tick(ClientTick(50000));
+        assert!(tick_difference(game_timer.get_client_tick(), ClientTick(50000)) < 100);
+    }
+
+    #[test]
+    fn small_differences_are_slewed() {
+        let mut game_timer = GameTimer::new();
+        let now = game_timer.global_timer + Duration::from_secs(10);
+
+        game_timer.add_tick_sample_at(sample_at(&game_timer, now, 200), now);
+        assert_eq!(game_timer.client_tick_at(now), ClientTick(10000));
+        assert_eq!(game_timer.target_tick_offset, 200.0);
+
+        // The tick may only run faster by the slew rate.
+        game_timer.slew_tick_offset(0.05);
+        assert!((game_timer.tick_offset - 50.0 * SLEW_RATE).abs() < 1e-9);
+
+        game_timer.slew_tick_offset(1.0);
+        assert!((game_timer.tick_offset - (1050.0 * SLEW_RATE)).abs() < 1e-9);
+
+        // Once the target is reached, the tick runs at real time again.
+        game_timer.slew_tick_offset(10.0);
+        assert!((game_timer.tick_offset - 200.0).abs() < 1e-9);
+        game_timer.slew_tick_offset(10.0);
+        assert!((game_timer.tick_offset - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn large_differences_jump() {
+        let mut game_timer = GameTimer::new();
+        let now = game_timer.global_timer + Duration::from_secs(10);
+
+        game_timer.add_tick_sample_at(sample_at(&game_timer, now, -5000), now);
+        assert_eq!(game_timer.client_tick_at(now), ClientTick(5000));
+    }
+}
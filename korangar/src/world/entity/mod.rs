@@ -2,21 +2,24 @@ use std::sync::Arc;
 
 use cgmath::{Array, Vector2, Vector3, VectorSpace};
 use derive_new::new;
+use korangar_interface::application::{ClipTraitExt, FontSizeTrait};
 use korangar_interface::elements::PrototypeElement;
 use korangar_interface::windows::{PrototypeWindow, Window};
-use korangar_networking::EntityData;
+use korangar_networking::{EntityData, StatusEffect};
 use ragnarok_formats::map::TileFlags;
-use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, EntityId, Sex, StatusType, WorldPosition};
+use ragnarok_packets::{AccountId, CharacterInformation, ClientTick, EmotionId, EntityId, Sex, StatusType, WorldPosition};
 use vulkano::buffer::Subbuffer;
+use vulkano::image::view::ImageView;
 
 #[cfg(feature = "debug")]
 use crate::graphics::MarkerRenderer;
-use crate::graphics::{Camera, DeferredRenderer, EntityRenderer, ModelVertex, Renderer};
+use crate::graphics::{Camera, Color, DeferredRenderer, EntityRenderer, ModelVertex, PickerTarget, Renderer, SpriteRenderer};
 use crate::interface::application::InterfaceSettings;
-use crate::interface::layout::{ScreenPosition, ScreenSize};
+use crate::interface::layout::{ScreenClip, ScreenPosition, ScreenSize};
 use crate::interface::theme::GameTheme;
 use crate::interface::windows::WindowCache;
-use crate::loaders::{ActionLoader, Actions, AnimationState, GameFileLoader, ScriptLoader, Sprite, SpriteLoader};
+use crate::loaders::error::LoadError;
+use crate::loaders::{ActionLoader, Actions, AnimationState, FontSize, GameFileLoader, ScriptLoader, Sprite, SpriteLoader};
 use crate::world::Map;
 #[cfg(feature = "debug")]
 use crate::world::MarkerIdentifier;
@@ -47,6 +50,40 @@ pub struct Movement {
     pub steps_vertex_buffer: Option<Subbuffer<[ModelVertex]>>,
 }
 
+#[derive(Clone)]
+pub struct ActiveStatusEffect {
+    pub status_id: u16,
+    pub icon: Option<Arc<ImageView>>,
+    pub total_duration: Option<u32>,
+    /// Client tick at which the effect runs out. `None` if the effect does not
+    /// expire on its own.
+    pub end_tick: Option<ClientTick>,
+}
+
+impl ActiveStatusEffect {
+    /// Remaining duration in milliseconds.
+    pub fn remaining_duration(&self, client_tick: ClientTick) -> Option<u32> {
+        self.end_tick.map(|end_tick| end_tick.0.saturating_sub(client_tick.0))
+    }
+}
+
+/// Path of the sprite and actions containing all emotions, relative to
+/// `data\\sprite`.
+const EMOTION_FILE_PATH: &str = "ÀÌÆÑÆ®\\emotion";
+/// Minimum time in milliseconds that an emotion stays above the head of an
+/// entity. Emotions are usually shown until their animation played once, but
+/// some files don't specify a delay for their actions.
+const MINIMUM_EMOTION_DURATION: u32 = 1000;
+
+/// Emotion bubble shown above the head of an entity.
+struct ActiveEmotion {
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    emotion: EmotionId,
+    animation_state: AnimationState,
+    end_tick: ClientTick,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum EntityType {
     Warp,
@@ -76,7 +113,15 @@ pub struct Common {
     #[hidden_element]
     details: ResourceState<String>,
     #[hidden_element]
+    guild_name: Option<String>,
+    #[hidden_element]
+    vending_title: Option<String>,
+    #[hidden_element]
     animation_state: AnimationState,
+    #[hidden_element]
+    status_effects: Vec<ActiveStatusEffect>,
+    #[hidden_element]
+    emotion: Option<ActiveEmotion>,
 }
 
 #[cfg_attr(feature = "debug", korangar_debug::profile)]
@@ -262,6 +307,71 @@ fn get_sprite_and_actions(
     )
 }
 
+/// Job id of the novice, the regular start job.
+pub const NOVICE_JOB_ID: u16 = 0;
+/// Job id of the summoner, the start job of the doram race.
+pub const SUMMONER_JOB_ID: u16 = 4218;
+/// Appearance that is preselected when creating a new character.
+pub const DEFAULT_SEX: Sex = Sex::Male;
+pub const DEFAULT_HAIR_STYLE: u16 = 1;
+pub const DEFAULT_HAIR_COLOR: u16 = 0;
+
+/// Body and head of a player character, used to preview the character while
+/// it is being created.
+#[derive(Clone)]
+pub struct CharacterSprites {
+    pub body_sprite: Arc<Sprite>,
+    pub body_actions: Arc<Actions>,
+    pub head_sprite: Arc<Sprite>,
+    pub head_actions: Arc<Actions>,
+}
+
+impl CharacterSprites {
+    #[allow(clippy::invisible_characters)]
+    pub fn load(
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        start_job: u16,
+        sex: Sex,
+        hair_style: u16,
+        hair_color: u16,
+    ) -> Result<Self, LoadError> {
+        let sex_sprite_path = match sex == Sex::Female {
+            true => "¿©",
+            false => "³²",
+        };
+
+        let (race_path, body_path, palette_path) = match start_job {
+            SUMMONER_JOB_ID => ("µµ¶÷Á·", "¼ÒÈ¯»ç", "µµ¶÷Á·\\¸Ó¸®\\¸Ó¸®"),
+            _ => ("ÀÎ°£Á·", get_sprite_path_for_player_job(NOVICE_JOB_ID as usize), "¸Ó¸®\\¸Ó¸®"),
+        };
+
+        let body_path = format!("{race_path}\\¸öÅë\\{sex_sprite_path}\\{body_path}_{sex_sprite_path}");
+        let head_path = format!("{race_path}\\¸Ó¸®Åë\\{sex_sprite_path}\\{hair_style}_{sex_sprite_path}");
+        let head_sprite_path = format!("{head_path}.spr");
+
+        // The hair color 0 uses the palette of the sprite itself.
+        let head_sprite = match hair_color {
+            0 => sprite_loader.get(&head_sprite_path, game_file_loader)?,
+            _ => {
+                let palette_path = format!("{palette_path}{hair_style}_{sex_sprite_path}_{hair_color}.pal");
+
+                sprite_loader
+                    .get_with_palette(&head_sprite_path, &palette_path, game_file_loader)
+                    .or_else(|_| sprite_loader.get(&head_sprite_path, game_file_loader))?
+            }
+        };
+
+        Ok(Self {
+            body_sprite: sprite_loader.get(&format!("{body_path}.spr"), game_file_loader)?,
+            body_actions: action_loader.get(&format!("{body_path}.act"), game_file_loader)?,
+            head_sprite,
+            head_actions: action_loader.get(&format!("{head_path}.act"), game_file_loader)?,
+        })
+    }
+}
+
 impl Common {
     pub fn new(
         game_file_loader: &mut GameFileLoader,
@@ -306,7 +416,11 @@ impl Common {
             sex,
         );
         let details = ResourceState::Unavailable;
+        let guild_name = None;
+        let vending_title = None;
         let animation_state = AnimationState::new(client_tick);
+        let status_effects = Vec::new();
+        let emotion = None;
 
         let mut common = Self {
             grid_position,
@@ -323,7 +437,11 @@ impl Common {
             sprite,
             actions,
             details,
+            guild_name,
+            vending_title,
             animation_state,
+            status_effects,
+            emotion,
         };
 
         if let Some(destination) = entity_data.destination {
@@ -405,9 +523,183 @@ impl Common {
             }
         }
 
+        self.status_effects
+            .retain(|status_effect| status_effect.end_tick.map_or(true, |end_tick| end_tick.0 > client_tick.0));
+
+        if self.emotion.as_ref().is_some_and(|emotion| emotion.end_tick.0 <= client_tick.0) {
+            self.emotion = None;
+        }
+
+        if let Some(emotion) = &mut self.emotion {
+            emotion.animation_state.update(client_tick);
+        }
+
         self.animation_state.update(client_tick);
     }
 
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect, icon: Option<Arc<ImageView>>, client_tick: ClientTick) {
+        // Some servers send very long durations for effects that practically never
+        // expire, so we saturate instead of overflowing the tick.
+        let end_tick = status_effect
+            .remaining_duration
+            .map(|remaining_duration| ClientTick(client_tick.0.saturating_add(remaining_duration)));
+
+        self.status_effects
+            .retain(|active_effect| active_effect.status_id != status_effect.status_id);
+        self.status_effects.push(ActiveStatusEffect {
+            status_id: status_effect.status_id,
+            icon,
+            total_duration: status_effect.total_duration,
+            end_tick,
+        });
+    }
+
+    pub fn remove_status_effect(&mut self, status_id: u16) {
+        self.status_effects.retain(|active_effect| active_effect.status_id != status_id);
+    }
+
+    pub fn set_emotion(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        emotion: EmotionId,
+        client_tick: ClientTick,
+    ) {
+        let (Ok(sprite), Ok(actions)) = (
+            sprite_loader.get(&format!("{EMOTION_FILE_PATH}.spr"), game_file_loader),
+            action_loader.get(&format!("{EMOTION_FILE_PATH}.act"), game_file_loader),
+        ) else {
+            return;
+        };
+
+        // The emotion file has a single direction per action, so the emotion is the
+        // index of the action.
+        let duration = actions.action_duration(emotion.0 as usize).max(MINIMUM_EMOTION_DURATION);
+
+        self.emotion = Some(ActiveEmotion {
+            sprite,
+            actions,
+            emotion,
+            animation_state: AnimationState::new(client_tick),
+            end_tick: ClientTick(client_tick.0.saturating_add(duration)),
+        });
+    }
+
+    /// Project the position of the entity onto the screen.
+    fn screen_position(&self, camera: &dyn Camera, window_size: ScreenSize) -> ScreenPosition {
+        let (view_matrix, projection_matrix) = camera.view_projection_matrices();
+        let clip_space_position = (projection_matrix * view_matrix) * self.position.extend(1.0);
+        let screen_position = Vector2::new(
+            clip_space_position.x / clip_space_position.w + 1.0,
+            clip_space_position.y / clip_space_position.w + 1.0,
+        );
+        let screen_position = screen_position / 2.0;
+
+        ScreenPosition {
+            left: screen_position.x * window_size.width,
+            top: screen_position.y * window_size.height,
+        }
+    }
+
+    /// Render the icons of all status effects in a centered row starting at
+    /// the given position.
+    fn render_status_effect_icons(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        position: ScreenPosition,
+    ) {
+        let icon_size = theme.status_bar.status_effect_icon_size.get();
+        let icons: Vec<&Arc<ImageView>> = self
+            .status_effects
+            .iter()
+            .filter_map(|status_effect| status_effect.icon.as_ref())
+            .collect();
+        let row_width = icons.len() as f32 * icon_size;
+
+        for (index, icon) in icons.into_iter().enumerate() {
+            let icon_position = position + ScreenPosition::only_left(index as f32 * icon_size - row_width / 2.0);
+
+            renderer.render_sprite(
+                render_target,
+                icon.clone(),
+                icon_position,
+                ScreenSize::uniform(icon_size),
+                ScreenClip::unbound(),
+                Color::monochrome_u8(255),
+                true,
+            );
+        }
+    }
+
+    /// Render the title of an open vending shop above the head of the entity.
+    fn render_vending_board(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        let Some(title) = &self.vending_title else {
+            return;
+        };
+
+        let screen_position = self.screen_position(camera, window_size);
+
+        // TODO: move variables into theme
+        let board_size = ScreenSize {
+            width: title.len() as f32 * 6.0 + 10.0,
+            height: 20.0,
+        };
+        let board_position = ScreenPosition {
+            left: screen_position.left - board_size.width / 2.0,
+            top: screen_position.top - 100.0,
+        };
+
+        renderer.render_rectangle(render_target, board_position, board_size, Color::rgba_u8(40, 40, 40, 200));
+        renderer.render_text(
+            render_target,
+            title,
+            board_position + ScreenPosition::uniform(5.0),
+            Color::rgb_u8(255, 220, 120),
+            FontSize::new(12.0),
+        );
+    }
+
+    /// Render the current emotion above the head of the entity.
+    fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+        application: &InterfaceSettings,
+    ) {
+        let Some(emotion) = &self.emotion else {
+            return;
+        };
+
+        // TODO: move variables into theme
+        let screen_position = self.screen_position(camera, window_size);
+        let bubble_position = ScreenPosition {
+            left: screen_position.left,
+            top: screen_position.top - 110.0,
+        };
+
+        emotion.actions.render_action(
+            render_target,
+            renderer,
+            &emotion.sprite,
+            &emotion.animation_state,
+            emotion.emotion.0 as usize,
+            bubble_position,
+            Color::monochrome_u8(255),
+            application,
+        );
+    }
+
     pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
         use pathfinding::prelude::astar;
 
@@ -741,7 +1033,7 @@ impl Common {
             Vector2::new(1, 1),
             Vector2::new(0, 0),
             mirror,
-            self.entity_id,
+            PickerTarget::Entity(self.entity_id),
         );
     }
 
@@ -767,6 +1059,10 @@ pub struct Player {
     pub activity_points: usize,
     pub maximum_spell_points: usize,
     pub maximum_activity_points: usize,
+    pub base_experience: u64,
+    pub job_experience: u64,
+    pub next_base_experience: u64,
+    pub next_job_experience: u64,
 }
 
 impl Player {
@@ -785,6 +1081,11 @@ impl Player {
         let activity_points = 0;
         let maximum_spell_points = character_information.maximum_spell_points as usize;
         let maximum_activity_points = 0;
+        let base_experience = character_information.experience as u64;
+        let job_experience = character_information.job_experience as u64;
+        // The amount required for the next level is sent in a separate status update.
+        let next_base_experience = 0;
+        let next_job_experience = 0;
         let common = Common::new(
             game_file_loader,
             sprite_loader,
@@ -801,6 +1102,10 @@ impl Player {
             activity_points,
             maximum_spell_points,
             maximum_activity_points,
+            base_experience,
+            job_experience,
+            next_base_experience,
+            next_job_experience,
         }
     }
 
@@ -820,6 +1125,10 @@ impl Player {
             StatusType::SpellPoints(value) => self.spell_points = value as usize,
             StatusType::ActivityPoints(value) => self.activity_points = value as usize,
             StatusType::MaximumActivityPoints(value) => self.maximum_activity_points = value as usize,
+            StatusType::BaseExperience(value) => self.base_experience = value,
+            StatusType::JobExperience(value) => self.job_experience = value,
+            StatusType::NextBaseExperience(value) => self.next_base_experience = value,
+            StatusType::NextJobExperience(value) => self.next_job_experience = value,
             _ => {}
         }
     }
@@ -906,6 +1215,112 @@ impl Player {
             self.maximum_activity_points as f32,
             self.activity_points as f32,
         );
+
+        let icon_offset = total_height + theme.status_bar.border_size.get().height * 2.0 + gap;
+
+        self.common.render_status_effect_icons(
+            render_target,
+            renderer,
+            theme,
+            final_position + ScreenPosition::only_top(icon_offset),
+        );
+    }
+
+    /// Render the base and job experience bars at the bottom of the screen.
+    pub fn render_experience_bars(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        let height = theme.status_bar.experience_height.get();
+        let gap = theme.status_bar.gap.get();
+        let total_height = height * 2.0 + gap;
+        let position = ScreenPosition::only_top(window_size.height - total_height);
+        let bar_position = position + ScreenPosition::only_left(window_size.width / 2.0);
+
+        // The amount required for the next level is 0 once the maximum level is
+        // reached.
+        let progress = |current: u64, next: u64| match next {
+            0 => 0.0,
+            next => (current as f32 / next as f32).min(1.0),
+        };
+
+        renderer.render_rectangle(
+            render_target,
+            position,
+            ScreenSize {
+                width: window_size.width,
+                height: total_height,
+            },
+            theme.status_bar.background_color.get(),
+        );
+
+        renderer.render_bar(
+            render_target,
+            bar_position,
+            ScreenSize {
+                width: window_size.width,
+                height,
+            },
+            theme.status_bar.base_experience_color.get(),
+            1.0,
+            progress(self.base_experience, self.next_base_experience),
+        );
+
+        renderer.render_bar(
+            render_target,
+            bar_position + ScreenPosition::only_top(height + gap),
+            ScreenSize {
+                width: window_size.width,
+                height,
+            },
+            theme.status_bar.job_experience_color.get(),
+            1.0,
+            progress(self.job_experience, self.next_job_experience),
+        );
+    }
+
+    /// Render the status effects of the player in a row at the top of the
+    /// screen, together with their remaining duration.
+    pub fn render_buff_bar(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        client_tick: ClientTick,
+    ) {
+        let icon_size = theme.status_bar.buff_bar_icon_size.get();
+        let mut position = theme.status_bar.buff_bar_offset.get();
+
+        for status_effect in &self.common.status_effects {
+            let Some(icon) = status_effect.icon.clone() else {
+                continue;
+            };
+
+            renderer.render_sprite(
+                render_target,
+                icon,
+                position,
+                ScreenSize::uniform(icon_size),
+                ScreenClip::unbound(),
+                Color::monochrome_u8(255),
+                true,
+            );
+
+            if let Some(remaining_duration) = status_effect.remaining_duration(client_tick) {
+                renderer.render_text(
+                    render_target,
+                    &format!("{}s", remaining_duration.div_ceil(1000)),
+                    position + ScreenPosition::only_top(icon_size),
+                    theme.status_bar.buff_bar_text_color.get(),
+                    FontSize::new(12.0),
+                );
+            }
+
+            position += ScreenSize::only_width(icon_size + theme.status_bar.gap.get());
+        }
     }
 }
 
@@ -992,6 +1407,16 @@ impl Npc {
             self.common.maximum_health_points as f32,
             self.common.health_points as f32,
         );
+
+        let icon_offset =
+            theme.status_bar.status_effect_icon_size.get() + theme.status_bar.border_size.get().height + theme.status_bar.gap.get();
+
+        self.common.render_status_effect_icons(
+            render_target,
+            renderer,
+            theme,
+            final_position - ScreenPosition::only_top(icon_offset),
+        );
     }
 }
 
@@ -1059,6 +1484,22 @@ impl Entity {
         self.get_common().details.as_option()
     }
 
+    pub fn set_guild_name(&mut self, guild_name: String) {
+        self.get_common_mut().guild_name = Some(guild_name);
+    }
+
+    pub fn get_guild_name(&self) -> Option<&String> {
+        self.get_common().guild_name.as_ref()
+    }
+
+    pub fn set_vending_title(&mut self, vending_title: Option<String>) {
+        self.get_common_mut().vending_title = vending_title;
+    }
+
+    pub fn get_vending_title(&self) -> Option<&String> {
+        self.get_common().vending_title.as_ref()
+    }
+
     pub fn get_grid_position(&self) -> Vector2<usize> {
         self.get_common().grid_position
     }
@@ -1081,6 +1522,26 @@ impl Entity {
         self.get_common_mut().update(map, delta_time, client_tick);
     }
 
+    pub fn add_status_effect(&mut self, status_effect: StatusEffect, icon: Option<Arc<ImageView>>, client_tick: ClientTick) {
+        self.get_common_mut().add_status_effect(status_effect, icon, client_tick);
+    }
+
+    pub fn remove_status_effect(&mut self, status_id: u16) {
+        self.get_common_mut().remove_status_effect(status_id);
+    }
+
+    pub fn set_emotion(
+        &mut self,
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        emotion: EmotionId,
+        client_tick: ClientTick,
+    ) {
+        self.get_common_mut()
+            .set_emotion(game_file_loader, sprite_loader, action_loader, emotion, client_tick);
+    }
+
     pub fn move_from_to(&mut self, map: &Map, from: Vector2<usize>, to: Vector2<usize>, starting_timestamp: ClientTick) {
         self.get_common_mut().move_from_to(map, from, to, starting_timestamp);
     }
@@ -1125,6 +1586,52 @@ impl Entity {
             Self::Npc(npc) => npc.render_status(render_target, renderer, camera, theme, window_size),
         }
     }
+
+    pub fn render_vending_board(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+    ) {
+        self.get_common().render_vending_board(render_target, renderer, camera, window_size);
+    }
+
+    pub fn render_emotion(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        camera: &dyn Camera,
+        window_size: ScreenSize,
+        application: &InterfaceSettings,
+    ) {
+        self.get_common()
+            .render_emotion(render_target, renderer, camera, window_size, application);
+    }
+
+    pub fn render_experience_bars(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        window_size: ScreenSize,
+    ) {
+        if let Self::Player(player) = self {
+            player.render_experience_bars(render_target, renderer, theme, window_size);
+        }
+    }
+
+    pub fn render_buff_bar(
+        &self,
+        render_target: &mut <DeferredRenderer as Renderer>::Target,
+        renderer: &DeferredRenderer,
+        theme: &GameTheme,
+        client_tick: ClientTick,
+    ) {
+        if let Self::Player(player) = self {
+            player.render_buff_bar(render_target, renderer, theme, client_tick);
+        }
+    }
 }
 
 impl PrototypeWindow<InterfaceSettings> for Entity {
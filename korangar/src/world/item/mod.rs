@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use cgmath::{Array, Vector2, Vector3};
+use korangar_networking::GroundItemData;
+use ragnarok_packets::{ClientTick, GroundItemId};
+
+use crate::graphics::{Camera, EntityRenderer, PickerTarget, Renderer};
+use crate::loaders::{ActionLoader, Actions, AnimationState, GameFileLoader, ScriptLoader, Sprite, SpriteLoader};
+use crate::world::Map;
+
+/// Number of sub tile steps the server uses to place items inside a tile.
+const SUB_TILE_STEPS: f32 = 12.0;
+
+/// Item lying on the ground that can be picked up by clicking on it.
+pub struct GroundItem {
+    ground_item_id: GroundItemId,
+    position: Vector3<f32>,
+    sprite: Arc<Sprite>,
+    actions: Arc<Actions>,
+    animation_state: AnimationState,
+}
+
+impl GroundItem {
+    pub fn new(
+        game_file_loader: &mut GameFileLoader,
+        sprite_loader: &mut SpriteLoader,
+        action_loader: &mut ActionLoader,
+        script_loader: &ScriptLoader,
+        map: &Map,
+        ground_item_data: GroundItemData,
+        client_tick: ClientTick,
+    ) -> Self {
+        let (sprite, actions) = script_loader.load_ground_item_sprite(
+            game_file_loader,
+            sprite_loader,
+            action_loader,
+            ground_item_data.item_id,
+            ground_item_data.is_identified,
+        );
+
+        let tile_position = Vector2::new(ground_item_data.position.x as usize, ground_item_data.position.y as usize);
+        let tile_center = map.get_world_position(tile_position);
+
+        // The server places the item on a grid inside the tile, so we offset it from
+        // the upper left corner of the tile instead of the center.
+        let position = Vector3::new(
+            tile_center.x - 2.5 + ground_item_data.sub_x as f32 / SUB_TILE_STEPS * 5.0,
+            tile_center.y,
+            tile_center.z - 2.5 + ground_item_data.sub_y as f32 / SUB_TILE_STEPS * 5.0,
+        );
+
+        Self {
+            ground_item_id: ground_item_data.ground_item_id,
+            position,
+            sprite,
+            actions,
+            animation_state: AnimationState::new(client_tick),
+        }
+    }
+
+    pub fn get_ground_item_id(&self) -> GroundItemId {
+        self.ground_item_id
+    }
+
+    pub fn update(&mut self, client_tick: ClientTick) {
+        self.animation_state.update(client_tick);
+    }
+
+    pub fn render<T>(&self, render_target: &mut T::Target, renderer: &T, camera: &dyn Camera)
+    where
+        T: Renderer + EntityRenderer,
+    {
+        let (texture, position, mirror) = self.actions.render(&self.sprite, &self.animation_state, 0, 0);
+
+        renderer.render_entity(
+            render_target,
+            camera,
+            texture,
+            self.position,
+            Vector3::new(position.x, position.y, 0.0),
+            Vector2::from_value(0.7),
+            Vector2::new(1, 1),
+            Vector2::new(0, 0),
+            mirror,
+            PickerTarget::GroundItem(self.ground_item_id),
+        );
+    }
+}
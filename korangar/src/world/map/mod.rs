@@ -209,6 +209,16 @@ impl Map {
             .for_each(|entity| entity.render(render_target, renderer, camera));
     }
 
+    #[cfg_attr(feature = "debug", korangar_debug::profile)]
+    pub fn render_ground_items<T>(&self, ground_items: &[GroundItem], render_target: &mut T::Target, renderer: &T, camera: &dyn Camera)
+    where
+        T: Renderer + EntityRenderer,
+    {
+        ground_items
+            .iter()
+            .for_each(|ground_item| ground_item.render(render_target, renderer, camera));
+    }
+
     #[cfg(feature = "debug")]
     #[korangar_debug::profile]
     pub fn render_bounding(
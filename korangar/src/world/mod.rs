@@ -1,5 +1,6 @@
 mod effect;
 mod entity;
+mod item;
 mod light;
 mod map;
 mod model;
@@ -8,6 +9,7 @@ mod sound;
 
 pub use self::effect::*;
 pub use self::entity::*;
+pub use self::item::*;
 pub use self::light::*;
 pub use self::map::*;
 pub use self::model::*;
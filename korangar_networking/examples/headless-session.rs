@@ -0,0 +1,65 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use korangar_debug::logging::Colorize;
+use korangar_networking::{CharacterSelection, ChatEntry, NetworkingSystem, Session, SessionConfig};
+use ragnarok_packets::WorldPosition;
+
+#[tokio::main]
+async fn main() {
+    // Session settings.
+    const SOCKET_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 6900);
+    const USERNAME: &str = "username";
+    const PASSWORD: &str = "password";
+    const CHARACTER_NAME: &str = "character name";
+
+    let config = SessionConfig {
+        login_server: SOCKET_ADDR,
+        username: USERNAME.to_owned(),
+        password: PASSWORD.to_owned(),
+        character_server: 0,
+        character: CharacterSelection::Name(CHARACTER_NAME.to_owned()),
+    };
+
+    let mut session = Session::new(NetworkingSystem::spawn(), config);
+    session.connect();
+
+    if let Err(error) = session.wait_until_in_game(Duration::from_secs(30)).await {
+        panic!("Failed to log in: {}", error);
+    }
+
+    let player = session.player().unwrap();
+    println!(
+        "[{}] Logged in on {} at {}, {}",
+        "Session".green(),
+        player.map_name.magenta(),
+        player.position.x,
+        player.position.y
+    );
+
+    // Walk a few tiles and report everything that happens on the way.
+    let position = player.position;
+    session
+        .walk_to(WorldPosition::new(position.x + 5, position.y))
+        .expect("Map server disconnected");
+
+    loop {
+        session.next_events().await;
+
+        for entry in session.take_chat_messages() {
+            match entry {
+                ChatEntry::Message { text, .. } => println!("[{}] {}", "Chat".cyan(), text),
+                ChatEntry::Whisper { sender_name, message } => {
+                    println!("[{}] {}: {}", "Whisper".cyan(), sender_name.yellow(), message);
+                }
+            }
+        }
+
+        println!(
+            "[{}] {} entities nearby, {} items in inventory",
+            "Session".green(),
+            session.entities().count(),
+            session.inventory().len()
+        );
+    }
+}
@@ -98,9 +98,9 @@ async fn main() {
                     panic!("Failed to connect to login server: {}", message);
                 }
                 NetworkEvent::LoginServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
+                    reason: DisconnectReason::ConnectionError(error),
                 } => {
-                    panic!("Login server connection error");
+                    panic!("Login server connection error: {}", error);
                 }
                 NetworkEvent::CharacterServerConnected { .. } => {
                     println!("[{}] Successfully connected to character server", "Setup".green());
@@ -111,17 +111,17 @@ async fn main() {
                     panic!("Failed to connect to character server: {}", message);
                 }
                 NetworkEvent::CharacterServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
+                    reason: DisconnectReason::ConnectionError(error),
                 } => {
-                    panic!("Character server connection error");
+                    panic!("Character server connection error: {}", error);
                 }
                 NetworkEvent::CharacterSelectionFailed { message, .. } => {
                     panic!("Failed to select character: {}", message);
                 }
                 NetworkEvent::MapServerDisconnected {
-                    reason: DisconnectReason::ConnectionError,
+                    reason: DisconnectReason::ConnectionError(error),
                 } => {
-                    panic!("Map server connection error");
+                    panic!("Map server connection error: {}", error);
                 }
                 NetworkEvent::CharacterList { characters } => {
                     let character_slot = characters
@@ -0,0 +1,32 @@
+use ragnarok_packets::*;
+
+#[derive(Debug, Clone)]
+pub struct Achievement {
+    pub achievement_id: u32,
+    pub is_completed: bool,
+    /// Current progress for each objective of the achievement.
+    pub objectives: [u32; 10],
+    pub completion_timestamp: u32,
+    pub is_rewarded: bool,
+}
+
+impl From<AchievementData> for Achievement {
+    fn from(data: AchievementData) -> Self {
+        Self {
+            achievement_id: data.acheivement_id,
+            is_completed: data.is_completed != 0,
+            objectives: data.objectives,
+            completion_timestamp: data.completion_timestamp,
+            is_rewarded: data.got_rewarded != 0,
+        }
+    }
+}
+
+/// Overall achievement progress of the player.
+#[derive(Debug, Clone, Default)]
+pub struct AchievementSummary {
+    pub total_score: u32,
+    pub level: u16,
+    pub experience: u32,
+    pub experience_to_next_level: u32,
+}
@@ -0,0 +1,206 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::{ClientTick, RequestServerTickPacket};
+
+/// Number of recent samples that are considered for the estimate.
+const SAMPLE_COUNT: usize = 8;
+
+/// Request for the server tick together with the answer from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TickSample {
+    /// Time at which the request was sent.
+    pub sent_at: Instant,
+    /// Time at which the answer was received.
+    pub received_at: Instant,
+    /// Tick of the server when it answered the request.
+    pub server_tick: ClientTick,
+}
+
+impl TickSample {
+    pub fn round_trip_time(&self) -> Duration {
+        self.received_at.saturating_duration_since(self.sent_at)
+    }
+
+    /// Estimated server tick at the time the answer was received. Assumes that
+    /// the request and the answer took equally long.
+    pub fn server_tick_at_receive(&self) -> ClientTick {
+        let one_way_delay = (self.round_trip_time() / 2).as_millis() as u32;
+        ClientTick(self.server_tick.0.wrapping_add(one_way_delay))
+    }
+}
+
+/// Estimates the server tick from answered requests, similar to the clock
+/// filter of NTP. Of the recent samples, the one with the shortest round trip
+/// is the most accurate, since it had the least room for the request and the
+/// answer to be delayed by different amounts.
+#[derive(Debug, Default)]
+pub struct TickSynchronizer {
+    samples: VecDeque<TickSample>,
+}
+
+impl TickSynchronizer {
+    pub fn add_sample(&mut self, sample: TickSample) {
+        if self.samples.len() == SAMPLE_COUNT {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(sample);
+    }
+
+    /// Forget all samples, for example after connecting to a different
+    /// server.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    fn best_sample(&self) -> Option<&TickSample> {
+        self.samples.iter().min_by_key(|sample| sample.round_trip_time())
+    }
+
+    /// Shortest round trip of the recent samples.
+    pub fn round_trip_time(&self) -> Option<Duration> {
+        self.best_sample().map(TickSample::round_trip_time)
+    }
+
+    /// Estimated server tick at the given time.
+    pub fn server_tick_at(&self, instant: Instant) -> Option<ClientTick> {
+        let sample = self.best_sample()?;
+        let server_tick = sample.server_tick_at_receive().0;
+
+        let server_tick = match instant.checked_duration_since(sample.received_at) {
+            Some(elapsed) => server_tick.wrapping_add(elapsed.as_millis() as u32),
+            None => server_tick.wrapping_sub(sample.received_at.duration_since(instant).as_millis() as u32),
+        };
+
+        Some(ClientTick(server_tick))
+    }
+}
+
+/// Client tick at the given time, counting from `origin`.
+pub(crate) fn client_tick_at(origin: Instant, instant: Instant) -> ClientTick {
+    ClientTick(instant.saturating_duration_since(origin).as_millis() as u32)
+}
+
+/// Request for the server tick that is waiting for an answer. Shared between
+/// the keep-alive of a map server connection and its packet handler, since the
+/// answer doesn't reference the request.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingTickRequest {
+    /// Start of the client ticks that are sent to the server.
+    origin: Instant,
+    sent_at: Rc<Cell<Option<Instant>>>,
+}
+
+impl PendingTickRequest {
+    pub fn new(origin: Instant) -> Self {
+        Self {
+            origin,
+            sent_at: Rc::default(),
+        }
+    }
+
+    /// Create a new request. A previous request that was never answered is
+    /// dropped.
+    pub fn request(&self) -> RequestServerTickPacket {
+        let now = Instant::now();
+        self.sent_at.set(Some(now));
+
+        RequestServerTickPacket::new(client_tick_at(self.origin, now))
+    }
+
+    /// Match an answer from the server to the pending request. Returns `None`
+    /// if there is no pending request.
+    pub fn answer(&self, server_tick: ClientTick) -> Option<TickSample> {
+        let sent_at = self.sent_at.take()?;
+
+        Some(TickSample {
+            sent_at,
+            received_at: Instant::now(),
+            server_tick,
+        })
+    }
+}
+
+#[cfg(test)]
+mod estimate {
+    use std::time::{Duration, Instant};
+
+    use ragnarok_packets::ClientTick;
+
+    use super::{client_tick_at, PendingTickRequest, TickSample, TickSynchronizer};
+
+    fn sample(start: Instant, sent: u64, received: u64, server_tick: u32) -> TickSample {
+        TickSample {
+            sent_at: start + Duration::from_millis(sent),
+            received_at: start + Duration::from_millis(received),
+            server_tick: ClientTick(server_tick),
+        }
+    }
+
+    #[test]
+    fn shortest_round_trip() {
+        let start = Instant::now();
+        let mut synchronizer = TickSynchronizer::default();
+
+        assert_eq!(synchronizer.server_tick_at(start), None);
+
+        // The server tick is 5000 ahead of the start. The first answer was
+        // delayed on the way back, the second one is symmetric.
+        synchronizer.add_sample(sample(start, 0, 300, 5050));
+        synchronizer.add_sample(sample(start, 4000, 4100, 9050));
+
+        assert_eq!(synchronizer.round_trip_time(), Some(Duration::from_millis(100)));
+        assert_eq!(
+            synchronizer.server_tick_at(start + Duration::from_millis(4100)),
+            Some(ClientTick(9100))
+        );
+        assert_eq!(
+            synchronizer.server_tick_at(start + Duration::from_millis(5000)),
+            Some(ClientTick(10000))
+        );
+        assert_eq!(synchronizer.server_tick_at(start), Some(ClientTick(5000)));
+    }
+
+    #[test]
+    fn old_samples_are_dropped() {
+        let start = Instant::now();
+        let mut synchronizer = TickSynchronizer::default();
+
+        synchronizer.add_sample(sample(start, 0, 10, 1000));
+
+        for index in 0..8 {
+            let sent = 1000 + index * 1000;
+            synchronizer.add_sample(sample(start, sent, sent + 50, 1000));
+        }
+
+        assert_eq!(synchronizer.round_trip_time(), Some(Duration::from_millis(50)));
+
+        synchronizer.clear();
+        assert_eq!(synchronizer.round_trip_time(), None);
+    }
+
+    #[test]
+    fn client_tick() {
+        let origin = Instant::now();
+
+        assert_eq!(client_tick_at(origin, origin + Duration::from_millis(1500)), ClientTick(1500));
+        assert_eq!(client_tick_at(origin + Duration::from_millis(10), origin), ClientTick(0));
+    }
+
+    #[test]
+    fn pending_request() {
+        let pending_request = PendingTickRequest::new(Instant::now());
+
+        assert_eq!(pending_request.answer(ClientTick(10)), None);
+
+        pending_request.request();
+        let sample = pending_request.answer(ClientTick(10)).unwrap();
+        assert_eq!(sample.server_tick, ClientTick(10));
+        assert!(sample.received_at >= sample.sent_at);
+
+        assert_eq!(pending_request.answer(ClientTick(20)), None);
+    }
+}
@@ -78,3 +78,53 @@ impl From<MovingEntityAppearedPacket> for EntityData {
         }
     }
 }
+
+impl From<LegacyEntityAppearedPacket> for EntityData {
+    fn from(packet: LegacyEntityAppearedPacket) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            movement_speed: packet.movement_speed,
+            job: packet.job,
+            position: packet.position,
+            destination: None,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            head_direction: packet.head_direction as usize,
+            sex: packet.sex,
+        }
+    }
+}
+
+impl From<LegacyEntityAppeared2Packet> for EntityData {
+    fn from(packet: LegacyEntityAppeared2Packet) -> Self {
+        Self {
+            entity_id: packet.entity_id,
+            movement_speed: packet.movement_speed,
+            job: packet.job,
+            position: packet.position,
+            destination: None,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            head_direction: packet.head_direction as usize,
+            sex: packet.sex,
+        }
+    }
+}
+
+impl From<LegacyMovingEntityAppearedPacket> for EntityData {
+    fn from(packet: LegacyMovingEntityAppearedPacket) -> Self {
+        let (origin, destination) = packet.position.to_origin_destination();
+
+        Self {
+            entity_id: packet.entity_id,
+            movement_speed: packet.movement_speed,
+            job: packet.job,
+            position: origin,
+            destination: Some(destination),
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+            head_direction: packet.head_direction as usize,
+            sex: packet.sex,
+        }
+    }
+}
@@ -1,10 +1,13 @@
+use std::time::Duration;
+
 use ragnarok_packets::*;
 
 use crate::hotkey::HotkeyState;
-use crate::items::ShopItem;
+use crate::items::{ShopItem, VendingItem};
 use crate::{
-    CharacterServerLoginData, EntityData, InventoryItem, LoginServerLoginData, MessageColor, NoMetadata,
-    UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    Achievement, AchievementSummary, CharacterServerLoginData, EntityData, GroundItemData, GuildInformation, GuildMember, InventoryItem,
+    LoginServerLoginData, Mail, MailPreview, MessageColor, NetworkTaskError, NoMetadata, PartyMember, QuestInformation, StatusEffect,
+    TickSample, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
 
 /// An event triggered by one of the Ragnarok Online servers.
@@ -55,29 +58,75 @@ pub enum NetworkEvent {
         reason: CharacterDeletionFailedReason,
         message: &'static str,
     },
+    /// The character will be deleted once the deletion is accepted after
+    /// `deletion_time`.
+    CharacterDeletionReserved {
+        character_id: CharacterId,
+        deletion_time: Duration,
+    },
+    CharacterDeletionReservationFailed {
+        character_id: CharacterId,
+        reason: CharacterDeletionReservationStatus,
+        message: &'static str,
+    },
+    CharacterDeletionAccepted {
+        character_id: CharacterId,
+    },
+    CharacterDeletionAcceptFailed {
+        character_id: CharacterId,
+        reason: CharacterDeletionAcceptStatus,
+        message: &'static str,
+    },
+    CharacterDeletionCanceled {
+        character_id: CharacterId,
+    },
+    CharacterDeletionCancelFailed {
+        character_id: CharacterId,
+        message: &'static str,
+    },
     MapServerDisconnected {
         reason: DisconnectReason,
     },
+    /// The connection to the map server was lost and the client will try to
+    /// reconnect after `delay`.
+    MapServerReconnecting {
+        attempt: u32,
+        max_attempts: u32,
+        delay: Duration,
+    },
+    /// The client reconnected to the map server after losing the connection.
+    MapServerReconnected,
     /// Add an entity to the list of entities that the client is aware of.
     AddEntity(EntityData),
     /// Remove an entity from the list of entities that the client is aware of
     /// by its id.
     RemoveEntity(EntityId),
+    /// Add an item lying on the ground.
+    AddGroundItem(GroundItemData),
+    /// Remove an item from the ground, because it was picked up or vanished.
+    RemoveGroundItem(GroundItemId),
     /// The player is pathing to a new position.
     PlayerMove(WorldPosition, WorldPosition, ClientTick),
     /// An Entity nearby is pathing to a new position.
     EntityMove(EntityId, WorldPosition, WorldPosition, ClientTick),
     /// Player was moved to a new position on a different map or the current map
     ChangeMap(String, TilePosition),
-    /// Update the client side [`tick
-    /// counter`](crate::system::GameTimer::base_client_tick) to keep server and
-    /// client synchronized.
+    /// Set the client side tick counter to the tick of the server to keep
+    /// server and client synchronized.
     UpdateClientTick(ClientTick),
+    /// The map server answered a request for its tick. Used to estimate the
+    /// latency and the offset between the server and the client tick.
+    TickSample(TickSample),
     /// New chat message for the client.
     ChatMessage {
         text: String,
         color: MessageColor,
     },
+    /// An entity nearby (or the player) is showing an emotion.
+    EntityEmotion {
+        entity_id: EntityId,
+        emotion: EmotionId,
+    },
     CharacterSlotSwitched,
     CharacterSlotSwitchFailed,
     /// Update entity details. Mostly received when the client sends
@@ -87,6 +136,13 @@ pub enum NetworkEvent {
     DamageEffect(EntityId, usize),
     HealEffect(EntityId, usize),
     UpdateStatus(StatusType),
+    /// Experience gained by the player, used to display the experience feed.
+    GainedExperience {
+        account_id: AccountId,
+        amount: u64,
+        experience_type: ExperienceType,
+        experience_source: ExperienceSource,
+    },
     OpenDialog(String, EntityId),
     AddNextButton,
     AddCloseButton,
@@ -147,6 +203,252 @@ pub enum NetworkEvent {
         index: InventoryIndex,
         amount: u16,
     },
+    /// An entity used an item. If the entity is the player, `index` and
+    /// `remaining_amount` refer to the item in their inventory.
+    ItemUsed {
+        entity_id: EntityId,
+        index: InventoryIndex,
+        item_id: ItemId,
+        remaining_amount: u16,
+    },
+    ItemUseFailed {
+        index: InventoryIndex,
+    },
+    ItemPickupFailed {
+        reason: ItemPickupResult,
+        message: &'static str,
+    },
+    /// The player was invited to join a party.
+    PartyInvitation {
+        party_id: PartyId,
+        party_name: String,
+    },
+    /// Replace the complete list of party members. Received when joining a
+    /// party, on login and whenever the party changes considerably.
+    SetPartyMembers {
+        party_name: String,
+        members: Vec<PartyMember>,
+    },
+    PartyMemberJoined {
+        member: PartyMember,
+    },
+    /// A member left or was expelled from the party. If the account id is the
+    /// one of the player, the player is no longer in a party.
+    PartyMemberLeft {
+        account_id: AccountId,
+    },
+    PartyMemberHealth {
+        account_id: AccountId,
+        health_points: u32,
+        maximum_health_points: u32,
+    },
+    PartyMemberPosition {
+        account_id: AccountId,
+        position: TilePosition,
+    },
+    PartyMemberJobLevel {
+        account_id: AccountId,
+        job: u16,
+        base_level: u16,
+    },
+    PartyLeaderChanged {
+        account_id: AccountId,
+    },
+    /// Replace the complete quest journal of the player.
+    SetQuestList {
+        quests: Vec<QuestInformation>,
+    },
+    QuestAdded {
+        quest: QuestInformation,
+    },
+    QuestRemoved {
+        quest_id: u32,
+    },
+    /// Progress was made on one or more hunting objectives.
+    UpdateQuestObjectives {
+        objectives: Vec<HuntingObjective>,
+    },
+    /// A status effect was applied to an entity. If the entity already has a
+    /// status effect with the same id, it should be replaced.
+    AddStatusEffect {
+        entity_id: EntityId,
+        status_effect: StatusEffect,
+    },
+    RemoveStatusEffect {
+        entity_id: EntityId,
+        status_id: u16,
+    },
+    /// The guild of an entity. Only received for players that are in a guild.
+    UpdateEntityGuild {
+        entity_id: EntityId,
+        guild_name: String,
+        position_name: String,
+    },
+    /// The player is a member of a guild. Received when entering the map or
+    /// joining a guild.
+    UpdateGuild {
+        guild_id: GuildId,
+        guild_name: String,
+        emblem_id: u32,
+        is_master: bool,
+    },
+    SetGuildInformation {
+        information: GuildInformation,
+    },
+    SetGuildMembers {
+        members: Vec<GuildMember>,
+    },
+    SetGuildPositions {
+        positions: Vec<GuildPositionName>,
+    },
+    GuildNotice {
+        subject: String,
+        notice: String,
+    },
+    GuildMemberOnlineStatus {
+        account_id: AccountId,
+        is_online: bool,
+    },
+    GuildInvitation {
+        guild_id: GuildId,
+        guild_name: String,
+    },
+    GuildMemberLeft {
+        name: String,
+        reason: String,
+    },
+    /// Zlib compressed bitmap of a guild emblem.
+    GuildEmblem {
+        guild_id: GuildId,
+        emblem_id: u32,
+        emblem_data: Vec<u8>,
+    },
+    /// Another player sent a private message to the player.
+    WhisperReceived {
+        sender_name: String,
+        message: String,
+    },
+    /// The last private message sent by the player could not be delivered.
+    WhisperFailed {
+        reason: WhisperResult,
+    },
+    /// Another player wants to trade with the player.
+    TradeRequested {
+        name: String,
+        character_id: CharacterId,
+        base_level: u16,
+    },
+    /// The trade request was accepted by both sides.
+    TradeStarted,
+    /// An offer made by the player was accepted by the server. The index is
+    /// `None` if zeny were offered.
+    TradeOfferAccepted {
+        index: Option<InventoryIndex>,
+    },
+    /// The trade partner offered an item. The index of the item has no meaning
+    /// since it is not part of the player's inventory.
+    TradePartnerItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    TradePartnerZenyAdded {
+        amount: u32,
+    },
+    TradeLocked {
+        is_partner: bool,
+    },
+    TradeCancelled,
+    /// The trade was executed. Items that changed hands are received as regular
+    /// inventory updates.
+    TradeCompleted,
+    /// The storage was opened. The index of storage items is their index in
+    /// the storage and not in the inventory.
+    SetStorage {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    StorageItemAdded {
+        item: InventoryItem<NoMetadata>,
+    },
+    StorageItemRemoved {
+        index: StorageIndex,
+        amount: u32,
+    },
+    StorageClosed,
+    SetCart {
+        items: Vec<InventoryItem<NoMetadata>>,
+    },
+    CartItemRemoved {
+        index: InventoryIndex,
+        amount: u32,
+    },
+    /// The player used the vending skill and should pick the items to sell.
+    OpenVendingSetup {
+        maximum_items: u16,
+    },
+    VendingShopOpened,
+    /// Title of a vending shop that should be displayed above the merchant.
+    VendingShopBoard {
+        entity_id: EntityId,
+        title: String,
+    },
+    VendingShopBoardRemoved {
+        entity_id: EntityId,
+    },
+    VendingItemList {
+        entity_id: EntityId,
+        unique_id: u32,
+        items: Vec<VendingItem>,
+    },
+    /// The server notified the player about unread mail. The number of unread
+    /// mails is only known once the mailbox is opened.
+    NewMail,
+    /// Number of unread mails in the part of the mailbox that was received
+    /// last.
+    UnreadMailCount {
+        count: usize,
+    },
+    MailList {
+        mails: Vec<MailPreview>,
+        /// `false` if there are more mails that can be requested.
+        is_end: bool,
+    },
+    MailContent {
+        mail: Mail<NoMetadata>,
+    },
+    MailDeleted {
+        mail_id: MailId,
+    },
+    MailZenyTaken {
+        mail_id: MailId,
+    },
+    MailItemsTaken {
+        mail_id: MailId,
+    },
+    /// Response to looking up the receiver of a mail. `None` if no character
+    /// with the name exists.
+    MailReceiver {
+        character_id: Option<CharacterId>,
+    },
+    /// An item was attached to the mail that is being written. The index is
+    /// the index of the item in the inventory.
+    MailItemAttached {
+        item: InventoryItem<NoMetadata>,
+    },
+    MailItemDetached {
+        index: InventoryIndex,
+        amount: u16,
+    },
+    MailSent,
+    AchievementList {
+        summary: AchievementSummary,
+        achievements: Vec<Achievement>,
+    },
+    AchievementUpdate {
+        summary: AchievementSummary,
+        achievement: Achievement,
+    },
+    AchievementRewarded {
+        achievement_id: u32,
+    },
 }
 
 /// New-type so we can implement some `From` traits. This will help when
@@ -192,7 +494,7 @@ impl From<NoNetworkEvents> for NetworkEventList {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DisconnectReason {
     ClosedByClient,
-    ConnectionError,
+    ConnectionError(NetworkTaskError),
 }
 
 pub(crate) trait DisconnectedEvent {
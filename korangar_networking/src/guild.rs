@@ -0,0 +1,59 @@
+use ragnarok_packets::*;
+
+#[derive(Debug, Clone)]
+pub struct GuildInformation {
+    pub guild_id: GuildId,
+    pub name: String,
+    pub master_name: String,
+    pub level: u32,
+    pub member_count: u32,
+    pub maximum_member_count: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub next_experience: u32,
+    pub emblem_id: u32,
+}
+
+impl From<GuildInformationPacket> for GuildInformation {
+    fn from(packet: GuildInformationPacket) -> Self {
+        Self {
+            guild_id: packet.guild_id,
+            name: packet.guild_name,
+            master_name: packet.master_name,
+            level: packet.level,
+            member_count: packet.member_count,
+            maximum_member_count: packet.maximum_member_count,
+            average_level: packet.average_level,
+            experience: packet.experience,
+            next_experience: packet.next_experience,
+            emblem_id: packet.emblem_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GuildMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub name: String,
+    pub job: u16,
+    pub base_level: u16,
+    pub position_id: u32,
+    pub contributed_experience: u32,
+    pub is_online: bool,
+}
+
+impl From<GuildMemberInformation> for GuildMember {
+    fn from(member: GuildMemberInformation) -> Self {
+        Self {
+            account_id: member.account_id,
+            character_id: member.character_id,
+            name: member.name,
+            job: member.job,
+            base_level: member.base_level,
+            position_id: member.position_id,
+            contributed_experience: member.contributed_experience,
+            is_online: member.is_online != 0,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use ragnarok_packets::{EquipPosition, EquippableItemFlags, InventoryIndex, ItemId, ItemOptions, Price, RegularItemFlags};
+use ragnarok_packets::{
+    EquipPosition, EquippableItemFlags, EquippableItemInformation, GroundItemEntryPacket, GroundItemFallPacket, GroundItemId,
+    InventoryIndex, ItemId, ItemOptions, Price, RegularItemFlags, RegularItemInformation, TilePosition, VendingItemInformation,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NoMetadata;
@@ -41,6 +44,123 @@ impl<Meta> InventoryItem<Meta> {
             InventoryItemDetails::Equippable { flags, .. } => flags.contains(EquippableItemFlags::IDENTIFIED),
         }
     }
+
+    /// Healing, usable, delayed consume and cash items can be used from the
+    /// inventory.
+    pub fn is_usable(&self) -> bool {
+        matches!(self.item_type, 0 | 2 | 11 | 18)
+    }
+}
+
+impl From<RegularItemInformation> for InventoryItem<NoMetadata> {
+    fn from(item_information: RegularItemInformation) -> Self {
+        let RegularItemInformation {
+            index,
+            item_id,
+            item_type,
+            amount,
+            equipped_position,
+            slot,
+            hire_expiration_date,
+            flags,
+        } = item_information;
+
+        InventoryItem {
+            index,
+            metadata: NoMetadata,
+            item_id,
+            item_type,
+            slot,
+            hire_expiration_date,
+            details: InventoryItemDetails::Regular {
+                amount,
+                equipped_position,
+                flags,
+            },
+        }
+    }
+}
+
+impl From<EquippableItemInformation> for InventoryItem<NoMetadata> {
+    fn from(item_information: EquippableItemInformation) -> Self {
+        let EquippableItemInformation {
+            index,
+            item_id,
+            item_type,
+            equip_position,
+            equipped_position,
+            slot,
+            hire_expiration_date,
+            bind_on_equip_type,
+            w_item_sprite_number,
+            option_count,
+            option_data,
+            refinement_level,
+            enchantment_level,
+            flags,
+        } = item_information;
+
+        InventoryItem {
+            index,
+            metadata: NoMetadata,
+            item_id,
+            item_type,
+            slot,
+            hire_expiration_date,
+            details: InventoryItemDetails::Equippable {
+                equip_position,
+                equipped_position,
+                bind_on_equip_type,
+                w_item_sprite_number,
+                option_count,
+                option_data,
+                refinement_level,
+                enchantment_level,
+                flags,
+            },
+        }
+    }
+}
+
+/// An item lying on the ground.
+#[derive(Debug, Clone)]
+pub struct GroundItemData {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub is_identified: bool,
+    pub position: TilePosition,
+    /// Offset of the item inside of its tile, ranging from 0 to 12.
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub amount: u16,
+}
+
+impl From<GroundItemEntryPacket> for GroundItemData {
+    fn from(packet: GroundItemEntryPacket) -> Self {
+        Self {
+            ground_item_id: packet.ground_item_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            position: packet.position,
+            sub_x: packet.sub_x,
+            sub_y: packet.sub_y,
+            amount: packet.amount,
+        }
+    }
+}
+
+impl From<GroundItemFallPacket> for GroundItemData {
+    fn from(packet: GroundItemFallPacket) -> Self {
+        Self {
+            ground_item_id: packet.ground_item_id,
+            item_id: packet.item_id,
+            is_identified: packet.is_identified != 0,
+            position: packet.position,
+            sub_x: packet.sub_x,
+            sub_y: packet.sub_y,
+            amount: packet.amount,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -76,3 +196,28 @@ pub struct SellItem<Meta> {
     pub price: Price,
     pub overcharge_price: Price,
 }
+
+/// An item in the vending shop of another player.
+#[derive(Debug, Clone)]
+pub struct VendingItem {
+    /// Index of the item in the vending shop, used when buying it.
+    pub index: u16,
+    pub item: ShopItem<NoMetadata>,
+}
+
+impl From<VendingItemInformation> for VendingItem {
+    fn from(item: VendingItemInformation) -> Self {
+        Self {
+            index: item.index,
+            item: ShopItem {
+                metadata: NoMetadata,
+                item_id: item.item_id,
+                item_type: item.item_type,
+                price: item.price,
+                quantity: ItemQuantity::Fixed(item.amount as u32),
+                weight: 0,
+                location: item.location,
+            },
+        }
+    }
+}
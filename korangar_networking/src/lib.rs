@@ -1,14 +1,28 @@
+mod achievement;
+mod clock;
 mod entity;
 mod event;
+mod guild;
 mod hotkey;
 mod items;
+mod mail;
 mod message;
+#[cfg(test)]
+mod mock_server;
+mod party;
+mod quest;
+mod reconnect;
+mod recording;
 mod server;
+mod session;
+mod statistics;
+mod status;
 
 use std::cell::RefCell;
+use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
 use std::rc::Rc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use event::{
     CharacterServerDisconnectedEvent, DisconnectedEvent, LoginServerDisconnectedEvent, MapServerDisconnectedEvent, NetworkEventList,
@@ -17,36 +31,65 @@ use event::{
 use ragnarok_bytes::{ByteStream, FromBytes};
 use ragnarok_packets::handler::{DuplicateHandlerError, HandlerResult, NoPacketCallback, PacketCallback, PacketHandler};
 use ragnarok_packets::*;
-use server::{ServerConnectCommand, ServerConnection};
+use server::{connect_with_timeout, ConnectionTarget, ServerConnectCommand, ServerConnection, ServerStream};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
 
+pub use self::achievement::{Achievement, AchievementSummary};
+pub use self::clock::{TickSample, TickSynchronizer};
 pub use self::entity::EntityData;
 pub use self::event::{DisconnectReason, NetworkEvent};
+pub use self::guild::{GuildInformation, GuildMember};
 pub use self::hotkey::HotkeyState;
-pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
+pub use self::items::{GroundItemData, InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem, VendingItem};
+pub use self::mail::{Mail, MailPreview};
 pub use self::message::MessageColor;
+pub use self::party::PartyMember;
+pub use self::quest::{QuestInformation, QuestObjective};
+pub use self::reconnect::ReconnectPolicy;
+pub use self::recording::{Capture, CaptureError, PacketDirection, PacketRecord, PacketRecorder};
 pub use self::server::{
-    CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+    CharacterServerLoginData, LoginServerLoginData, NetworkTaskError, NetworkTimeouts, NotConnectedError, ServerTimeouts,
+    UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
+};
+pub use self::session::{CharacterSelection, ChatEntry, Session, SessionConfig, SessionEntity, SessionError, SessionPlayer, SessionState};
+pub use self::statistics::{
+    NetworkStatistics, NetworkStatisticsHandle, ServerStatistics, TrafficSample, STATISTICS_HISTORY_LENGTH, STATISTICS_SAMPLE_INTERVAL,
 };
-use crate::server::NetworkTaskError;
+pub use self::status::StatusEffect;
+use crate::clock::{client_tick_at, PendingTickRequest};
+use crate::reconnect::ReconnectState;
+use crate::recording::Replay;
+use crate::statistics::StatisticsCollector;
 
 pub struct NetworkingSystem<Callback> {
     command_sender: UnboundedSender<ServerConnectCommand>,
     login_server_connection: ServerConnection,
     character_server_connection: ServerConnection,
     map_server_connection: ServerConnection,
+    map_server_login_data: Option<(LoginServerLoginData, CharacterServerLoginData)>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    reconnect_state: Option<ReconnectState>,
+    timeouts: NetworkTimeouts,
+    packet_version: PacketVersion,
+    packet_length_overrides: PacketLengthTable,
+    replay: Option<Replay>,
+    statistics: NetworkStatisticsHandle,
+    /// The client ticks sent to the map server count from here.
+    client_start: Instant,
     packet_callback: Callback,
 }
 
 impl NetworkingSystem<NoPacketCallback> {
     pub fn spawn() -> Self {
-        let command_sender = Self::spawn_networking_thread(NoPacketCallback);
+        let client_start = Instant::now();
+        let command_sender = Self::spawn_networking_thread(NoPacketCallback, client_start);
 
-        Self::inner_new(command_sender, NoPacketCallback)
+        Self::inner_new(command_sender, NoPacketCallback, client_start)
     }
 }
 
@@ -54,23 +97,89 @@ impl<Callback> NetworkingSystem<Callback>
 where
     Callback: PacketCallback + Send,
 {
-    fn inner_new(command_sender: UnboundedSender<ServerConnectCommand>, packet_callback: Callback) -> Self {
+    fn inner_new(command_sender: UnboundedSender<ServerConnectCommand>, packet_callback: Callback, client_start: Instant) -> Self {
         Self {
             command_sender,
             login_server_connection: ServerConnection::Disconnected,
             character_server_connection: ServerConnection::Disconnected,
             map_server_connection: ServerConnection::Disconnected,
+            map_server_login_data: None,
+            reconnect_policy: None,
+            reconnect_state: None,
+            timeouts: NetworkTimeouts::default(),
+            packet_version: PacketVersion::default(),
+            packet_length_overrides: PacketLengthTable::empty(),
+            replay: None,
+            statistics: NetworkStatisticsHandle::default(),
+            client_start,
             packet_callback,
         }
     }
 
     pub fn spawn_with_callback(packet_callback: Callback) -> Self {
-        let command_sender = Self::spawn_networking_thread(packet_callback.clone());
+        let client_start = Instant::now();
+        let command_sender = Self::spawn_networking_thread(packet_callback.clone(), client_start);
+
+        Self::inner_new(command_sender, packet_callback, client_start)
+    }
+
+    /// Set the client date that the server is configured for. Applies to all
+    /// connections that are opened after this call.
+    pub fn set_packet_version(&mut self, packet_version: PacketVersion) {
+        self.packet_version = packet_version;
+    }
+
+    /// Set lengths that override the built-in packet length table, used to
+    /// skip packets that Korangar doesn't know. Applies to all connections
+    /// that are opened after this call.
+    pub fn set_packet_length_overrides(&mut self, packet_length_overrides: PacketLengthTable) {
+        self.packet_length_overrides = packet_length_overrides;
+    }
+
+    fn packet_lengths(&self) -> PacketLengthTable {
+        let mut packet_lengths = PacketLengthTable::for_version(self.packet_version);
+        packet_lengths.extend(self.packet_length_overrides.clone());
+        packet_lengths
+    }
+
+    /// Set the timeouts used for all connections that are opened after this
+    /// call.
+    pub fn set_timeouts(&mut self, timeouts: NetworkTimeouts) {
+        self.timeouts = timeouts;
+    }
+
+    /// Play back a recorded session instead of connecting to the servers.
+    /// Every connection to a server consumes the next recorded connection to
+    /// that server. Passing `None` goes back to connecting normally.
+    pub fn set_replay(&mut self, capture: Option<Capture>) {
+        self.replay = capture.map(Replay::new);
+    }
+
+    fn connection_target(&mut self, server_type: ServerType, address: SocketAddr) -> ConnectionTarget {
+        match &mut self.replay {
+            Some(replay) => ConnectionTarget::Replay(replay.next_connection(server_type)),
+            None => ConnectionTarget::Address(address),
+        }
+    }
+
+    /// Get a snapshot of the traffic and latency statistics of all servers.
+    pub fn get_statistics(&self) -> NetworkStatistics {
+        self.statistics.get()
+    }
+
+    /// Get a handle to the statistics that stays up to date, for code that
+    /// doesn't have access to the networking system.
+    pub fn statistics_handle(&self) -> NetworkStatisticsHandle {
+        self.statistics.clone()
+    }
 
-        Self::inner_new(command_sender, packet_callback)
+    /// Automatically reconnect to the map server if the connection is lost.
+    /// Passing `None` disables reconnecting, which is the default.
+    pub fn set_reconnect_policy(&mut self, reconnect_policy: Option<ReconnectPolicy>) {
+        self.reconnect_policy = reconnect_policy;
     }
 
-    fn spawn_networking_thread(packet_callback: Callback) -> UnboundedSender<ServerConnectCommand> {
+    fn spawn_networking_thread(packet_callback: Callback, client_start: Instant) -> UnboundedSender<ServerConnectCommand> {
         let (command_sender, mut command_receiver) = tokio::sync::mpsc::unbounded_channel::<ServerConnectCommand>();
 
         std::thread::spawn(move || {
@@ -79,80 +188,124 @@ where
             let _guard = runtime.enter();
             let local_set = tokio::task::LocalSet::new();
 
-            let mut login_server_task_handle: Option<JoinHandle<Result<(), NetworkTaskError>>> = None;
-            let mut character_server_task_handle: Option<JoinHandle<Result<(), NetworkTaskError>>> = None;
-            let mut map_server_task_handle: Option<JoinHandle<Result<(), NetworkTaskError>>> = None;
+            let mut login_server_task_handle: Option<JoinHandle<()>> = None;
+            let mut character_server_task_handle: Option<JoinHandle<()>> = None;
+            let mut map_server_task_handle: Option<JoinHandle<()>> = None;
 
             local_set.block_on(&runtime, async {
                 while let Some(command) = command_receiver.recv().await {
                     match command {
                         ServerConnectCommand::Login {
-                            address,
+                            target,
+                            timeouts,
+                            packet_version,
+                            packet_lengths,
+                            statistics,
                             action_receiver,
                             event_sender,
+                            error_sender,
                         } => {
                             if let Some(handle) = login_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
-                            let packet_handler = Self::create_login_server_packet_handler(packet_callback.clone()).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                LoginServerKeepalivePacket::new,
-                                Duration::from_secs(58),
-                                false,
+                            let mut packet_handler =
+                                Self::create_login_server_packet_handler(packet_callback.for_server(ServerType::Login), packet_version)
+                                    .unwrap();
+                            packet_handler.set_packet_lengths(packet_lengths);
+                            let handle = local_set.spawn_local(Self::report_error(
+                                error_sender,
+                                event_sender.clone(),
+                                Self::handle_server_connection(
+                                    target,
+                                    timeouts,
+                                    statistics,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    LoginServerKeepalivePacket::new,
+                                    Duration::from_secs(58),
+                                    false,
+                                ),
                             ));
 
                             login_server_task_handle = Some(handle);
                         }
                         ServerConnectCommand::Character {
-                            address,
+                            target,
+                            timeouts,
+                            packet_version,
+                            packet_lengths,
+                            statistics,
                             action_receiver,
                             event_sender,
+                            error_sender,
                         } => {
                             if let Some(handle) = character_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
-                            let packet_handler = Self::create_character_server_packet_handler(packet_callback.clone()).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                CharacterServerKeepalivePacket::new,
-                                Duration::from_secs(10),
-                                true,
+                            let mut packet_handler = Self::create_character_server_packet_handler(
+                                packet_callback.for_server(ServerType::Character),
+                                packet_version,
+                            )
+                            .unwrap();
+                            packet_handler.set_packet_lengths(packet_lengths);
+                            let handle = local_set.spawn_local(Self::report_error(
+                                error_sender,
+                                event_sender.clone(),
+                                Self::handle_server_connection(
+                                    target,
+                                    timeouts,
+                                    statistics,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    CharacterServerKeepalivePacket::new,
+                                    Duration::from_secs(10),
+                                    true,
+                                ),
                             ));
 
                             character_server_task_handle = Some(handle);
                         }
                         ServerConnectCommand::Map {
-                            address,
+                            target,
+                            timeouts,
+                            packet_version,
+                            packet_lengths,
+                            statistics,
                             action_receiver,
                             event_sender,
+                            error_sender,
                         } => {
                             if let Some(handle) = map_server_task_handle.take() {
-                                // TODO: Maybe add a timeout here? Maybe handle Result?
-                                let _ = handle.await.unwrap();
+                                Self::finish_task(handle).await;
                             }
 
-                            let packet_handler = Self::create_map_server_packet_handler(packet_callback.clone()).unwrap();
-                            let handle = local_set.spawn_local(Self::handle_server_connection(
-                                address,
-                                action_receiver,
-                                event_sender,
-                                packet_handler,
-                                // Always passing 100 seems to work fine for now, but it might cause
-                                // issues when connecting to something other than rAthena.
-                                || RequestServerTickPacket::new(ClientTick(100)),
-                                Duration::from_secs(4),
-                                false,
+                            let pending_tick_request = PendingTickRequest::new(client_start);
+                            let mut packet_handler = Self::create_map_server_packet_handler(
+                                packet_callback.for_server(ServerType::Map),
+                                packet_version,
+                                pending_tick_request.clone(),
+                                statistics.clone(),
+                            )
+                            .unwrap();
+                            packet_handler.set_packet_lengths(packet_lengths);
+                            let handle = local_set.spawn_local(Self::report_error(
+                                error_sender,
+                                event_sender.clone(),
+                                Self::handle_server_connection(
+                                    target,
+                                    timeouts,
+                                    statistics,
+                                    action_receiver,
+                                    event_sender,
+                                    packet_handler,
+                                    move || pending_tick_request.request(),
+                                    Duration::from_secs(4),
+                                    false,
+                                ),
                             ));
 
                             map_server_task_handle = Some(handle);
@@ -173,6 +326,7 @@ where
             ServerConnection::Connected {
                 action_sender,
                 mut event_receiver,
+                mut error_receiver,
             } => loop {
                 match event_receiver.try_recv() {
                     Ok(login_event) => {
@@ -182,11 +336,16 @@ where
                         *connection = ServerConnection::Connected {
                             action_sender,
                             event_receiver,
+                            error_receiver,
                         };
                         break;
                     }
                     Err(..) => {
-                        events.push(Event::create_event(DisconnectReason::ConnectionError));
+                        // The error is always sent before the event channel is closed, so it
+                        // can only be missing if the task panicked.
+                        let error = error_receiver.try_recv().unwrap_or(NetworkTaskError::ConnectionClosed);
+
+                        events.push(Event::create_event(DisconnectReason::ConnectionError(error)));
                         *connection = ServerConnection::Disconnected;
                         break;
                     }
@@ -205,13 +364,120 @@ where
 
         Self::handle_connection::<LoginServerDisconnectedEvent>(&mut self.login_server_connection, &mut events);
         Self::handle_connection::<CharacterServerDisconnectedEvent>(&mut self.character_server_connection, &mut events);
+
+        let map_server_events_start = events.len();
         Self::handle_connection::<MapServerDisconnectedEvent>(&mut self.map_server_connection, &mut events);
+        self.handle_map_server_reconnect(map_server_events_start, &mut events);
 
         events
     }
 
+    fn handle_map_server_reconnect(&mut self, map_server_events_start: usize, events: &mut Vec<NetworkEvent>) {
+        let Some(policy) = self.reconnect_policy else {
+            return;
+        };
+
+        let connection_lost = matches!(
+            events.last(),
+            Some(NetworkEvent::MapServerDisconnected {
+                reason: DisconnectReason::ConnectionError(..)
+            })
+        );
+
+        if connection_lost {
+            if self.map_server_login_data.is_none() {
+                return;
+            }
+
+            let attempt = self.reconnect_state.map(|state| state.attempt() + 1).unwrap_or(1);
+
+            if attempt > policy.max_attempts {
+                // Give up and let the client handle the disconnect.
+                self.reconnect_state = None;
+                self.map_server_login_data = None;
+                return;
+            }
+
+            let delay = policy.delay(attempt);
+
+            events.pop();
+            events.push(NetworkEvent::MapServerReconnecting {
+                attempt,
+                max_attempts: policy.max_attempts,
+                delay,
+            });
+
+            self.reconnect_state = Some(ReconnectState::Waiting {
+                attempt,
+                next_attempt: Instant::now() + delay,
+            });
+
+            return;
+        }
+
+        match self.reconnect_state {
+            Some(ReconnectState::Waiting { attempt, next_attempt }) if Instant::now() >= next_attempt => {
+                let (login_server_login_data, character_server_login_data) = self.map_server_login_data.unwrap();
+
+                self.connect_to_map_server(&login_server_login_data, character_server_login_data);
+                self.reconnect_state = Some(ReconnectState::Connecting { attempt });
+            }
+            // Only the login success counts as a successful reconnect. Anything else
+            // (like the server rejecting us because the old session is still open) is
+            // followed by a disconnect and counts as a failed attempt.
+            Some(ReconnectState::Connecting { .. })
+                if events[map_server_events_start..]
+                    .iter()
+                    .any(|event| matches!(event, NetworkEvent::SetPlayerPosition(..))) =>
+            {
+                events.insert(map_server_events_start, NetworkEvent::MapServerReconnected);
+                self.reconnect_state = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Wait for the previous connection task of a server to finish. The task
+    /// ends as soon as its action channel is dropped, so this should be
+    /// instant. If it isn't, the task is stuck and gets aborted.
+    async fn finish_task(mut handle: JoinHandle<()>) {
+        if tokio::time::timeout(Duration::from_secs(1), &mut handle).await.is_err() {
+            handle.abort();
+        }
+    }
+
+    /// Run a connection task and pass its error to the main thread. The error
+    /// is sent before the event channel is closed, so the main thread always
+    /// receives it together with the disconnect.
+    async fn report_error(
+        error_sender: oneshot::Sender<NetworkTaskError>,
+        event_sender: UnboundedSender<NetworkEvent>,
+        task: impl Future<Output = Result<(), NetworkTaskError>>,
+    ) {
+        if let Err(error) = task.await {
+            let _ = error_sender.send(error);
+        }
+
+        drop(event_sender);
+    }
+
+    /// Resolves with the given error once the deadline is reached. Never
+    /// resolves if there is no deadline.
+    async fn timeout_expired(timeout: Option<(tokio::time::Instant, NetworkTaskError)>) -> NetworkTaskError {
+        match timeout {
+            Some((deadline, error)) => {
+                tokio::time::sleep_until(deadline).await;
+                error
+            }
+            None => std::future::pending().await,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn handle_server_connection<PingPacket>(
-        address: SocketAddr,
+        target: ConnectionTarget,
+        timeouts: ServerTimeouts,
+        statistics: StatisticsCollector,
         mut action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
         mut packet_handler: PacketHandler<NetworkEventList, (), Callback>,
@@ -226,12 +492,22 @@ where
         PingPacket: Packet + ClientPacket,
         Callback: PacketCallback,
     {
-        let mut stream = TcpStream::connect(address).await.map_err(|_| NetworkTaskError::FailedToConnect)?;
+        let mut stream: Box<dyn ServerStream> = match target {
+            ConnectionTarget::Address(address) => Box::new(connect_with_timeout(timeouts.connect, TcpStream::connect(address)).await?),
+            ConnectionTarget::Replay(connection) => Box::new(connection.into_stream()),
+        };
         let mut interval = tokio::time::interval(ping_frequency);
         let mut buffer = [0u8; 8192];
         let mut cut_off_buffer_base = 0;
+        let connected_at = tokio::time::Instant::now();
+        let mut last_received = None;
 
         loop {
+            let timeout = match last_received {
+                None => Some((connected_at + timeouts.handshake, NetworkTaskError::HandshakeTimeout)),
+                Some(last_received) => timeouts.idle.map(|idle| (last_received + idle, NetworkTaskError::IdleTimeout)),
+            };
+
             tokio::select! {
                 // Send a packet to the server.
                 action = action_receiver.recv() => {
@@ -241,6 +517,7 @@ where
                     };
 
                     stream.write_all(&action).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                    statistics.packet_sent(&action);
                 }
                 // Receive some packets from the server.
                 received_bytes = stream.read(&mut buffer[cut_off_buffer_base..]) => {
@@ -255,6 +532,9 @@ where
                         break Err(NetworkTaskError::ConnectionClosed);
                     }
 
+                    last_received = Some(tokio::time::Instant::now());
+                    statistics.bytes_received(received_bytes);
+
                     let data = &buffer[..cut_off_buffer_base + received_bytes];
                     let mut byte_stream = ByteStream::without_metadata(data);
                     let mut events = Vec::new();
@@ -266,10 +546,15 @@ where
                     }
 
                     while !byte_stream.is_empty() {
+                        let packet_start = byte_stream.get_offset();
+
                         match packet_handler.process_one(&mut byte_stream) {
-                            HandlerResult::Ok(packet_events) => events.extend(packet_events.0.into_iter()),
+                            HandlerResult::Ok(packet_events) => {
+                                packet_handler.packet_callback().raw_incoming_packet(&data[packet_start..byte_stream.get_offset()]);
+                                statistics.packet_received(&data[packet_start..]);
+                                events.extend(packet_events.0.into_iter());
+                            }
                             HandlerResult::PacketCutOff => {
-                                let packet_start = byte_stream.get_offset();
                                 let packet_end = cut_off_buffer_base + received_bytes;
 
                                 if packet_start == 0 {
@@ -285,12 +570,20 @@ where
 
                                 break;
                             },
-                            // The packet callback can take care of handling these properly.
+                            // Unknown packets with a known length are passed to the packet callback.
+                            HandlerResult::SkippedPacket => {
+                                packet_handler.packet_callback().raw_incoming_packet(&data[packet_start..byte_stream.get_offset()]);
+                                statistics.packet_received(&data[packet_start..]);
+                            }
+                            // The packet callback can take care of handling these properly. Since
+                            // the end of the packet is unknown, the rest of the data is passed on.
                             HandlerResult::UnhandledPacket => {
+                                packet_handler.packet_callback().raw_incoming_packet(&data[packet_start..]);
                                 cut_off_buffer_base = 0;
                                 break
                             },
                             HandlerResult::InternalError(..) => {
+                                packet_handler.packet_callback().raw_incoming_packet(&data[packet_start..]);
                                 cut_off_buffer_base = 0;
                                 break
                             },
@@ -303,8 +596,13 @@ where
                 }
                 // Send a keep-alive packet to the server.
                 _ = interval.tick() => {
-                    let packet_bytes = ping_factory().packet_to_bytes().unwrap();
+                    let packet_bytes = ping_factory().versioned_packet_to_bytes(packet_handler.packet_version()).unwrap();
                     stream.write_all(&packet_bytes).await.map_err(|_| NetworkTaskError::ConnectionClosed)?;
+                    statistics.packet_sent(&packet_bytes);
+                }
+                // The server didn't send anything for too long.
+                error = Self::timeout_expired(timeout) => {
+                    break Err(error);
                 }
             }
         }
@@ -317,26 +615,39 @@ where
 
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (error_sender, error_receiver) = oneshot::channel();
+
+        let target = self.connection_target(ServerType::Login, address);
 
         self.command_sender
             .send(ServerConnectCommand::Login {
-                address,
+                target,
+                timeouts: self.timeouts.login_server,
+                packet_version: self.packet_version,
+                packet_lengths: self.packet_lengths(),
+                statistics: self.statistics.collector(ServerType::Login),
                 action_receiver,
                 event_sender,
+                error_sender,
             })
             .expect("network thread dropped");
 
         let login_packet = LoginServerLoginPacket::new(username.into(), password.into());
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let packet_callback = self.packet_callback.for_server(ServerType::Login);
+        let login_bytes = login_packet.versioned_packet_to_bytes(self.packet_version).unwrap();
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        packet_callback.outgoing_packet(&login_packet);
+        packet_callback.raw_outgoing_packet(&login_bytes);
+
+        // If the connection already failed, the receiver is dropped and the error is
+        // reported through the error receiver instead.
+        let _ = action_sender.send(login_bytes);
 
         self.login_server_connection = ServerConnection::Connected {
             action_sender,
             event_receiver,
+            error_receiver,
         };
     }
 
@@ -347,14 +658,22 @@ where
 
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (error_sender, error_receiver) = oneshot::channel();
 
         let address = SocketAddr::new(IpAddr::V4(server.server_ip.into()), server.server_port);
 
+        let target = self.connection_target(ServerType::Character, address);
+
         self.command_sender
             .send(ServerConnectCommand::Character {
-                address,
+                target,
+                timeouts: self.timeouts.character_server,
+                packet_version: self.packet_version,
+                packet_lengths: self.packet_lengths(),
+                statistics: self.statistics.collector(ServerType::Character),
                 action_receiver,
                 event_sender,
+                error_sender,
             })
             .expect("network thread dropped");
 
@@ -365,15 +684,20 @@ where
             login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let packet_callback = self.packet_callback.for_server(ServerType::Character);
+        let login_bytes = login_packet.versioned_packet_to_bytes(self.packet_version).unwrap();
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        packet_callback.outgoing_packet(&login_packet);
+        packet_callback.raw_outgoing_packet(&login_bytes);
+
+        // If the connection already failed, the receiver is dropped and the error is
+        // reported through the error receiver instead.
+        let _ = action_sender.send(login_bytes);
 
         self.character_server_connection = ServerConnection::Connected {
             action_sender,
             event_receiver,
+            error_receiver,
         };
     }
 
@@ -386,16 +710,26 @@ where
             return;
         }
 
+        self.map_server_login_data = Some((*login_server_login_data, character_server_login_data));
+
         let (action_sender, action_receiver) = tokio::sync::mpsc::unbounded_channel();
         let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (error_sender, error_receiver) = oneshot::channel();
 
         let address = SocketAddr::new(character_server_login_data.server_ip, character_server_login_data.server_port);
 
+        let target = self.connection_target(ServerType::Map, address);
+
         self.command_sender
             .send(ServerConnectCommand::Map {
-                address,
+                target,
+                timeouts: self.timeouts.map_server,
+                packet_version: self.packet_version,
+                packet_lengths: self.packet_lengths(),
+                statistics: self.statistics.collector(ServerType::Map),
                 action_receiver,
                 event_sender,
+                error_sender,
             })
             .expect("network thread dropped");
 
@@ -403,21 +737,24 @@ where
             login_server_login_data.account_id,
             character_server_login_data.character_id,
             login_server_login_data.login_id1,
-            // Always passing 100 seems to work fine for now, but it might cause
-            // issues when connecting to something other than rAthena.
-            ClientTick(100),
+            client_tick_at(self.client_start, Instant::now()),
             login_server_login_data.sex,
         );
 
-        self.packet_callback.outgoing_packet(&login_packet);
+        let packet_callback = self.packet_callback.for_server(ServerType::Map);
+        let login_bytes = login_packet.versioned_packet_to_bytes(self.packet_version).unwrap();
 
-        action_sender
-            .send(login_packet.packet_to_bytes().unwrap())
-            .expect("action receiver instantly dropped");
+        packet_callback.outgoing_packet(&login_packet);
+        packet_callback.raw_outgoing_packet(&login_bytes);
+
+        // If the connection already failed, the receiver is dropped and the error is
+        // reported through the error receiver instead.
+        let _ = action_sender.send(login_bytes);
 
         self.map_server_connection = ServerConnection::Connected {
             action_sender,
             event_receiver,
+            error_receiver,
         };
     }
 
@@ -430,40 +767,54 @@ where
     }
 
     pub fn disconnect_from_map_server(&mut self) {
+        self.map_server_login_data = None;
+        self.reconnect_state = None;
         self.map_server_connection = ServerConnection::ClosingManually;
     }
 
-    pub fn send_login_server_packet(&mut self, packet: &(impl Packet + LoginServerPacket)) -> Result<(), NotConnectedError> {
+    pub fn send_login_server_packet(&mut self, packet: &(impl ClientPacket + LoginServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.login_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
+                let packet_callback = self.packet_callback.for_server(ServerType::Login);
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.versioned_packet_to_bytes(self.packet_version).unwrap();
+
+                packet_callback.outgoing_packet(packet);
+                packet_callback.raw_outgoing_packet(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
     }
 
-    pub fn send_character_server_packet(&mut self, packet: &(impl Packet + CharacterServerPacket)) -> Result<(), NotConnectedError> {
+    pub fn send_character_server_packet(&mut self, packet: &(impl ClientPacket + CharacterServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.character_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
+                let packet_callback = self.packet_callback.for_server(ServerType::Character);
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.versioned_packet_to_bytes(self.packet_version).unwrap();
+
+                packet_callback.outgoing_packet(packet);
+                packet_callback.raw_outgoing_packet(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
     }
 
-    pub fn send_map_server_packet(&mut self, packet: &(impl Packet + MapServerPacket)) -> Result<(), NotConnectedError> {
+    pub fn send_map_server_packet(&mut self, packet: &(impl ClientPacket + MapServerPacket)) -> Result<(), NotConnectedError> {
         match &mut self.map_server_connection {
             ServerConnection::Connected { action_sender, .. } => {
-                self.packet_callback.outgoing_packet(packet);
-
+                let packet_callback = self.packet_callback.for_server(ServerType::Map);
                 // FIX: Don't unwrap.
-                action_sender.send(packet.packet_to_bytes().unwrap()).map_err(|_| NotConnectedError)
+                let bytes = packet.versioned_packet_to_bytes(self.packet_version).unwrap();
+
+                packet_callback.outgoing_packet(packet);
+                packet_callback.raw_outgoing_packet(&bytes);
+
+                action_sender.send(bytes).map_err(|_| NotConnectedError)
             }
             _ => Err(NotConnectedError),
         }
@@ -471,8 +822,9 @@ where
 
     fn create_login_server_packet_handler(
         packet_callback: Callback,
+        packet_version: PacketVersion,
     ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_version(packet_version, packet_callback);
 
         packet_handler.register(|packet: LoginServerLoginSuccessPacket| NetworkEvent::LoginServerConnected {
             character_servers: packet.character_server_information,
@@ -483,6 +835,21 @@ where
                 sex: packet.sex,
             },
         })?;
+        packet_handler.register(
+            |packet: LegacyLoginServerLoginSuccessPacket| NetworkEvent::LoginServerConnected {
+                character_servers: packet
+                    .character_server_information
+                    .into_iter()
+                    .map(CharacterServerInformation::from)
+                    .collect(),
+                login_data: LoginServerLoginData {
+                    account_id: packet.account_id,
+                    login_id1: packet.login_id1,
+                    login_id2: packet.login_id2,
+                    sex: packet.sex,
+                },
+            },
+        )?;
         packet_handler.register(|packet: LoginFailedPacket| {
             let (reason, message) = match packet.reason {
                 LoginFailedReason::ServerClosed => (UnifiedLoginFailedReason::ServerClosed, "Server closed"),
@@ -519,8 +886,9 @@ where
 
     fn create_character_server_packet_handler(
         packet_callback: Callback,
+        packet_version: PacketVersion,
     ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_version(packet_version, packet_callback);
 
         packet_handler.register(|packet: LoginFailedPacket| {
             let reason = packet.reason;
@@ -540,6 +908,9 @@ where
         packet_handler.register(|packet: RequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
             characters: packet.character_information,
         })?;
+        packet_handler.register(|packet: LegacyRequestCharacterListSuccessPacket| NetworkEvent::CharacterList {
+            characters: packet.character_information.into_iter().map(CharacterInformation::from).collect(),
+        })?;
         packet_handler.register_noop::<Packet006b>()?;
         packet_handler.register_noop::<Packet0b18>()?;
         packet_handler.register(|packet: CharacterSelectionSuccessPacket| {
@@ -571,6 +942,9 @@ where
         packet_handler.register(|packet: CreateCharacterSuccessPacket| NetworkEvent::CharacterCreated {
             character_information: packet.character_information,
         })?;
+        packet_handler.register(|packet: LegacyCreateCharacterSuccessPacket| NetworkEvent::CharacterCreated {
+            character_information: packet.character_information.into(),
+        })?;
         packet_handler.register(|packet: CharacterCreationFailedPacket| {
             let reason = packet.reason;
             let message = match reason {
@@ -592,6 +966,56 @@ where
             };
             NetworkEvent::CharacterDeletionFailed { reason, message }
         })?;
+        packet_handler.register(|packet: CharacterDeletionReservedPacket| {
+            let character_id = packet.character_id;
+            let message = match packet.status {
+                CharacterDeletionReservationStatus::Success => {
+                    let deletion_time = Duration::from_secs(packet.deletion_date as u64);
+                    return NetworkEvent::CharacterDeletionReserved {
+                        character_id,
+                        deletion_time,
+                    };
+                }
+                CharacterDeletionReservationStatus::UnknownError => "An unknown error occurred",
+                CharacterDeletionReservationStatus::DatabaseError => "A database error occurred",
+                CharacterDeletionReservationStatus::InGuild => "You need to leave your guild to delete this character",
+                CharacterDeletionReservationStatus::InParty => "You need to leave your party to delete this character",
+            };
+
+            NetworkEvent::CharacterDeletionReservationFailed {
+                character_id,
+                reason: packet.status,
+                message,
+            }
+        })?;
+        packet_handler.register(|packet: CharacterDeletionAcceptedPacket| {
+            let character_id = packet.character_id;
+            let message = match packet.status {
+                CharacterDeletionAcceptStatus::Success => return NetworkEvent::CharacterDeletionAccepted { character_id },
+                CharacterDeletionAcceptStatus::UnknownError => "An unknown error occurred",
+                CharacterDeletionAcceptStatus::NotAllowed => "Characters can not be deleted on this server",
+                CharacterDeletionAcceptStatus::DatabaseError => "A database error occurred",
+                CharacterDeletionAcceptStatus::NotYetPossible => "This character can not be deleted yet",
+                CharacterDeletionAcceptStatus::BirthdateMismatch => "The birthdate does not match",
+            };
+
+            NetworkEvent::CharacterDeletionAcceptFailed {
+                character_id,
+                reason: packet.status,
+                message,
+            }
+        })?;
+        packet_handler.register(|packet: CharacterDeletionCanceledPacket| {
+            let character_id = packet.character_id;
+
+            match packet.status {
+                CharacterDeletionCancelStatus::Success => NetworkEvent::CharacterDeletionCanceled { character_id },
+                CharacterDeletionCancelStatus::UnknownError => NetworkEvent::CharacterDeletionCancelFailed {
+                    character_id,
+                    message: "Failed to cancel the deletion",
+                },
+            }
+        })?;
         packet_handler.register(|packet: SwitchCharacterSlotResponsePacket| match packet.status {
             SwitchCharacterSlotResponseStatus::Success => NetworkEvent::CharacterSlotSwitched,
             SwitchCharacterSlotResponseStatus::Error => NetworkEvent::CharacterSlotSwitchFailed,
@@ -602,8 +1026,11 @@ where
 
     fn create_map_server_packet_handler(
         packet_callback: Callback,
+        packet_version: PacketVersion,
+        pending_tick_request: PendingTickRequest,
+        statistics: StatisticsCollector,
     ) -> Result<PacketHandler<NetworkEventList, (), Callback>, DuplicateHandlerError> {
-        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_callback(packet_callback);
+        let mut packet_handler = PacketHandler::<NetworkEventList, (), Callback>::with_version(packet_version, packet_callback);
 
         // This is a bit of a workaround for the way that the inventory is
         // sent. There is a single packet to start the inventory list,
@@ -654,7 +1081,10 @@ where
                 color,
             }
         })?;
-        packet_handler.register_noop::<DisplayEmotionPacket>()?;
+        packet_handler.register(|packet: DisplayEmotionPacket| NetworkEvent::EntityEmotion {
+            entity_id: packet.entity_id,
+            emotion: packet.emotion,
+        })?;
         packet_handler.register(|packet: EntityMovePacket| {
             let (origin, destination) = packet.from_to.to_origin_destination();
             NetworkEvent::EntityMove(packet.entity_id, origin, destination, packet.timestamp)
@@ -668,15 +1098,43 @@ where
         packet_handler.register(|packet: EntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: EntityAppeared2Packet| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: MovingEntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
+        packet_handler.register(|packet: LegacyEntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
+        packet_handler.register(|packet: LegacyEntityAppeared2Packet| NetworkEvent::AddEntity(packet.into()))?;
+        packet_handler.register(|packet: LegacyMovingEntityAppearedPacket| NetworkEvent::AddEntity(packet.into()))?;
         packet_handler.register(|packet: EntityDisappearedPacket| NetworkEvent::RemoveEntity(packet.entity_id))?;
         packet_handler.register(|packet: UpdateStatusPacket| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket1| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket2| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register(|packet: UpdateStatusPacket3| NetworkEvent::UpdateStatus(packet.status_type))?;
         packet_handler.register_noop::<UpdateAttackRangePacket>()?;
-        packet_handler.register_noop::<NewMailStatusPacket>()?;
-        packet_handler.register_noop::<AchievementUpdatePacket>()?;
-        packet_handler.register_noop::<AchievementListPacket>()?;
+        packet_handler.register(|packet: NewMailStatusPacket| (packet.new_available != 0).then_some(NetworkEvent::NewMail))?;
+        packet_handler.register(|packet: AchievementUpdatePacket| NetworkEvent::AchievementUpdate {
+            summary: AchievementSummary {
+                total_score: packet.total_score,
+                level: packet.level,
+                experience: packet.acheivement_experience,
+                experience_to_next_level: packet.acheivement_experience_to_next_level,
+            },
+            achievement: packet.acheivement_data.into(),
+        })?;
+        packet_handler.register(|packet: AchievementListPacket| NetworkEvent::AchievementList {
+            summary: AchievementSummary {
+                total_score: packet.total_score,
+                level: packet.level,
+                experience: packet.acheivement_experience,
+                experience_to_next_level: packet.acheivement_experience_to_next_level,
+            },
+            achievements: packet.acheivement_data.into_iter().map(Achievement::from).collect(),
+        })?;
+        packet_handler.register(|packet: AchievementRewardPacket| match packet.success {
+            0 => NetworkEvent::ChatMessage {
+                text: "Failed to claim the achievement reward.".to_owned(),
+                color: MessageColor::Error,
+            },
+            _ => NetworkEvent::AchievementRewarded {
+                achievement_id: packet.achievement_id,
+            },
+        })?;
         packet_handler.register_noop::<CriticalWeightUpdatePacket>()?;
         packet_handler.register(|packet: SpriteChangePacket| {
             (packet.sprite_type == 0).then_some(NetworkEvent::ChangeJob(packet.account_id, packet.value))
@@ -693,34 +1151,11 @@ where
             let inventory_items = inventory_items.clone();
 
             move |packet: RegularItemListPacket| {
-                inventory_items.borrow_mut().as_mut().expect("Unexpected inventory packet").extend(
-                    packet.item_information.into_iter().map(|item_information| {
-                        let RegularItemInformation {
-                            index,
-                            item_id,
-                            item_type,
-                            amount,
-                            equipped_position,
-                            slot,
-                            hire_expiration_date,
-                            flags,
-                        } = item_information;
-
-                        InventoryItem {
-                            index,
-                            metadata: NoMetadata,
-                            item_id,
-                            item_type,
-                            slot,
-                            hire_expiration_date,
-                            details: InventoryItemDetails::Regular {
-                                amount,
-                                equipped_position,
-                                flags,
-                            },
-                        }
-                    }),
-                );
+                inventory_items
+                    .borrow_mut()
+                    .as_mut()
+                    .expect("Unexpected inventory packet")
+                    .extend(packet.item_information.into_iter().map(InventoryItem::from));
                 NoNetworkEvents
             }
         })?;
@@ -728,57 +1163,71 @@ where
             let inventory_items = inventory_items.clone();
 
             move |packet: EquippableItemListPacket| {
-                inventory_items.borrow_mut().as_mut().expect("Unexpected inventory packet").extend(
-                    packet.item_information.into_iter().map(|item| {
-                        let EquippableItemInformation {
-                            index,
-                            item_id,
-                            item_type,
-                            equip_position,
-                            equipped_position,
-                            slot,
-                            hire_expiration_date,
-                            bind_on_equip_type,
-                            w_item_sprite_number,
-                            option_count,
-                            option_data,
-                            refinement_level,
-                            enchantment_level,
-                            flags,
-                        } = item;
-
-                        InventoryItem {
-                            index,
-                            metadata: NoMetadata,
-                            item_id,
-                            item_type,
-                            slot,
-                            hire_expiration_date,
-                            details: InventoryItemDetails::Equippable {
-                                equip_position,
-                                equipped_position,
-                                bind_on_equip_type,
-                                w_item_sprite_number,
-                                option_count,
-                                option_data,
-                                refinement_level,
-                                enchantment_level,
-                                flags,
-                            },
-                        }
-                    }),
-                );
+                inventory_items
+                    .borrow_mut()
+                    .as_mut()
+                    .expect("Unexpected inventory packet")
+                    .extend(packet.item_information.into_iter().map(InventoryItem::from));
                 NoNetworkEvents
             }
         })?;
+        // Older servers don't frame the inventory with start and end packets. Instead
+        // they always send the regular items first, followed by the equippable items.
         packet_handler.register({
             let inventory_items = inventory_items.clone();
 
-            move |_: InventoyEndPacket| {
-                let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
+            move |packet: LegacyRegularItemListPacket| {
+                let items = packet
+                    .item_information
+                    .into_iter()
+                    .map(|item_information| InventoryItem::from(RegularItemInformation::from(item_information)))
+                    .collect();
+
+                *inventory_items.borrow_mut() = Some(items);
+                NoNetworkEvents
+            }
+        })?;
+        packet_handler.register({
+            let inventory_items = inventory_items.clone();
+
+            move |packet: LegacyEquippableItemListPacket| {
+                let mut items = inventory_items.borrow_mut().take().unwrap_or_default();
+
+                items.extend(
+                    packet
+                        .item_information
+                        .into_iter()
+                        .map(|item_information| InventoryItem::from(EquippableItemInformation::from(item_information))),
+                );
+
                 NetworkEvent::SetInventory { items }
             }
         })?;
+        packet_handler.register({
+            let inventory_items = inventory_items.clone();
+
+            move |packet: InventoyEndPacket| {
+                let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
+
+                match packet.inventory_type {
+                    InventoryType::Storage | InventoryType::GuildStorage => {
+                        // Storage items are sent with the same packets as the inventory, but their
+                        // indices are only offset by 1 instead of 2.
+                        let items = items
+                            .into_iter()
+                            .map(|mut item| {
+                                item.index = InventoryIndex(item.index.0.wrapping_add(1));
+                                item
+                            })
+                            .collect();
+
+                        NetworkEvent::SetStorage { items }
+                    }
+                    InventoryType::Cart => NetworkEvent::SetCart { items },
+                    InventoryType::Inventory => NetworkEvent::SetInventory { items },
+                }
+            }
+        })?;
         packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
         packet_handler.register_noop::<MapTypePacket>()?;
         packet_handler.register(|packet: UpdateSkillTreePacket| NetworkEvent::SkillTree(packet.skill_information))?;
@@ -793,7 +1242,38 @@ where
                 })
                 .collect(),
         })?;
-        packet_handler.register_noop::<InitialStatusPacket>()?;
+        packet_handler.register(|packet: InitialStatusPacket| {
+            // The bonus values of the attributes are not part of this packet, they are
+            // sent right after in separate UpdateStatusPackets.
+            vec![
+                NetworkEvent::UpdateStatus(StatusType::StatusPoint(packet.status_points as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Strength(packet.strength as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUstr(packet.required_strength)),
+                NetworkEvent::UpdateStatus(StatusType::Agility(packet.agility as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUagi(packet.required_agility)),
+                NetworkEvent::UpdateStatus(StatusType::Vitality(packet.vitatity as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUvit(packet.required_vitatity)),
+                NetworkEvent::UpdateStatus(StatusType::Intelligence(packet.intelligence as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUint(packet.required_intelligence)),
+                NetworkEvent::UpdateStatus(StatusType::Dexterity(packet.dexterity as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUdex(packet.required_dexterity)),
+                NetworkEvent::UpdateStatus(StatusType::Luck(packet.luck as u32, 0)),
+                NetworkEvent::UpdateStatus(StatusType::SpUluk(packet.required_luck)),
+                NetworkEvent::UpdateStatus(StatusType::Attack1(packet.left_attack as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Attack2(packet.rigth_attack as u32)),
+                NetworkEvent::UpdateStatus(StatusType::MagicAttack1(packet.left_magic_attack as u32)),
+                NetworkEvent::UpdateStatus(StatusType::MagicAttack2(packet.rigth_magic_attack as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Defense1(packet.left_defense as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Defense2(packet.rigth_defense as u32)),
+                NetworkEvent::UpdateStatus(StatusType::MagicDefense1(packet.left_magic_defense as u32)),
+                NetworkEvent::UpdateStatus(StatusType::MagicDefense2(packet.rigth_magic_defense as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Hit(packet.hit as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Flee1(packet.flee as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Flee2(packet.flee2 as u32)),
+                NetworkEvent::UpdateStatus(StatusType::Critical(packet.crit as u32)),
+                NetworkEvent::UpdateStatus(StatusType::AttackSpeed(packet.attack_speed as u32)),
+            ]
+        })?;
         packet_handler.register_noop::<UpdatePartyInvitationStatePacket>()?;
         packet_handler.register_noop::<UpdateShowEquipPacket>()?;
         packet_handler.register_noop::<UpdateConfigurationPacket>()?;
@@ -818,12 +1298,29 @@ where
             NetworkEvent::HealEffect(packet.destination_entity_id, packet.heal_amount as usize)
         })?;
         packet_handler.register_noop::<DisplayPlayerHealEffect>()?;
-        packet_handler.register_noop::<StatusChangePacket>()?;
-        packet_handler.register_noop::<QuestNotificationPacket1>()?;
-        packet_handler.register_noop::<HuntingQuestNotificationPacket>()?;
-        packet_handler.register_noop::<HuntingQuestUpdateObjectivePacket>()?;
-        packet_handler.register_noop::<QuestRemovedPacket>()?;
-        packet_handler.register_noop::<QuestListPacket>()?;
+        packet_handler.register(|packet: StatusChangePacket| match packet.state {
+            0 => NetworkEvent::RemoveStatusEffect {
+                entity_id: packet.entity_id,
+                status_id: packet.index,
+            },
+            _ => NetworkEvent::AddStatusEffect {
+                entity_id: packet.entity_id,
+                status_effect: packet.into(),
+            },
+        })?;
+        packet_handler.register(|packet: QuestNotificationPacket1| NetworkEvent::QuestAdded { quest: packet.into() })?;
+        packet_handler.register(|packet: HuntingQuestNotificationPacket| NetworkEvent::UpdateQuestObjectives {
+            objectives: packet.objective_details,
+        })?;
+        packet_handler.register(
+            |packet: HuntingQuestUpdateObjectivePacket| NetworkEvent::UpdateQuestObjectives {
+                objectives: packet.objective_details,
+            },
+        )?;
+        packet_handler.register(|packet: QuestRemovedPacket| NetworkEvent::QuestRemoved { quest_id: packet.quest_id })?;
+        packet_handler.register(|packet: QuestListPacket| NetworkEvent::SetQuestList {
+            quests: packet.quests.into_iter().map(QuestInformation::from).collect(),
+        })?;
         packet_handler.register(|packet: VisualEffectPacket| {
             let path = match packet.effect {
                 VisualEffect::BaseLevelUp => "angel.str",
@@ -840,7 +1337,19 @@ where
 
             NetworkEvent::VisualEffect(path, packet.entity_id)
         })?;
-        packet_handler.register_noop::<DisplayGainedExperiencePacket>()?;
+        packet_handler.register(|packet: StatusIncreaseResultPacket| match packet.result {
+            0 => Some(NetworkEvent::ChatMessage {
+                text: "Not enough status points".to_owned(),
+                color: MessageColor::Error,
+            }),
+            _ => None,
+        })?;
+        packet_handler.register(|packet: DisplayGainedExperiencePacket| NetworkEvent::GainedExperience {
+            account_id: packet.account_id,
+            amount: packet.amount,
+            experience_type: packet.experience_type,
+            experience_source: packet.experience_source,
+        })?;
         packet_handler.register_noop::<DisplayImagePacket>()?;
         packet_handler.register_noop::<StateChangePacket>()?;
 
@@ -868,8 +1377,18 @@ where
                 enchantment_level,
             } = packet;
 
-            if result != ItemPickupResult::Success {
-                todo!();
+            let message = match result {
+                ItemPickupResult::Success => None,
+                ItemPickupResult::Invalid => Some("You can't pick up this item"),
+                ItemPickupResult::Overweight => Some("You can't carry any more weight"),
+                ItemPickupResult::NoSpace => Some("You can't carry any more items"),
+                ItemPickupResult::MaximumOfItem => Some("You can't carry any more of this item"),
+                ItemPickupResult::StackLimitation => Some("You can't stack any more of this item"),
+                ItemPickupResult::Unknown0 | ItemPickupResult::Unknown1 => Some("Failed to pick up the item"),
+            };
+
+            if let Some(message) = message {
+                return NetworkEvent::ItemPickupFailed { reason: result, message };
             }
 
             // TODO: Not sure where to store these, since the *InventoryItem packets are not
@@ -921,9 +1440,45 @@ where
             index: packet.index,
             amount: packet.amount,
         })?;
-        packet_handler.register(|packet: ServerTickPacket| NetworkEvent::UpdateClientTick(packet.client_tick))?;
+        packet_handler.register(|packet: UseItemSuccessPacket| NetworkEvent::ItemUsed {
+            entity_id: packet.entity_id,
+            index: packet.index,
+            item_id: packet.item_id,
+            remaining_amount: packet.remaining_amount,
+        })?;
+        packet_handler.register(|packet: UseItemFailedPacket| NetworkEvent::ItemUseFailed { index: packet.index })?;
+        packet_handler.register(|packet: DropItemSuccessPacket| NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: packet.index,
+            amount: packet.amount,
+        })?;
+        packet_handler.register(|packet: GroundItemEntryPacket| NetworkEvent::AddGroundItem(packet.into()))?;
+        packet_handler.register(|packet: GroundItemFallPacket| NetworkEvent::AddGroundItem(packet.into()))?;
+        packet_handler.register(|packet: GroundItemDisappearPacket| NetworkEvent::RemoveGroundItem(packet.ground_item_id))?;
+        packet_handler.register(
+            move |packet: ServerTickPacket| match pending_tick_request.answer(packet.client_tick) {
+                Some(sample) => {
+                    // The round trip that the tick estimate is based on is also the latency
+                    // of the connection, so it isn't measured a second time.
+                    statistics.latency_measured(sample.round_trip_time());
+                    NetworkEvent::TickSample(sample)
+                }
+                None => NetworkEvent::UpdateClientTick(packet.client_tick),
+            },
+        )?;
         packet_handler.register(|packet: RequestPlayerDetailsSuccessPacket| {
-            NetworkEvent::UpdateEntityDetails(EntityId(packet.character_id.0), packet.name)
+            let entity_id = EntityId(packet.character_id.0);
+            let mut events = vec![NetworkEvent::UpdateEntityDetails(entity_id, packet.name)];
+
+            if !packet.guild_name.is_empty() {
+                events.push(NetworkEvent::UpdateEntityGuild {
+                    entity_id,
+                    guild_name: packet.guild_name,
+                    position_name: packet.position_name,
+                });
+            }
+
+            events
         })?;
         packet_handler
             .register(|packet: RequestEntityDetailsSuccessPacket| NetworkEvent::UpdateEntityDetails(packet.entity_id, packet.name))?;
@@ -954,6 +1509,18 @@ where
         })?;
         packet_handler.register_noop::<Packet8302>()?;
         packet_handler.register_noop::<Packet0b18>()?;
+        packet_handler.register(|packet: LoginFailedPacket| {
+            let text = match packet.reason {
+                LoginFailedReason::ServerClosed => "Server closed",
+                LoginFailedReason::AlreadyLoggedIn => "Someone has already logged in with this id",
+                LoginFailedReason::AlreadyOnline => "Already online",
+            };
+
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            }
+        })?;
         packet_handler.register(|packet: MapServerLoginSuccessPacket| {
             (
                 NetworkEvent::UpdateClientTick(packet.client_tick),
@@ -1008,49 +1575,528 @@ where
             account_id: packet.account_id,
             character_id: packet.character_id,
         })?;
-        packet_handler.register_noop::<PartyInvitePacket>()?;
-        packet_handler.register_noop::<StatusChangeSequencePacket>()?;
-        packet_handler.register_noop::<ReputationPacket>()?;
-        packet_handler.register_noop::<ClanInfoPacket>()?;
-        packet_handler.register_noop::<ClanOnlineCountPacket>()?;
-        packet_handler.register_noop::<ChangeMapCellPacket>()?;
-        packet_handler.register_noop::<OpenMarketPacket>()?;
-        packet_handler.register(|packet: BuyOrSellPacket| NetworkEvent::AskBuyOrSell { shop_id: packet.shop_id })?;
-        packet_handler.register(|packet: ShopItemListPacket| {
-            let items = packet
-                .items
-                .into_iter()
-                .map(|item| ShopItem {
-                    metadata: NoMetadata,
-                    item_id: item.item_id,
-                    item_type: item.item_type,
-                    price: item.price,
-                    quantity: items::ItemQuantity::Infinite,
-                    weight: 0,
-                    location: item.location,
-                })
-                .collect();
-
-            NetworkEvent::OpenShop { items }
+        packet_handler.register(|packet: PartyInvitePacket| NetworkEvent::PartyInvitation {
+            party_id: packet.party_id,
+            party_name: packet.party_name,
         })?;
-        packet_handler.register(|packet: BuyShopItemsResultPacket| NetworkEvent::BuyingCompleted { result: packet.result })?;
-        packet_handler.register_noop::<ParameterChangePacket>()?;
-        packet_handler.register(|packet: SellListPacket| NetworkEvent::SellItemList { items: packet.items })?;
-        packet_handler.register(|packet: SellItemsResultPacket| NetworkEvent::SellingCompleted { result: packet.result })?;
-
-        Ok(packet_handler)
-    }
+        packet_handler.register(|packet: CreatePartyResultPacket| {
+            let (text, color) = match packet.result {
+                CreatePartyResult::Success => ("Party created.", MessageColor::Information),
+                CreatePartyResult::NameAlreadyExists => ("A party with this name already exists.", MessageColor::Error),
+                CreatePartyResult::AlreadyInParty => ("You are already in a party.", MessageColor::Error),
+                CreatePartyResult::NotAllowedOnMap => ("You can't create a party on this map.", MessageColor::Error),
+            };
 
-    pub fn request_character_list(&mut self) -> Result<(), NotConnectedError> {
-        self.send_character_server_packet(&RequestCharacterListPacket::default())
-    }
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color,
+            }
+        })?;
+        packet_handler.register(|packet: PartyInviteResultPacket| {
+            let name = packet.character_name;
+            let (text, color) = match packet.result {
+                PartyInviteResult::AlreadyInParty => (format!("{name} is already in a party."), MessageColor::Error),
+                PartyInviteResult::Rejected => (format!("{name} rejected the party invitation."), MessageColor::Information),
+                PartyInviteResult::Accepted => (format!("{name} joined the party."), MessageColor::Information),
+                PartyInviteResult::PartyFull => ("The party is full.".to_owned(), MessageColor::Error),
+                PartyInviteResult::SameAccount => (
+                    "You can't invite a character of the same account.".to_owned(),
+                    MessageColor::Error,
+                ),
+                PartyInviteResult::InvitationBlocked => (format!("{name} does not accept party invitations."), MessageColor::Error),
+                PartyInviteResult::NotOnline => (format!("{name} is not online."), MessageColor::Error),
+                PartyInviteResult::NotAllowedOnMap => ("You can't invite players on this map.".to_owned(), MessageColor::Error),
+                PartyInviteResult::LevelRestriction => (
+                    format!("{name} can't join because of the level restriction."),
+                    MessageColor::Error,
+                ),
+            };
 
-    pub fn select_character(&mut self, character_slot: usize) -> Result<(), NotConnectedError> {
-        self.send_character_server_packet(&SelectCharacterPacket::new(character_slot as u8))
-    }
+            NetworkEvent::ChatMessage { text, color }
+        })?;
+        packet_handler.register(|packet: PartyMemberListPacket| NetworkEvent::SetPartyMembers {
+            party_name: packet.party_name,
+            members: packet.members.into_iter().map(PartyMember::from).collect(),
+        })?;
+        packet_handler.register(|packet: LegacyPartyMemberListPacket| NetworkEvent::SetPartyMembers {
+            party_name: packet.party_name,
+            members: packet.members.into_iter().map(PartyMember::from).collect(),
+        })?;
+        packet_handler.register(|packet: PartyMemberJoinedPacket| NetworkEvent::PartyMemberJoined { member: packet.into() })?;
+        packet_handler.register(|packet: PartyMemberLeftPacket| match packet.reason {
+            PartyMemberLeftReason::Left | PartyMemberLeftReason::Expelled => {
+                let text = match packet.reason {
+                    PartyMemberLeftReason::Left => format!("{} left the party.", packet.name),
+                    _ => format!("{} was expelled from the party.", packet.name),
+                };
+
+                vec![
+                    NetworkEvent::ChatMessage {
+                        text,
+                        color: MessageColor::Information,
+                    },
+                    NetworkEvent::PartyMemberLeft {
+                        account_id: packet.account_id,
+                    },
+                ]
+            }
+            PartyMemberLeftReason::CannotLeaveOnMap => vec![NetworkEvent::ChatMessage {
+                text: "You can't leave the party on this map.".to_owned(),
+                color: MessageColor::Error,
+            }],
+            PartyMemberLeftReason::CannotExpelOnMap => vec![NetworkEvent::ChatMessage {
+                text: "You can't expel party members on this map.".to_owned(),
+                color: MessageColor::Error,
+            }],
+        })?;
+        packet_handler.register(|packet: PartyLeaderChangedPacket| NetworkEvent::PartyLeaderChanged {
+            account_id: packet.new_leader_account_id,
+        })?;
+        packet_handler.register_noop::<PartySettingsPacket>()?;
+        packet_handler.register(|packet: PartyMemberHealthPointsPacket| NetworkEvent::PartyMemberHealth {
+            account_id: packet.account_id,
+            health_points: packet.health_points,
+            maximum_health_points: packet.maximum_health_points,
+        })?;
+        packet_handler.register(|packet: PartyMemberPositionPacket| NetworkEvent::PartyMemberPosition {
+            account_id: packet.account_id,
+            position: packet.position,
+        })?;
+        packet_handler.register(|packet: PartyMemberJobLevelPacket| NetworkEvent::PartyMemberJobLevel {
+            account_id: packet.account_id,
+            job: packet.job,
+            base_level: packet.base_level,
+        })?;
+        packet_handler.register(|packet: ReceivePartyMessagePacket| NetworkEvent::ChatMessage {
+            text: packet.message,
+            color: MessageColor::Rgb {
+                red: 255,
+                green: 200,
+                blue: 200,
+            },
+        })?;
+        packet_handler.register(|packet: UpdateGuildPacket| NetworkEvent::UpdateGuild {
+            guild_id: packet.guild_id,
+            guild_name: packet.guild_name,
+            emblem_id: packet.emblem_id,
+            is_master: packet.is_master != 0,
+        })?;
+        packet_handler.register(|packet: GuildInformationPacket| NetworkEvent::SetGuildInformation {
+            information: packet.into(),
+        })?;
+        packet_handler.register(|packet: GuildMemberListPacket| NetworkEvent::SetGuildMembers {
+            members: packet.members.into_iter().map(GuildMember::from).collect(),
+        })?;
+        packet_handler.register(|packet: GuildPositionNamesPacket| NetworkEvent::SetGuildPositions {
+            positions: packet.positions,
+        })?;
+        packet_handler.register(|packet: GuildNoticePacket| NetworkEvent::GuildNotice {
+            subject: packet.subject,
+            notice: packet.notice,
+        })?;
+        packet_handler.register(|packet: GuildMemberOnlineStatusPacket| NetworkEvent::GuildMemberOnlineStatus {
+            account_id: packet.account_id,
+            is_online: packet.is_online != 0,
+        })?;
+        packet_handler.register(|packet: ReceiveGuildMessagePacket| NetworkEvent::ChatMessage {
+            text: packet.message,
+            color: MessageColor::Rgb {
+                red: 180,
+                green: 255,
+                blue: 180,
+            },
+        })?;
+        packet_handler.register(|packet: GuildInvitationPacket| NetworkEvent::GuildInvitation {
+            guild_id: packet.guild_id,
+            guild_name: packet.guild_name,
+        })?;
+        packet_handler.register(|packet: GuildInviteResultPacket| {
+            let (text, color) = match packet.result {
+                GuildInviteResult::AlreadyInGuild => ("The character is already in a guild.", MessageColor::Error),
+                GuildInviteResult::Rejected => ("The guild invitation was rejected.", MessageColor::Information),
+                GuildInviteResult::Accepted => ("The guild invitation was accepted.", MessageColor::Information),
+                GuildInviteResult::GuildFull => ("The guild is full.", MessageColor::Error),
+            };
 
-    pub fn map_loaded(&mut self) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&MapLoadedPacket::default())
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color,
+            }
+        })?;
+        packet_handler.register(|packet: GuildMemberLeftPacket| NetworkEvent::GuildMemberLeft {
+            name: packet.name,
+            reason: packet.reason,
+        })?;
+        packet_handler.register(|packet: GuildEmblemPacket| NetworkEvent::GuildEmblem {
+            guild_id: packet.guild_id,
+            emblem_id: packet.emblem_id,
+            emblem_data: packet.emblem_data,
+        })?;
+        packet_handler.register(|packet: ReceiveWhisperPacket| NetworkEvent::WhisperReceived {
+            sender_name: packet.sender_name,
+            message: packet.message,
+        })?;
+        packet_handler.register(|packet: WhisperResultPacket| match packet.result {
+            WhisperResult::Success => None,
+            reason => Some(NetworkEvent::WhisperFailed { reason }),
+        })?;
+        packet_handler.register(|packet: TradeRequestPacket| NetworkEvent::TradeRequested {
+            name: packet.name,
+            character_id: packet.character_id,
+            base_level: packet.base_level,
+        })?;
+        packet_handler.register(|packet: TradeRequestResultPacket| {
+            let (text, color) = match packet.result {
+                TradeRequestResult::Accepted => return NetworkEvent::TradeStarted,
+                TradeRequestResult::TooFarAway => ("The character is too far away.", MessageColor::Error),
+                TradeRequestResult::CharacterNotFound => ("The character does not exist.", MessageColor::Error),
+                TradeRequestResult::Failed => ("The trade request failed.", MessageColor::Error),
+                TradeRequestResult::Rejected => ("The trade request was rejected.", MessageColor::Information),
+                TradeRequestResult::Busy => ("The character is already trading.", MessageColor::Error),
+            };
+
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color,
+            }
+        })?;
+        packet_handler.register(|packet: AddTradeItemResultPacket| {
+            let text = match packet.result {
+                AddTradeItemResult::Success => {
+                    return NetworkEvent::TradeOfferAccepted {
+                        index: (packet.index != 0).then(|| InventoryIndex(packet.index - 2)),
+                    };
+                }
+                AddTradeItemResult::Overweight => "The trade partner can not carry this much weight.",
+                AddTradeItemResult::TradeClosed => "The offer is already locked.",
+                AddTradeItemResult::TooManyItems => "No more items can be added to the trade.",
+                AddTradeItemResult::TooManyOfThisItem => "The trade partner can not carry more of this item.",
+            };
+
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            }
+        })?;
+        packet_handler.register(|packet: TradeItemAddedPacket| {
+            let TradeItemAddedPacket {
+                item_id,
+                item_type,
+                amount,
+                is_identified,
+                is_broken,
+                cards,
+                option_data,
+                equip_position,
+                look: _,
+                refinement_level,
+                enchantment_level,
+            } = packet;
+
+            if item_id == ItemId(0) {
+                return NetworkEvent::TradePartnerZenyAdded { amount };
+            }
+
+            let details = match equip_position.is_empty() {
+                true => InventoryItemDetails::Regular {
+                    amount: amount as u16,
+                    equipped_position: EquipPosition::empty(),
+                    flags: {
+                        let mut flags = RegularItemFlags::empty();
+                        flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                        flags
+                    },
+                },
+                false => InventoryItemDetails::Equippable {
+                    equip_position,
+                    equipped_position: EquipPosition::empty(),
+                    bind_on_equip_type: 0,
+                    w_item_sprite_number: 0,
+                    option_count: option_data.len() as u8,
+                    option_data,
+                    refinement_level,
+                    enchantment_level,
+                    flags: {
+                        let mut flags = EquippableItemFlags::empty();
+                        flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                        flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                        flags
+                    },
+                },
+            };
+
+            let item = InventoryItem {
+                metadata: NoMetadata,
+                index: InventoryIndex(0),
+                item_id,
+                item_type,
+                slot: cards,
+                hire_expiration_date: 0,
+                details,
+            };
+
+            NetworkEvent::TradePartnerItemAdded { item }
+        })?;
+        packet_handler.register(|packet: TradeLockedPacket| NetworkEvent::TradeLocked {
+            is_partner: packet.is_partner != 0,
+        })?;
+        packet_handler.register(|_: TradeCancelledPacket| NetworkEvent::TradeCancelled)?;
+        packet_handler.register(|packet: TradeCompletedPacket| match packet.result {
+            TradeResult::Success => vec![NetworkEvent::TradeCompleted],
+            TradeResult::Failed => vec![
+                NetworkEvent::ChatMessage {
+                    text: "The trade failed.".to_owned(),
+                    color: MessageColor::Error,
+                },
+                NetworkEvent::TradeCancelled,
+            ],
+        })?;
+        packet_handler.register_noop::<StorageCapacityPacket>()?;
+        packet_handler.register(|packet: StorageItemAddedPacket| {
+            let StorageItemAddedPacket {
+                index,
+                amount,
+                item_id,
+                item_type,
+                is_identified,
+                is_broken,
+                refinement_level,
+                cards,
+                option_data,
+                enchantment_level,
+            } = packet;
+
+            // The packet does not contain the equip position, so we have to rely on the
+            // item type (armor, weapon, pet armor, and shadow gear) instead.
+            let details = match item_type {
+                4 | 5 | 8 | 12 => InventoryItemDetails::Equippable {
+                    equip_position: EquipPosition::empty(),
+                    equipped_position: EquipPosition::empty(),
+                    bind_on_equip_type: 0,
+                    w_item_sprite_number: 0,
+                    option_count: option_data.len() as u8,
+                    option_data,
+                    refinement_level,
+                    enchantment_level,
+                    flags: {
+                        let mut flags = EquippableItemFlags::empty();
+                        flags.set(EquippableItemFlags::IDENTIFIED, is_identified != 0);
+                        flags.set(EquippableItemFlags::IS_BROKEN, is_broken != 0);
+                        flags
+                    },
+                },
+                _ => InventoryItemDetails::Regular {
+                    amount: amount as u16,
+                    equipped_position: EquipPosition::empty(),
+                    flags: {
+                        let mut flags = RegularItemFlags::empty();
+                        flags.set(RegularItemFlags::IDENTIFIED, is_identified != 0);
+                        flags
+                    },
+                },
+            };
+
+            let item = InventoryItem {
+                metadata: NoMetadata,
+                index: InventoryIndex(index.0),
+                item_id,
+                item_type,
+                slot: cards,
+                hire_expiration_date: 0,
+                details,
+            };
+
+            NetworkEvent::StorageItemAdded { item }
+        })?;
+        packet_handler.register(|packet: StorageItemRemovedPacket| NetworkEvent::StorageItemRemoved {
+            index: packet.index,
+            amount: packet.amount,
+        })?;
+        packet_handler.register(|_: StorageClosedPacket| NetworkEvent::StorageClosed)?;
+        packet_handler.register_noop::<CartCapacityPacket>()?;
+        packet_handler.register(|packet: CartItemRemovedPacket| NetworkEvent::CartItemRemoved {
+            index: packet.index,
+            amount: packet.amount,
+        })?;
+        packet_handler.register(|packet: OpenVendingSetupPacket| NetworkEvent::OpenVendingSetup {
+            maximum_items: packet.maximum_items,
+        })?;
+        packet_handler.register(|packet: OpenVendingShopResultPacket| match packet.result {
+            OpenVendingShopResult::Success => NetworkEvent::VendingShopOpened,
+            OpenVendingShopResult::Failed => NetworkEvent::ChatMessage {
+                text: "Failed to open the vending shop.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: VendingShopBoardPacket| NetworkEvent::VendingShopBoard {
+            entity_id: packet.entity_id,
+            title: packet.title,
+        })?;
+        packet_handler.register(|packet: VendingShopBoardRemovedPacket| NetworkEvent::VendingShopBoardRemoved {
+            entity_id: packet.entity_id,
+        })?;
+        packet_handler.register(|packet: VendingItemListPacket| NetworkEvent::VendingItemList {
+            entity_id: packet.entity_id,
+            unique_id: packet.unique_id,
+            items: packet.items.into_iter().map(VendingItem::from).collect(),
+        })?;
+        packet_handler.register(|packet: BuyVendingItemsResultPacket| {
+            let text = match packet.result {
+                BuyVendingItemsResult::Success => return None,
+                BuyVendingItemsResult::NotEnoughZeny => "You do not have enough zeny.",
+                BuyVendingItemsResult::Overweight => "You can not carry this much weight.",
+                BuyVendingItemsResult::OutOfStock => "The item is out of stock.",
+                BuyVendingItemsResult::VendorBusy => "The merchant is busy.",
+                BuyVendingItemsResult::ShopChanged => "The shop changed in the meantime.",
+                BuyVendingItemsResult::NoSalesInformation => "The item is no longer for sale.",
+            };
+
+            Some(NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            })
+        })?;
+        packet_handler.register_noop::<OwnVendingItemListPacket>()?;
+        packet_handler.register(|packet: VendingItemSoldPacket| NetworkEvent::ChatMessage {
+            text: format!("Sold {} item(s) for {} zeny.", packet.amount, packet.zeny),
+            color: MessageColor::Information,
+        })?;
+        packet_handler.register(|packet: MailListPacket| {
+            let mails: Vec<MailPreview> = packet.mails.into_iter().map(MailPreview::from).collect();
+            let count = mails.iter().filter(|mail| !mail.is_read).count();
+
+            vec![
+                NetworkEvent::MailList {
+                    mails,
+                    is_end: packet.is_end != 0,
+                },
+                NetworkEvent::UnreadMailCount { count },
+            ]
+        })?;
+        packet_handler.register(|packet: MailContentPacket| NetworkEvent::MailContent { mail: packet.into() })?;
+        packet_handler.register(|packet: MailDeletedPacket| NetworkEvent::MailDeleted { mail_id: packet.mail_id })?;
+        packet_handler.register(|packet: MailZenyResultPacket| match packet.result {
+            MailAttachmentResult::Success => NetworkEvent::MailZenyTaken { mail_id: packet.mail_id },
+            _ => NetworkEvent::ChatMessage {
+                text: "Failed to take the zeny.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register(|packet: MailItemsResultPacket| match packet.result {
+            MailAttachmentResult::Success => NetworkEvent::MailItemsTaken { mail_id: packet.mail_id },
+            MailAttachmentResult::Failed => NetworkEvent::ChatMessage {
+                text: "Failed to take the items.".to_owned(),
+                color: MessageColor::Error,
+            },
+            MailAttachmentResult::Overweight => NetworkEvent::ChatMessage {
+                text: "You can not carry this much weight.".to_owned(),
+                color: MessageColor::Error,
+            },
+        })?;
+        packet_handler.register_noop::<OpenWriteMailResultPacket>()?;
+        packet_handler.register(|packet: MailReceiverPacket| NetworkEvent::MailReceiver {
+            character_id: (packet.character_id != CharacterId(0)).then_some(packet.character_id),
+        })?;
+        packet_handler.register(|packet: AddMailItemResultPacket| {
+            let text = match packet.result {
+                AddMailItemResult::Success => {
+                    let item = MailItemInformation {
+                        amount: packet.amount,
+                        item_id: packet.item_id,
+                        is_identified: packet.is_identified,
+                        is_broken: packet.is_broken,
+                        refinement_level: packet.refinement_level,
+                        cards: packet.cards,
+                        location: packet.location,
+                        item_type: packet.item_type,
+                        look: 0,
+                        bind_on_equip_type: 0,
+                        option_data: packet.option_data,
+                        enchantment_level: packet.enchantment_level,
+                    };
+
+                    let item = InventoryItem {
+                        index: packet.index,
+                        ..InventoryItem::from(item)
+                    };
+
+                    return NetworkEvent::MailItemAttached { item };
+                }
+                AddMailItemResult::Overweight => "The mail can not carry this much weight.",
+                AddMailItemResult::Failed => "Failed to attach the item.",
+                AddMailItemResult::TooManyItems => "No more items can be attached to the mail.",
+                AddMailItemResult::NotTradable => "This item can not be sent.",
+            };
+
+            NetworkEvent::ChatMessage {
+                text: text.to_owned(),
+                color: MessageColor::Error,
+            }
+        })?;
+        packet_handler.register(|packet: RemoveMailItemResultPacket| {
+            (packet.success != 0).then_some(NetworkEvent::MailItemDetached {
+                index: packet.index,
+                amount: packet.amount,
+            })
+        })?;
+        packet_handler.register(|packet: SendMailResultPacket| match packet.success {
+            0 => NetworkEvent::ChatMessage {
+                text: "Failed to send the mail.".to_owned(),
+                color: MessageColor::Error,
+            },
+            _ => NetworkEvent::MailSent,
+        })?;
+        packet_handler.register(|packet: StatusChangeSequencePacket| match packet.state {
+            0 => NetworkEvent::RemoveStatusEffect {
+                entity_id: packet.entity_id,
+                status_id: packet.index,
+            },
+            _ => NetworkEvent::AddStatusEffect {
+                entity_id: packet.entity_id,
+                status_effect: packet.into(),
+            },
+        })?;
+        packet_handler.register_noop::<ReputationPacket>()?;
+        packet_handler.register_noop::<ClanInfoPacket>()?;
+        packet_handler.register_noop::<ClanOnlineCountPacket>()?;
+        packet_handler.register_noop::<ChangeMapCellPacket>()?;
+        packet_handler.register_noop::<OpenMarketPacket>()?;
+        packet_handler.register(|packet: BuyOrSellPacket| NetworkEvent::AskBuyOrSell { shop_id: packet.shop_id })?;
+        packet_handler.register(|packet: ShopItemListPacket| {
+            let items = packet
+                .items
+                .into_iter()
+                .map(|item| ShopItem {
+                    metadata: NoMetadata,
+                    item_id: item.item_id,
+                    item_type: item.item_type,
+                    price: item.price,
+                    quantity: items::ItemQuantity::Infinite,
+                    weight: 0,
+                    location: item.location,
+                })
+                .collect();
+
+            NetworkEvent::OpenShop { items }
+        })?;
+        packet_handler.register(|packet: BuyShopItemsResultPacket| NetworkEvent::BuyingCompleted { result: packet.result })?;
+        packet_handler.register_noop::<ParameterChangePacket>()?;
+        packet_handler.register(|packet: SellListPacket| NetworkEvent::SellItemList { items: packet.items })?;
+        packet_handler.register(|packet: SellItemsResultPacket| NetworkEvent::SellingCompleted { result: packet.result })?;
+
+        Ok(packet_handler)
+    }
+
+    pub fn request_character_list(&mut self) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&RequestCharacterListPacket::default())
+    }
+
+    pub fn select_character(&mut self, character_slot: usize) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&SelectCharacterPacket::new(character_slot as u8))
+    }
+
+    pub fn map_loaded(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MapLoadedPacket::default())
     }
 
     pub fn log_out(&mut self) -> Result<(), NotConnectedError> {
@@ -1079,6 +2125,10 @@ where
         self.send_map_server_packet(&GlobalMessagePacket::new(complete_message))
     }
 
+    pub fn send_emotion(&mut self, emotion: EmotionId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestEmotionPacket::new(emotion))
+    }
+
     pub fn start_dialog(&mut self, npc_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&StartDialogPacket::new(npc_id))
     }
@@ -1103,6 +2153,18 @@ where
         self.send_map_server_packet(&RequestUnequipItemPacket::new(item_index))
     }
 
+    pub fn use_item(&mut self, item_index: InventoryIndex, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&UseItemPacket::new(item_index, account_id))
+    }
+
+    pub fn drop_item(&mut self, item_index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&DropItemPacket::new(item_index, amount))
+    }
+
+    pub fn pick_up_item(&mut self, ground_item_id: GroundItemId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PickUpItemPacket::new(ground_item_id))
+    }
+
     pub fn cast_skill(&mut self, skill_id: SkillId, skill_level: SkillLevel, entity_id: EntityId) -> Result<(), NotConnectedError> {
         self.send_map_server_packet(&UseSkillAtIdPacket::new(skill_level, skill_id, entity_id))
     }
@@ -1153,77 +2215,2288 @@ where
         ))
     }
 
-    pub fn create_character(&mut self, slot: usize, name: String) -> Result<(), NotConnectedError> {
-        let hair_color = 0;
-        let hair_style = 0;
-        let start_job = 0;
-        let sex = Sex::Male;
-
-        self.send_character_server_packet(&CreateCharacterPacket::new(
-            name, slot as u8, hair_color, hair_style, start_job, sex,
+    pub fn create_party(&mut self, party_name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CreatePartyPacket::new(
+            party_name,
+            PartyItemRule::Individual,
+            PartyItemRule::Individual,
         ))
     }
 
-    pub fn delete_character(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
-        let email = "a@a.com".to_string();
-
-        self.send_character_server_packet(&DeleteCharacterPacket::new(character_id, email))
+    pub fn invite_to_party(&mut self, character_name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&InviteToPartyPacket::new(character_name))
     }
 
-    pub fn switch_character_slot(&mut self, origin_slot: usize, destination_slot: usize) -> Result<(), NotConnectedError> {
-        self.send_character_server_packet(&SwitchCharacterSlotPacket::new(origin_slot as u16, destination_slot as u16))
+    pub fn reject_party_invitation(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Reject))
     }
 
-    pub fn set_hotkey_data(&mut self, tab: HotbarTab, index: HotbarSlot, hotkey_data: HotkeyData) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&SetHotkeyData2Packet::new(tab, index, hotkey_data))
+    pub fn accept_party_invitation(&mut self, party_id: PartyId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&PartyInviteResponsePacket::new(party_id, PartyInviteResponse::Accept))
     }
 
-    pub fn select_buy_or_sell(&mut self, shop_id: ShopId, buy_or_sell: BuyOrSellOption) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&SelectBuyOrSellPacket::new(shop_id, buy_or_sell))
+    pub fn leave_party(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LeavePartyPacket::default())
     }
 
-    pub fn purchase_items(&mut self, items: Vec<ShopItem<u32>>) -> Result<(), NotConnectedError> {
-        let item_information = items
-            .into_iter()
-            .map(|item| BuyShopItemInformation {
-                item_id: item.item_id,
-                amount: item.metadata,
-            })
-            .collect();
+    pub fn expel_party_member(&mut self, account_id: AccountId, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ExpelPartyMemberPacket::new(account_id, name))
+    }
 
-        self.send_map_server_packet(&BuyShopItemsPacket::new(item_information))
+    pub fn change_party_leader(&mut self, account_id: AccountId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ChangePartyLeaderPacket::new(account_id))
     }
 
-    pub fn close_shop(&mut self) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&CloseShopPacket::new())
+    pub fn send_party_message(&mut self, player_name: &str, message: &str) -> Result<(), NotConnectedError> {
+        let complete_message = format!("{} : {}", player_name, message);
+
+        self.send_map_server_packet(&PartyMessagePacket::new(complete_message))
     }
 
-    pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
-        self.send_map_server_packet(&SellItemsPacket { items })
+    pub fn request_guild_menu(&mut self, menu_type: GuildMenuType) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildMenuPacket::new(menu_type))
     }
-}
 
-#[cfg(test)]
-mod packet_handlers {
-    use ragnarok_packets::handler::NoPacketCallback;
+    pub fn request_guild_emblem(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestGuildEmblemPacket::new(guild_id))
+    }
 
-    use crate::NetworkingSystem;
+    pub fn invite_to_guild(&mut self, name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&InviteToGuildPacket::new(name))
+    }
 
-    #[test]
-    fn login_server() {
-        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback);
-        assert!(result.is_ok());
+    pub fn reject_guild_invitation(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&GuildInviteResponsePacket::new(guild_id, GuildInviteResponse::Reject))
     }
 
-    #[test]
-    fn character_server() {
-        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback);
-        assert!(result.is_ok());
+    pub fn accept_guild_invitation(&mut self, guild_id: GuildId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&GuildInviteResponsePacket::new(guild_id, GuildInviteResponse::Accept))
+    }
+
+    pub fn leave_guild(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        character_id: CharacterId,
+        reason: String,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LeaveGuildPacket::new(guild_id, account_id, character_id, reason))
+    }
+
+    pub fn send_guild_message(&mut self, player_name: &str, message: &str) -> Result<(), NotConnectedError> {
+        let complete_message = format!("{} : {}", player_name, message);
+
+        self.send_map_server_packet(&GuildMessagePacket::new(complete_message))
+    }
+
+    pub fn send_whisper(&mut self, name: String, message: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SendWhisperPacket::new(name, message))
+    }
+
+    pub fn request_trade(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestTradePacket::new(entity_id))
+    }
+
+    pub fn reject_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&TradeRequestResponsePacket::new(TradeRequestResponse::Reject))
+    }
+
+    pub fn accept_trade_request(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&TradeRequestResponsePacket::new(TradeRequestResponse::Accept))
+    }
+
+    pub fn add_trade_item(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        // Index 0 is reserved for zeny, so the inventory index is offset by 2.
+        self.send_map_server_packet(&AddTradeItemPacket::new(index.0 + 2, amount))
+    }
+
+    pub fn add_trade_zeny(&mut self, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddTradeItemPacket::new(0, amount))
+    }
+
+    pub fn lock_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&LockTradePacket::new())
+    }
+
+    pub fn cancel_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CancelTradePacket::new())
+    }
+
+    pub fn commit_trade(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CommitTradePacket::new())
+    }
+
+    pub fn move_item_to_storage(&mut self, index: InventoryIndex, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemToStoragePacket::new(index, amount))
+    }
+
+    pub fn move_item_from_storage(&mut self, index: StorageIndex, amount: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&MoveItemFromStoragePacket::new(index, amount))
+    }
+
+    pub fn close_storage(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseStoragePacket::new())
+    }
+
+    pub fn open_vending_shop(&mut self, title: String, items: Vec<VendingShopItemInformation>) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenVendingShopPacket::new(title, 1, items))
+    }
+
+    pub fn cancel_vending_setup(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenVendingShopPacket::new(String::new(), 0, Vec::new()))
+    }
+
+    pub fn close_vending_shop(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseVendingShopPacket::new())
+    }
+
+    pub fn request_vending_items(&mut self, entity_id: EntityId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestVendingItemListPacket::new(entity_id))
+    }
+
+    pub fn buy_vending_items(
+        &mut self,
+        entity_id: EntityId,
+        unique_id: u32,
+        items: Vec<VendingPurchaseInformation>,
+    ) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&BuyVendingItemsPacket::new(entity_id, unique_id, items))
+    }
+
+    pub fn open_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenMailboxPacket::new(MailType::Character, MailId(0)))
+    }
+
+    /// Request the mails following `mail_id`, which should be the oldest mail
+    /// that was received so far.
+    pub fn request_more_mails(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RefreshMailboxPacket::new(mail_type, mail_id))
+    }
+
+    pub fn close_mailbox(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseMailboxPacket::new())
+    }
+
+    pub fn read_mail(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&ReadMailPacket::new(mail_type, mail_id))
+    }
+
+    pub fn delete_mail(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&DeleteMailPacket::new(mail_type, mail_id))
+    }
+
+    pub fn take_mail_zeny(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestMailZenyPacket::new(mail_id, mail_type))
+    }
+
+    pub fn take_mail_items(&mut self, mail_type: MailType, mail_id: MailId) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestMailItemsPacket::new(mail_id, mail_type))
+    }
+
+    /// Start writing a new mail. This discards the attachments of any mail
+    /// that was not sent.
+    pub fn start_writing_mail(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&OpenWriteMailPacket::new(String::new()))
+    }
+
+    pub fn check_mail_receiver(&mut self, receiver_name: String) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CheckMailReceiverPacket::new(receiver_name))
+    }
+
+    pub fn attach_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&AddMailItemPacket::new(index, amount))
+    }
+
+    pub fn detach_mail_item(&mut self, index: InventoryIndex, amount: u16) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RemoveMailItemPacket::new(index, amount))
+    }
+
+    /// Send the mail that is being written. The receiver has to be looked up
+    /// with [`check_mail_receiver`](Self::check_mail_receiver) first.
+    pub fn send_mail(
+        &mut self,
+        receiver_name: String,
+        sender_name: String,
+        receiver_character_id: CharacterId,
+        title: String,
+        text: String,
+        zeny: u64,
+    ) -> Result<(), NotConnectedError> {
+        // NOTE: Both lengths include the null terminator.
+        let title_length = title.len() as u16 + 1;
+        let text_length = text.len() as u16 + 1;
+
+        self.send_map_server_packet(&SendMailPacket::new(
+            receiver_name,
+            sender_name,
+            zeny,
+            title_length,
+            text_length,
+            receiver_character_id,
+            title,
+            text,
+        ))
+    }
+
+    pub fn request_achievement_reward(&mut self, achievement_id: u32) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestAchievementRewardPacket::new(achievement_id))
+    }
+
+    pub fn request_status_increase(&mut self, status_type: StatusIncreaseType) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&RequestStatusIncreasePacket::new(status_type, 1))
+    }
+
+    pub fn create_character(
+        &mut self,
+        slot: usize,
+        name: String,
+        hair_color: u16,
+        hair_style: u16,
+        start_job: u16,
+        sex: Sex,
+    ) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&CreateCharacterPacket::new(
+            name, slot as u8, hair_color, hair_style, start_job, sex,
+        ))
+    }
+
+    /// Delete a character right away. Only supported by servers that don't
+    /// use delayed deletion. Depending on the configuration of the server,
+    /// `confirmation` is either the email or the birthdate of the account.
+    pub fn delete_character(&mut self, character_id: CharacterId, confirmation: String) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&DeleteCharacterPacket::new(character_id, confirmation))
+    }
+
+    pub fn reserve_character_deletion(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&ReserveCharacterDeletionPacket::new(character_id))
+    }
+
+    /// Delete a character after its deletion was reserved. `birthdate` is the
+    /// birthdate of the account owner in the format `YYMMDD`.
+    pub fn accept_character_deletion(&mut self, character_id: CharacterId, birthdate: [u8; 6]) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&AcceptCharacterDeletionPacket::new(character_id, birthdate))
+    }
+
+    pub fn cancel_character_deletion(&mut self, character_id: CharacterId) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&CancelCharacterDeletionPacket::new(character_id))
+    }
+
+    pub fn switch_character_slot(&mut self, origin_slot: usize, destination_slot: usize) -> Result<(), NotConnectedError> {
+        self.send_character_server_packet(&SwitchCharacterSlotPacket::new(origin_slot as u16, destination_slot as u16))
+    }
+
+    pub fn set_hotkey_data(&mut self, tab: HotbarTab, index: HotbarSlot, hotkey_data: HotkeyData) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SetHotkeyData2Packet::new(tab, index, hotkey_data))
+    }
+
+    pub fn select_buy_or_sell(&mut self, shop_id: ShopId, buy_or_sell: BuyOrSellOption) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SelectBuyOrSellPacket::new(shop_id, buy_or_sell))
+    }
+
+    pub fn purchase_items(&mut self, items: Vec<ShopItem<u32>>) -> Result<(), NotConnectedError> {
+        let item_information = items
+            .into_iter()
+            .map(|item| BuyShopItemInformation {
+                item_id: item.item_id,
+                amount: item.metadata,
+            })
+            .collect();
+
+        self.send_map_server_packet(&BuyShopItemsPacket::new(item_information))
+    }
+
+    pub fn close_shop(&mut self) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&CloseShopPacket::new())
+    }
+
+    pub fn sell_items(&mut self, items: Vec<SoldItemInformation>) -> Result<(), NotConnectedError> {
+        self.send_map_server_packet(&SellItemsPacket { items })
+    }
+}
+
+#[cfg(test)]
+mod packet_handlers {
+    use std::time::Instant;
+
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::{PacketVersion, ServerType};
+
+    use crate::clock::PendingTickRequest;
+    use crate::{NetworkStatisticsHandle, NetworkingSystem};
+
+    #[test]
+    fn login_server() {
+        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, PacketVersion::DEFAULT);
+        assert!(result.is_ok());
+
+        let result = NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, PacketVersion(20150101));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn character_server() {
+        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, PacketVersion::DEFAULT);
+        assert!(result.is_ok());
+
+        let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, PacketVersion(20150101));
+        assert!(result.is_ok());
     }
 
     #[test]
     fn map_server() {
-        let result = NetworkingSystem::create_map_server_packet_handler(NoPacketCallback);
+        let result = NetworkingSystem::create_map_server_packet_handler(
+            NoPacketCallback,
+            PacketVersion::DEFAULT,
+            PendingTickRequest::new(Instant::now()),
+            NetworkStatisticsHandle::default().collector(ServerType::Map),
+        );
+        assert!(result.is_ok());
+
+        let result = NetworkingSystem::create_map_server_packet_handler(
+            NoPacketCallback,
+            PacketVersion(20150101),
+            PendingTickRequest::new(Instant::now()),
+            NetworkStatisticsHandle::default().collector(ServerType::Map),
+        );
         assert!(result.is_ok());
     }
 }
+
+#[cfg(test)]
+mod reconnecting {
+    use std::io::Write;
+    use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    use std::time::Duration;
+
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::*;
+
+    use crate::mock_server::poll_until;
+    use crate::{
+        CharacterServerLoginData, DisconnectReason, LoginServerLoginData, NetworkEvent, NetworkTaskError, NetworkingSystem, ReconnectPolicy,
+    };
+
+    const POLICY: ReconnectPolicy = ReconnectPolicy {
+        max_attempts: 2,
+        initial_delay: Duration::from_millis(10),
+        max_delay: Duration::from_millis(20),
+    };
+
+    fn connect(listener: &TcpListener) -> NetworkingSystem<NoPacketCallback> {
+        let mut networking_system = NetworkingSystem::spawn();
+        networking_system.set_reconnect_policy(Some(POLICY));
+
+        let login_server_login_data = LoginServerLoginData {
+            account_id: AccountId(1),
+            login_id1: 0,
+            login_id2: 0,
+            sex: Sex::Male,
+        };
+        let character_server_login_data = CharacterServerLoginData {
+            server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server_port: listener.local_addr().unwrap().port(),
+            character_id: CharacterId(1),
+        };
+
+        networking_system.connect_to_map_server(&login_server_login_data, character_server_login_data);
+        networking_system
+    }
+
+    #[test]
+    fn reconnects_after_connection_drop() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut networking_system = connect(&listener);
+
+        let server = std::thread::spawn(move || {
+            // Drop the first connection right away.
+            drop(listener.accept().unwrap());
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let login_success = MapServerLoginSuccessPacket::new(ClientTick(100), WorldPosition::new(10, 10), 0);
+            stream.write_all(&login_success.packet_to_bytes().unwrap()).unwrap();
+            stream
+        });
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MapServerReconnected)
+        });
+        let _stream = server.join().unwrap();
+
+        assert!(matches!(events[0], NetworkEvent::MapServerReconnecting { attempt: 1, .. }));
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, NetworkEvent::MapServerDisconnected { .. }))
+        );
+    }
+
+    #[test]
+    fn rejected_login_is_a_failed_attempt() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut networking_system = connect(&listener);
+
+        let server = std::thread::spawn(move || {
+            drop(listener.accept().unwrap());
+
+            // The old session is still open, so the server rejects the first reconnect.
+            let (mut stream, _) = listener.accept().unwrap();
+            let login_failed = LoginFailedPacket::new(LoginFailedReason::AlreadyLoggedIn);
+            stream.write_all(&login_failed.packet_to_bytes().unwrap()).unwrap();
+            drop(stream);
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let login_success = MapServerLoginSuccessPacket::new(ClientTick(100), WorldPosition::new(10, 10), 0);
+            stream.write_all(&login_success.packet_to_bytes().unwrap()).unwrap();
+            stream
+        });
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MapServerReconnected)
+        });
+        let _stream = server.join().unwrap();
+
+        let attempts: Vec<u32> = events
+            .iter()
+            .filter_map(|event| match event {
+                NetworkEvent::MapServerReconnecting { attempt, .. } => Some(*attempt),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(attempts, [1, 2]);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, NetworkEvent::MapServerReconnected))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut networking_system = connect(&listener);
+
+        // Drop the connection and stop listening so every reconnect attempt fails.
+        drop(listener.accept().unwrap());
+        drop(listener);
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MapServerDisconnected { .. })
+        });
+
+        let attempts = events
+            .iter()
+            .filter(|event| matches!(event, NetworkEvent::MapServerReconnecting { .. }))
+            .count();
+
+        assert_eq!(attempts, POLICY.max_attempts as usize);
+        assert!(matches!(
+            events.last(),
+            Some(NetworkEvent::MapServerDisconnected {
+                reason: DisconnectReason::ConnectionError(NetworkTaskError::FailedToConnect)
+            })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod timeouts {
+    use std::io::Write;
+    use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    use std::time::Duration;
+
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::*;
+
+    use crate::mock_server::poll_until;
+    use crate::server::connect_with_timeout;
+    use crate::{
+        CharacterServerLoginData, DisconnectReason, LoginServerLoginData, NetworkEvent, NetworkTaskError, NetworkTimeouts,
+        NetworkingSystem, ServerTimeouts,
+    };
+
+    const TIMEOUTS: ServerTimeouts = ServerTimeouts {
+        connect: Duration::from_millis(200),
+        handshake: Duration::from_millis(200),
+        idle: Some(Duration::from_millis(200)),
+    };
+
+    fn connect(listener: &TcpListener) -> NetworkingSystem<NoPacketCallback> {
+        let mut networking_system = NetworkingSystem::spawn();
+        networking_system.set_timeouts(NetworkTimeouts {
+            map_server: TIMEOUTS,
+            ..NetworkTimeouts::default()
+        });
+
+        let login_server_login_data = LoginServerLoginData {
+            account_id: AccountId(1),
+            login_id1: 0,
+            login_id2: 0,
+            sex: Sex::Male,
+        };
+        let character_server_login_data = CharacterServerLoginData {
+            server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server_port: listener.local_addr().unwrap().port(),
+            character_id: CharacterId(1),
+        };
+
+        networking_system.connect_to_map_server(&login_server_login_data, character_server_login_data);
+        networking_system
+    }
+
+    fn disconnect_reason(networking_system: &mut NetworkingSystem<NoPacketCallback>) -> DisconnectReason {
+        let events = poll_until(networking_system, |event| {
+            matches!(event, NetworkEvent::MapServerDisconnected { .. })
+        });
+
+        match events.last() {
+            Some(NetworkEvent::MapServerDisconnected { reason }) => *reason,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn connect_timeout() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .unwrap();
+
+        // A connection attempt that never completes. With the clock paused, the
+        // runtime skips ahead to the timeout as soon as there is nothing else to do.
+        let connect = std::future::pending::<std::io::Result<()>>();
+        let result = runtime.block_on(connect_with_timeout(TIMEOUTS.connect, connect));
+
+        assert_eq!(result, Err(NetworkTaskError::ConnectTimeout));
+    }
+
+    #[test]
+    fn handshake_timeout() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut networking_system = connect(&listener);
+
+        // Accept the connection but never answer.
+        let (_stream, _) = listener.accept().unwrap();
+
+        assert_eq!(
+            disconnect_reason(&mut networking_system),
+            DisconnectReason::ConnectionError(NetworkTaskError::HandshakeTimeout)
+        );
+    }
+
+    #[test]
+    fn idle_timeout() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut networking_system = connect(&listener);
+
+        // Answer the login and go silent afterwards.
+        let (mut stream, _) = listener.accept().unwrap();
+        let login_success = MapServerLoginSuccessPacket::new(ClientTick(100), WorldPosition::new(10, 10), 0);
+        stream.write_all(&login_success.packet_to_bytes().unwrap()).unwrap();
+
+        assert_eq!(
+            disconnect_reason(&mut networking_system),
+            DisconnectReason::ConnectionError(NetworkTaskError::IdleTimeout)
+        );
+    }
+}
+
+#[cfg(test)]
+mod flows {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::*;
+
+    use crate::mock_server::{poll_until, MockServer};
+    use crate::{
+        Capture, CharacterServerLoginData, InventoryItemDetails, ItemQuantity, LoginServerLoginData, MessageColor, NetworkEvent,
+        NetworkingSystem, PacketDirection, PacketRecorder, ShopItem,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId(2000001);
+    const CHARACTER_ID: CharacterId = CharacterId(150000);
+
+    fn enter_map(map_server: &mut MockServer) -> NetworkingSystem<NoPacketCallback> {
+        let mut networking_system = NetworkingSystem::spawn();
+
+        let login_server_login_data = LoginServerLoginData {
+            account_id: ACCOUNT_ID,
+            login_id1: 11,
+            login_id2: 22,
+            sex: Sex::Male,
+        };
+        let character_server_login_data = CharacterServerLoginData {
+            server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server_port: map_server.address().port(),
+            character_id: CHARACTER_ID,
+        };
+
+        networking_system.connect_to_map_server(&login_server_login_data, character_server_login_data);
+        map_server.accept();
+        map_server.receive::<MapServerLoginPacket>();
+        map_server.send(&MapServerLoginSuccessPacket::new(
+            ClientTick(100),
+            WorldPosition::new(150, 180),
+            0,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetPlayerPosition(..))
+        });
+
+        networking_system
+    }
+
+    #[test]
+    fn login_to_map_server() {
+        let mut login_server = MockServer::bind();
+        let mut character_server = MockServer::bind();
+        let mut map_server = MockServer::bind();
+        let mut networking_system = NetworkingSystem::spawn();
+
+        // Login server.
+        networking_system.connect_to_login_server(login_server.address(), "username", "password");
+        login_server.accept();
+
+        let login_packet = login_server.receive::<LoginServerLoginPacket>();
+        assert_eq!(login_packet.name, "username");
+        assert_eq!(login_packet.password, "password");
+
+        let (server_ip, server_port) = character_server.server_address();
+        let character_server_information = CharacterServerInformation::new(server_ip, server_port, "Mock".to_owned(), 0, 0, 0);
+        login_server.send(&LoginServerLoginSuccessPacket::new(
+            11,
+            ACCOUNT_ID,
+            22,
+            Sex::Male,
+            AuthToken([0; 17]),
+            vec![character_server_information],
+        ));
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::LoginServerConnected { .. })
+        });
+        let Some(NetworkEvent::LoginServerConnected {
+            character_servers,
+            login_data,
+        }) = events.into_iter().last()
+        else {
+            panic!("expected login server connected event");
+        };
+        assert_eq!(login_data.account_id, ACCOUNT_ID);
+
+        // Character server.
+        networking_system.disconnect_from_login_server();
+        networking_system.connect_to_character_server(&login_data, character_servers[0].clone());
+        character_server.accept();
+
+        let login_packet = character_server.receive::<CharacterServerLoginPacket>();
+        assert_eq!(login_packet.account_id, ACCOUNT_ID);
+        assert_eq!((login_packet.login_id1, login_packet.login_id2), (11, 22));
+
+        character_server.send_raw(&ACCOUNT_ID.0.to_le_bytes());
+        character_server.send(&CharacterServerLoginSuccessPacket::new(29, 9, 0, 0, 0, 9));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterServerConnected { normal_slot_count: 9 })
+        });
+
+        networking_system.request_character_list().unwrap();
+        character_server.receive::<RequestCharacterListPacket>();
+        character_server.send(&RequestCharacterListSuccessPacket::new(Vec::new()));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterList { .. })
+        });
+
+        networking_system.select_character(0).unwrap();
+        assert_eq!(character_server.receive::<SelectCharacterPacket>().selected_slot, 0);
+
+        let (map_server_ip, map_server_port) = map_server.server_address();
+        character_server.send(&CharacterSelectionSuccessPacket::new(
+            CHARACTER_ID,
+            "prontera.gat".to_owned(),
+            map_server_ip,
+            map_server_port,
+        ));
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterSelected { .. })
+        });
+        let Some(NetworkEvent::CharacterSelected {
+            login_data: character_login_data,
+            map_name,
+        }) = events.into_iter().last()
+        else {
+            panic!("expected character selected event");
+        };
+        assert_eq!(map_name, "prontera");
+        assert_eq!(character_login_data.character_id, CHARACTER_ID);
+
+        // Map server.
+        networking_system.disconnect_from_character_server();
+        networking_system.connect_to_map_server(&login_data, character_login_data);
+        networking_system.map_loaded().unwrap();
+        map_server.accept();
+
+        let login_packet = map_server.receive::<MapServerLoginPacket>();
+        assert_eq!(login_packet.account_id, ACCOUNT_ID);
+        assert_eq!(login_packet.character_id, CHARACTER_ID);
+        assert_eq!(login_packet.login_id1, 11);
+        map_server.receive::<MapLoadedPacket>();
+
+        map_server.send(&MapServerLoginSuccessPacket::new(
+            ClientTick(100),
+            WorldPosition::new(150, 180),
+            0,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetPlayerPosition(..))
+        });
+        assert!(matches!(events.as_slice(), [
+            ..,
+            NetworkEvent::UpdateClientTick(ClientTick(100)),
+            NetworkEvent::SetPlayerPosition(WorldPosition { x: 150, y: 180 })
+        ]));
+    }
+
+    fn enter_character_server(character_server: &mut MockServer) -> NetworkingSystem<NoPacketCallback> {
+        let mut networking_system = NetworkingSystem::spawn();
+
+        let login_data = LoginServerLoginData {
+            account_id: ACCOUNT_ID,
+            login_id1: 11,
+            login_id2: 22,
+            sex: Sex::Male,
+        };
+        let (server_ip, server_port) = character_server.server_address();
+        let character_server_information = CharacterServerInformation::new(server_ip, server_port, "Mock".to_owned(), 0, 0, 0);
+
+        networking_system.connect_to_character_server(&login_data, character_server_information);
+        character_server.accept();
+        character_server.receive::<CharacterServerLoginPacket>();
+        character_server.send_raw(&ACCOUNT_ID.0.to_le_bytes());
+        character_server.send(&CharacterServerLoginSuccessPacket::new(29, 9, 0, 0, 0, 9));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterServerConnected { .. })
+        });
+
+        networking_system
+    }
+
+    #[test]
+    fn create_character() {
+        let mut character_server = MockServer::bind();
+        let mut networking_system = enter_character_server(&mut character_server);
+
+        networking_system
+            .create_character(2, "Poring".to_owned(), 3, 5, 4218, Sex::Female)
+            .unwrap();
+
+        let create_packet = character_server.receive::<CreateCharacterPacket>();
+        assert_eq!(create_packet.name, "Poring");
+        assert_eq!(create_packet.slot, 2);
+        assert_eq!(create_packet.hair_color, 3);
+        assert_eq!(create_packet.hair_style, 5);
+        assert_eq!(create_packet.start_job, 4218);
+        assert_eq!(create_packet.sex, Sex::Female);
+    }
+
+    #[test]
+    fn delayed_character_deletion() {
+        let mut character_server = MockServer::bind();
+        let mut networking_system = enter_character_server(&mut character_server);
+
+        networking_system.reserve_character_deletion(CHARACTER_ID).unwrap();
+        assert_eq!(
+            character_server.receive::<ReserveCharacterDeletionPacket>().character_id,
+            CHARACTER_ID
+        );
+
+        character_server.send(&CharacterDeletionReservedPacket::new(
+            CHARACTER_ID,
+            CharacterDeletionReservationStatus::Success,
+            86400,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterDeletionReserved { .. })
+        });
+        assert!(events.iter().any(|event| matches!(
+            event,
+            NetworkEvent::CharacterDeletionReserved { character_id, deletion_time }
+                if *character_id == CHARACTER_ID && deletion_time.as_secs() == 86400
+        )));
+
+        networking_system.accept_character_deletion(CHARACTER_ID, *b"991231").unwrap();
+        assert_eq!(
+            character_server.receive::<AcceptCharacterDeletionPacket>().birthdate,
+            *b"991231"
+        );
+
+        character_server.send(&CharacterDeletionAcceptedPacket::new(
+            CHARACTER_ID,
+            CharacterDeletionAcceptStatus::BirthdateMismatch,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::CharacterDeletionAcceptFailed {
+                reason: CharacterDeletionAcceptStatus::BirthdateMismatch,
+                ..
+            })
+        });
+
+        networking_system.cancel_character_deletion(CHARACTER_ID).unwrap();
+        character_server.receive::<CancelCharacterDeletionPacket>();
+
+        character_server.send(&CharacterDeletionCanceledPacket::new(
+            CHARACTER_ID,
+            CharacterDeletionCancelStatus::Success,
+        ));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::CharacterDeletionCanceled { character_id } if *character_id == CHARACTER_ID),
+        );
+    }
+
+    #[test]
+    fn buy_from_shop() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&BuyOrSellPacket::new(ShopId(5)));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AskBuyOrSell { shop_id: ShopId(5) })
+        });
+
+        networking_system.select_buy_or_sell(ShopId(5), BuyOrSellOption::Buy).unwrap();
+        let select_packet = map_server.receive::<SelectBuyOrSellPacket>();
+        assert_eq!(select_packet.shop_id, ShopId(5));
+        assert!(matches!(select_packet.option, BuyOrSellOption::Buy));
+
+        map_server.send(&ShopItemListPacket::new(vec![ShopItemInformation::new(
+            ItemId(501),
+            Price(50),
+            Price(45),
+            0,
+            0,
+            0,
+        )]));
+        let events = poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::OpenShop { .. }));
+        let Some(NetworkEvent::OpenShop { items }) = events.into_iter().last() else {
+            panic!("expected open shop event");
+        };
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].price, Price(50));
+
+        networking_system
+            .purchase_items(vec![ShopItem {
+                metadata: 3,
+                item_id: ItemId(501),
+                item_type: 0,
+                price: Price(45),
+                quantity: ItemQuantity::Infinite,
+                weight: 0,
+                location: 0,
+            }])
+            .unwrap();
+        let buy_packet = map_server.receive::<BuyShopItemsPacket>();
+        assert_eq!(buy_packet.items.len(), 1);
+        assert_eq!(buy_packet.items[0].item_id, ItemId(501));
+        assert_eq!(buy_packet.items[0].amount, 3);
+
+        map_server.send(&BuyShopItemsResultPacket::new(BuyShopItemsResult::Success, Vec::new()));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::BuyingCompleted {
+                result: BuyShopItemsResult::Success
+            })
+        });
+    }
+
+    #[test]
+    fn ground_items() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&GroundItemFallPacket::new(
+            GroundItemId(12),
+            ItemId(501),
+            0,
+            1,
+            TilePosition { x: 151, y: 180 },
+            3,
+            9,
+            2,
+            0,
+            0,
+        ));
+        let events = poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::AddGroundItem(..)));
+        let Some(NetworkEvent::AddGroundItem(ground_item)) = events.into_iter().last() else {
+            panic!("expected add ground item event");
+        };
+        assert_eq!(ground_item.ground_item_id, GroundItemId(12));
+        assert_eq!(ground_item.position, TilePosition { x: 151, y: 180 });
+        assert_eq!((ground_item.sub_x, ground_item.sub_y), (3, 9));
+        assert_eq!(ground_item.amount, 2);
+        assert!(ground_item.is_identified);
+
+        networking_system.pick_up_item(GroundItemId(12)).unwrap();
+        assert_eq!(map_server.receive::<PickUpItemPacket>().ground_item_id, GroundItemId(12));
+
+        map_server.send(&ItemPickupPacket {
+            index: InventoryIndex(0),
+            count: 0,
+            item_id: ItemId(0),
+            is_identified: 0,
+            is_broken: 0,
+            cards: [0; 4],
+            equip_position: EquipPosition::empty(),
+            item_type: 0,
+            result: ItemPickupResult::Overweight,
+            hire_expiration_date: 0,
+            bind_on_equip_type: 0,
+            option_data: std::array::from_fn(|_| ItemOptions {
+                index: 0,
+                value: 0,
+                parameter: 0,
+            }),
+            favorite: 0,
+            look: 0,
+            refinement_level: 0,
+            enchantment_level: 0,
+        });
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::ItemPickupFailed {
+                reason: ItemPickupResult::Overweight,
+                ..
+            })
+        });
+
+        map_server.send(&GroundItemDisappearPacket::new(GroundItemId(12)));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::RemoveGroundItem(GroundItemId(12)))
+        });
+    }
+
+    #[test]
+    fn use_and_drop_items() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        networking_system.use_item(InventoryIndex(4), ACCOUNT_ID).unwrap();
+        let use_packet = map_server.receive::<UseItemPacket>();
+        assert_eq!(use_packet.index, InventoryIndex(4));
+        assert_eq!(use_packet.account_id, ACCOUNT_ID);
+
+        map_server.send(&UseItemSuccessPacket::new(
+            InventoryIndex(4),
+            ItemId(501),
+            EntityId(ACCOUNT_ID.0),
+            9,
+            1,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::ItemUsed {
+                index: InventoryIndex(4),
+                remaining_amount: 9,
+                ..
+            })
+        });
+
+        networking_system.drop_item(InventoryIndex(4), 3).unwrap();
+        let drop_packet = map_server.receive::<DropItemPacket>();
+        assert_eq!(drop_packet.index, InventoryIndex(4));
+        assert_eq!(drop_packet.amount, 3);
+
+        map_server.send(&DropItemSuccessPacket::new(InventoryIndex(4), 3));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::InventoryItemRemoved {
+                index: InventoryIndex(4),
+                amount: 3,
+                ..
+            })
+        });
+    }
+
+    #[test]
+    fn emotions() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        networking_system.send_emotion(EmotionId(4)).unwrap();
+        assert_eq!(map_server.receive::<RequestEmotionPacket>().emotion, EmotionId(4));
+
+        map_server.send(&DisplayEmotionPacket::new(EntityId(ACCOUNT_ID.0), EmotionId(4)));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::EntityEmotion {
+                entity_id,
+                emotion: EmotionId(4),
+            } if *entity_id == EntityId(ACCOUNT_ID.0))
+        });
+    }
+
+    #[test]
+    fn party_member_list() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&PartyMemberListPacket::new("Party".to_owned(), vec![
+            PartyMemberInformation {
+                account_id: ACCOUNT_ID,
+                character_id: CHARACTER_ID,
+                name: "Leader".to_owned(),
+                map_name: "prontera.gat".to_owned(),
+                role: 0,
+                is_offline: 0,
+                job: 4001,
+                base_level: 99,
+            },
+            PartyMemberInformation {
+                account_id: AccountId(2000002),
+                character_id: CharacterId(150001),
+                name: "Member".to_owned(),
+                map_name: "geffen.gat".to_owned(),
+                role: 1,
+                is_offline: 1,
+                job: 0,
+                base_level: 10,
+            },
+        ]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetPartyMembers { .. })
+        });
+        let Some(NetworkEvent::SetPartyMembers { party_name, members }) = events.into_iter().last() else {
+            panic!("expected set party members event");
+        };
+
+        assert_eq!(party_name, "Party");
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].character_id, Some(CHARACTER_ID));
+        assert_eq!(members[0].map_name, "prontera");
+        assert!(members[0].is_leader && members[0].is_online);
+        assert_eq!(members[0].base_level, 99);
+        assert_eq!(members[1].account_id, AccountId(2000002));
+        assert_eq!(members[1].name, "Member");
+        assert!(!members[1].is_leader && !members[1].is_online);
+
+        // Older servers send the members without their character id.
+        map_server.send(&LegacyPartyMemberListPacket::new("Party".to_owned(), vec![
+            LegacyPartyMemberInformation {
+                account_id: ACCOUNT_ID,
+                name: "Leader".to_owned(),
+                map_name: "prontera.gat".to_owned(),
+                role: 0,
+                is_offline: 0,
+                job: 4001,
+                base_level: 99,
+            },
+        ]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetPartyMembers { .. })
+        });
+        let Some(NetworkEvent::SetPartyMembers { members, .. }) = events.into_iter().last() else {
+            panic!("expected set party members event");
+        };
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].account_id, ACCOUNT_ID);
+        assert_eq!(members[0].character_id, None);
+        assert_eq!(members[0].job, 4001);
+    }
+
+    #[test]
+    fn party() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        networking_system.create_party("Party".to_owned()).unwrap();
+        let create_packet = map_server.receive::<CreatePartyPacket>();
+        assert_eq!(create_packet.party_name, "Party");
+        assert_eq!(create_packet.item_pickup_rule, PartyItemRule::Individual);
+
+        map_server.send(&CreatePartyResultPacket::new(CreatePartyResult::NameAlreadyExists));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, color: MessageColor::Error } if text == "A party with this name already exists."),
+        );
+
+        networking_system.invite_to_party("Friend".to_owned()).unwrap();
+        assert_eq!(map_server.receive::<InviteToPartyPacket>().character_name, "Friend");
+
+        map_server.send(&PartyInvitePacket::new(PartyId(7), "Other".to_owned()));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::PartyInvitation { party_id: PartyId(7), party_name } if party_name == "Other"),
+        );
+
+        networking_system.accept_party_invitation(PartyId(7)).unwrap();
+        let response_packet = map_server.receive::<PartyInviteResponsePacket>();
+        assert_eq!(response_packet.party_id, PartyId(7));
+        assert!(matches!(response_packet.response, PartyInviteResponse::Accept));
+
+        map_server.send(&PartyMemberHealthPointsPacket::new(AccountId(2000002), 80, 100));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::PartyMemberHealth {
+                account_id: AccountId(2000002),
+                health_points: 80,
+                maximum_health_points: 100,
+            })
+        });
+
+        map_server.send(&PartyMemberLeftPacket::new(
+            AccountId(2000002),
+            "Member".to_owned(),
+            PartyMemberLeftReason::Expelled,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::PartyMemberLeft {
+                account_id: AccountId(2000002)
+            })
+        });
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, NetworkEvent::ChatMessage { text, .. } if text == "Member was expelled from the party."))
+        );
+
+        networking_system.send_party_message("Player", "hello").unwrap();
+        assert_eq!(map_server.receive::<PartyMessagePacket>().message, "Player : hello");
+
+        networking_system.leave_party().unwrap();
+        map_server.receive::<LeavePartyPacket>();
+    }
+
+    #[test]
+    fn quests() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&QuestListPacket::new(vec![Quest {
+            quest_id: 1001,
+            active: 1,
+            start_time: 1700000000,
+            expire_time: 0,
+            objective_count: 1,
+            objective_details: vec![QuestDetails {
+                hunt_identification: 7,
+                objective_type: 0,
+                mob_id: 1002,
+                minimum_level: 0,
+                maximum_level: 0,
+                kill_count: 3,
+                total_count: 10,
+                mob_name: "Poring".to_owned(),
+            }],
+        }]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetQuestList { .. })
+        });
+        let Some(NetworkEvent::SetQuestList { quests }) = events.into_iter().last() else {
+            panic!("expected set quest list event");
+        };
+
+        assert_eq!(quests.len(), 1);
+        assert_eq!(quests[0].quest_id, 1001);
+        assert!(quests[0].active);
+        assert_eq!(quests[0].start_time, 1700000000);
+        assert_eq!(quests[0].objectives.len(), 1);
+        assert_eq!(quests[0].objectives[0].mob_name, "Poring");
+        assert_eq!(quests[0].objectives[0].current_count, 3);
+        assert_eq!(quests[0].objectives[0].total_count, 10);
+
+        let objective = ObjectiveDetails1 {
+            hunt_identification: 8,
+            objective_type: 0,
+            mob_id: 1113,
+            minimum_level: 0,
+            maximum_level: 0,
+            mob_count: 5,
+            mob_name: "Drops".to_owned(),
+        };
+        map_server.send(&QuestNotificationPacket1::new(1002, 1, 1700000100, 0, 1, [
+            objective.clone(),
+            objective.clone(),
+            objective,
+        ]));
+        let events = poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::QuestAdded { .. }));
+        let Some(NetworkEvent::QuestAdded { quest }) = events.into_iter().last() else {
+            panic!("expected quest added event");
+        };
+
+        // Only the used objectives are taken from the fixed size array.
+        assert_eq!(quest.quest_id, 1002);
+        assert_eq!(quest.objectives.len(), 1);
+        assert_eq!(quest.objectives[0].current_count, 0);
+        assert_eq!(quest.objectives[0].total_count, 5);
+
+        map_server.send(&HuntingQuestUpdateObjectivePacket::new(1, vec![HuntingObjective {
+            hunt_identification: 8,
+            mob_id: 1113,
+            total_count: 5,
+            current_count: 2,
+        }]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::UpdateQuestObjectives { .. })
+        });
+        let Some(NetworkEvent::UpdateQuestObjectives { objectives }) = events.into_iter().last() else {
+            panic!("expected update quest objectives event");
+        };
+        assert_eq!(objectives.len(), 1);
+        assert_eq!(objectives[0].hunt_identification, 8);
+        assert_eq!(objectives[0].current_count, 2);
+
+        map_server.send(&QuestRemovedPacket::new(1001));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::QuestRemoved { quest_id: 1001 })
+        });
+    }
+
+    #[test]
+    fn status_effects() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+        let entity_id = EntityId(ACCOUNT_ID.0);
+
+        map_server.send(&StatusChangePacket::new(12, entity_id, 1, 60000, 45000, [5, 0, 0]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AddStatusEffect { .. })
+        });
+        let Some(NetworkEvent::AddStatusEffect {
+            entity_id: effect_entity_id,
+            status_effect,
+        }) = events.into_iter().last()
+        else {
+            panic!("expected add status effect event");
+        };
+
+        assert_eq!(effect_entity_id, entity_id);
+        assert_eq!(status_effect.status_id, 12);
+        assert_eq!(status_effect.total_duration, Some(60000));
+        assert_eq!(status_effect.remaining_duration, Some(45000));
+        assert_eq!(status_effect.values, [5, 0, 0]);
+
+        map_server.send(&StatusChangeSequencePacket::new(30, entity_id, 1));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AddStatusEffect { .. })
+        });
+        let Some(NetworkEvent::AddStatusEffect { status_effect, .. }) = events.into_iter().last() else {
+            panic!("expected add status effect event");
+        };
+        assert_eq!(status_effect.status_id, 30);
+        assert_eq!(status_effect.total_duration, None);
+
+        map_server.send(&StatusChangePacket::new(12, entity_id, 0, 0, 0, [0; 3]));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::RemoveStatusEffect { status_id: 12, .. })
+        });
+
+        map_server.send(&StatusChangeSequencePacket::new(30, entity_id, 0));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::RemoveStatusEffect { status_id: 30, .. })
+        });
+    }
+
+    #[test]
+    fn experience() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&DisplayGainedExperiencePacket::new(
+            ACCOUNT_ID,
+            250,
+            ExperienceType::JobExperience,
+            ExperienceSource::Quest,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::GainedExperience {
+                account_id,
+                amount: 250,
+                experience_type: ExperienceType::JobExperience,
+                experience_source: ExperienceSource::Quest,
+            } if *account_id == ACCOUNT_ID)
+        });
+
+        // Status types can't be encoded, so the packets are built by hand.
+        let update_status = |status_type: u16, value: u64| {
+            let mut bytes = UpdateStatusPacket2::HEADER.0.to_le_bytes().to_vec();
+            bytes.extend(status_type.to_le_bytes());
+            bytes.extend(value.to_le_bytes());
+            bytes
+        };
+        map_server.send_raw(&update_status(1, 1234));
+        map_server.send_raw(&update_status(22, 5000));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::UpdateStatus(StatusType::NextBaseExperience(5000)))
+        })
+        .iter()
+        .find(|event| matches!(event, NetworkEvent::UpdateStatus(StatusType::BaseExperience(1234))))
+        .expect("expected base experience update");
+    }
+
+    #[test]
+    fn status_points() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&InitialStatusPacket::new(
+            48, 9, 2, 8, 2, 7, 2, 6, 2, 5, 2, 4, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::UpdateStatus(StatusType::AttackSpeed(..)))
+        });
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, NetworkEvent::UpdateStatus(StatusType::StatusPoint(48))))
+        );
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, NetworkEvent::UpdateStatus(StatusType::Dexterity(5, 0))))
+        );
+
+        networking_system.request_status_increase(StatusIncreaseType::Dexterity).unwrap();
+        let increase_packet = map_server.receive::<RequestStatusIncreasePacket>();
+        assert_eq!(increase_packet.status_type, StatusIncreaseType::Dexterity);
+        assert_eq!(increase_packet.amount, 1);
+
+        map_server.send(&StatusIncreaseResultPacket::new(StatusIncreaseType::Dexterity, 0, 5));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, color: MessageColor::Error } if text == "Not enough status points"),
+        );
+    }
+
+    #[test]
+    fn whispers() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        networking_system.send_whisper("Friend".to_owned(), "hello".to_owned()).unwrap();
+        networking_system.send_whisper("Other".to_owned(), "hi".to_owned()).unwrap();
+
+        let whisper_packet = map_server.receive::<SendWhisperPacket>();
+        assert_eq!(whisper_packet.name, "Friend");
+        assert_eq!(whisper_packet.message, "hello");
+
+        let whisper_packet = map_server.receive::<SendWhisperPacket>();
+        assert_eq!(whisper_packet.name, "Other");
+        assert_eq!(whisper_packet.message, "hi");
+
+        map_server.send(&ReceiveWhisperPacket::new(
+            CharacterId(150001),
+            "Friend".to_owned(),
+            0,
+            "hey there".to_owned(),
+        ));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::WhisperReceived { sender_name, message } if sender_name == "Friend" && message == "hey there"),
+        );
+
+        // Successfully delivered whispers don't trigger an event.
+        map_server.send(&WhisperResultPacket::new(WhisperResult::Success, CharacterId(150001)));
+        map_server.send(&WhisperResultPacket::new(WhisperResult::TargetNotOnline, CharacterId(150002)));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::WhisperFailed { .. })
+        });
+        let failures: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                NetworkEvent::WhisperFailed { reason } => Some(*reason),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(failures, [WhisperResult::TargetNotOnline]);
+    }
+
+    #[test]
+    fn guild() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&UpdateGuildPacket::new(GuildId(3), 12, 0, 1, 0, "Guild".to_owned()));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::UpdateGuild {
+                guild_id: GuildId(3),
+                guild_name,
+                emblem_id: 12,
+                is_master: true,
+            } if guild_name == "Guild")
+        });
+
+        networking_system.request_guild_menu(GuildMenuType::Information).unwrap();
+        assert_eq!(
+            map_server.receive::<RequestGuildMenuPacket>().menu_type,
+            GuildMenuType::Information
+        );
+
+        map_server.send(&GuildInformationPacket::new(
+            GuildId(3),
+            5,
+            2,
+            16,
+            60,
+            1000,
+            2000,
+            0,
+            0,
+            0,
+            12,
+            "Guild".to_owned(),
+            "Master".to_owned(),
+            String::new(),
+            0,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetGuildInformation { .. })
+        });
+        let Some(NetworkEvent::SetGuildInformation { information }) = events.into_iter().last() else {
+            panic!("expected set guild information event");
+        };
+        assert_eq!(information.guild_id, GuildId(3));
+        assert_eq!(information.master_name, "Master");
+        assert_eq!(information.maximum_member_count, 16);
+        assert_eq!(information.next_experience, 2000);
+
+        networking_system.request_guild_menu(GuildMenuType::MemberList).unwrap();
+        assert_eq!(
+            map_server.receive::<RequestGuildMenuPacket>().menu_type,
+            GuildMenuType::MemberList
+        );
+
+        let member = |account_id: u32, name: &str, is_online: u32| GuildMemberInformation {
+            account_id: AccountId(account_id),
+            character_id: CharacterId(account_id + 1000),
+            head: 0,
+            head_palette: 0,
+            sex: 0,
+            job: 7,
+            base_level: 80,
+            contributed_experience: 500,
+            is_online,
+            position_id: 0,
+            memo: String::new(),
+            name: name.to_owned(),
+        };
+        map_server.send(&GuildMemberListPacket::new(vec![
+            member(ACCOUNT_ID.0, "Master", 1),
+            member(2000002, "Member", 0),
+        ]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetGuildMembers { .. })
+        });
+        let Some(NetworkEvent::SetGuildMembers { members }) = events.into_iter().last() else {
+            panic!("expected set guild members event");
+        };
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].name, "Master");
+        assert!(members[0].is_online);
+        assert_eq!(members[1].character_id, CharacterId(2001002));
+        assert!(!members[1].is_online);
+
+        map_server.send(&GuildInvitationPacket::new(GuildId(4), "Other".to_owned()));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::GuildInvitation { guild_id: GuildId(4), guild_name } if guild_name == "Other"),
+        );
+
+        networking_system.reject_guild_invitation(GuildId(4)).unwrap();
+        let response_packet = map_server.receive::<GuildInviteResponsePacket>();
+        assert_eq!(response_packet.guild_id, GuildId(4));
+        assert!(matches!(response_packet.response, GuildInviteResponse::Reject));
+
+        networking_system.send_guild_message("Player", "hello").unwrap();
+        assert_eq!(map_server.receive::<GuildMessagePacket>().message, "Player : hello");
+
+        networking_system
+            .leave_guild(GuildId(3), ACCOUNT_ID, CHARACTER_ID, "Bye".to_owned())
+            .unwrap();
+        let leave_packet = map_server.receive::<LeaveGuildPacket>();
+        assert_eq!(leave_packet.guild_id, GuildId(3));
+        assert_eq!(leave_packet.character_id, CHARACTER_ID);
+        assert_eq!(leave_packet.reason, "Bye");
+    }
+
+    #[test]
+    fn trade() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        networking_system.request_trade(EntityId(2000002)).unwrap();
+        assert_eq!(map_server.receive::<RequestTradePacket>().entity_id, EntityId(2000002));
+
+        map_server.send(&TradeRequestPacket::new("Partner".to_owned(), CharacterId(150001), 42));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradeRequested {
+                name,
+                character_id: CharacterId(150001),
+                base_level: 42,
+            } if name == "Partner")
+        });
+
+        networking_system.accept_trade_request().unwrap();
+        assert!(matches!(
+            map_server.receive::<TradeRequestResponsePacket>().response,
+            TradeRequestResponse::Accept
+        ));
+
+        map_server.send(&TradeRequestResultPacket::new(
+            TradeRequestResult::Accepted,
+            CharacterId(150001),
+            42,
+        ));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::TradeStarted));
+
+        networking_system.add_trade_item(InventoryIndex(4), 2).unwrap();
+        let packet = map_server.receive::<AddTradeItemPacket>();
+        assert_eq!(packet.index, 6);
+        assert_eq!(packet.amount, 2);
+
+        map_server.send(&AddTradeItemResultPacket::new(6, AddTradeItemResult::Success));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradeOfferAccepted {
+                index: Some(InventoryIndex(4))
+            })
+        });
+
+        networking_system.add_trade_zeny(500).unwrap();
+        let packet = map_server.receive::<AddTradeItemPacket>();
+        assert_eq!(packet.index, 0);
+        assert_eq!(packet.amount, 500);
+
+        map_server.send(&AddTradeItemResultPacket::new(0, AddTradeItemResult::Success));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradeOfferAccepted { index: None })
+        });
+
+        let offered_item = |item_id: u32, amount: u32| {
+            TradeItemAddedPacket::new(
+                ItemId(item_id),
+                0,
+                amount,
+                1,
+                0,
+                [0; 4],
+                std::array::from_fn(|_| ItemOptions {
+                    index: 0,
+                    value: 0,
+                    parameter: 0,
+                }),
+                EquipPosition::empty(),
+                0,
+                0,
+                0,
+            )
+        };
+        map_server.send(&offered_item(501, 3));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradePartnerItemAdded { .. })
+        });
+        let Some(NetworkEvent::TradePartnerItemAdded { item }) = events.into_iter().last() else {
+            panic!("expected trade partner item added event");
+        };
+        assert_eq!(item.item_id, ItemId(501));
+        assert!(matches!(item.details, InventoryItemDetails::Regular { amount: 3, .. }));
+
+        map_server.send(&offered_item(0, 1000));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradePartnerZenyAdded { amount: 1000 })
+        });
+
+        networking_system.lock_trade().unwrap();
+        map_server.receive::<LockTradePacket>();
+
+        map_server.send(&TradeLockedPacket::new(0));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradeLocked { is_partner: false })
+        });
+        map_server.send(&TradeLockedPacket::new(1));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::TradeLocked { is_partner: true })
+        });
+
+        networking_system.commit_trade().unwrap();
+        map_server.receive::<CommitTradePacket>();
+
+        map_server.send(&TradeCompletedPacket::new(TradeResult::Success));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::TradeCompleted));
+
+        networking_system.request_trade(EntityId(2000002)).unwrap();
+        map_server.receive::<RequestTradePacket>();
+        networking_system.cancel_trade().unwrap();
+        map_server.receive::<CancelTradePacket>();
+
+        map_server.send(&TradeCancelledPacket::new());
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::TradeCancelled));
+    }
+
+    #[test]
+    fn storage() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        // Storage indices are only offset by 1, so the first storage slot is sent as
+        // inventory index `u16::MAX`.
+        map_server.send(&InventoyStartPacket::new(InventoryType::Storage, "Storage".to_owned()));
+        map_server.send(&RegularItemListPacket::new(2, vec![RegularItemInformation {
+            index: InventoryIndex(u16::MAX),
+            item_id: ItemId(501),
+            item_type: 0,
+            amount: 5,
+            equipped_position: EquipPosition::empty(),
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            flags: RegularItemFlags::IDENTIFIED,
+        }]));
+        map_server.send(&InventoyEndPacket::new(InventoryType::Storage, 0));
+        let events = poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::SetStorage { .. }));
+        let Some(NetworkEvent::SetStorage { items }) = events.into_iter().last() else {
+            panic!("expected set storage event");
+        };
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].index, InventoryIndex(0));
+        assert_eq!(items[0].item_id, ItemId(501));
+
+        networking_system.move_item_to_storage(InventoryIndex(3), 2).unwrap();
+        let packet = map_server.receive::<MoveItemToStoragePacket>();
+        assert_eq!(packet.index, InventoryIndex(3));
+        assert_eq!(packet.amount, 2);
+
+        map_server.send(&StorageItemAddedPacket::new(
+            StorageIndex(1),
+            2,
+            ItemId(909),
+            3,
+            1,
+            0,
+            0,
+            [0; 4],
+            std::array::from_fn(|_| ItemOptions {
+                index: 0,
+                value: 0,
+                parameter: 0,
+            }),
+            0,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::StorageItemAdded { .. })
+        });
+        let Some(NetworkEvent::StorageItemAdded { item }) = events.into_iter().last() else {
+            panic!("expected storage item added event");
+        };
+        assert_eq!(item.index, InventoryIndex(1));
+        assert_eq!(item.item_id, ItemId(909));
+        assert!(matches!(item.details, InventoryItemDetails::Regular { amount: 2, .. }));
+
+        networking_system.move_item_from_storage(StorageIndex(0), 5).unwrap();
+        let packet = map_server.receive::<MoveItemFromStoragePacket>();
+        assert_eq!(packet.index, StorageIndex(0));
+        assert_eq!(packet.amount, 5);
+
+        map_server.send(&StorageItemRemovedPacket::new(StorageIndex(0), 5));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::StorageItemRemoved {
+                index: StorageIndex(0),
+                amount: 5,
+            })
+        });
+
+        networking_system.close_storage().unwrap();
+        map_server.receive::<CloseStoragePacket>();
+
+        map_server.send(&StorageClosedPacket::new());
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::StorageClosed));
+    }
+
+    #[test]
+    fn vending() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        map_server.send(&OpenVendingSetupPacket::new(3));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::OpenVendingSetup { maximum_items: 3 })
+        });
+
+        networking_system
+            .open_vending_shop("Shop".to_owned(), vec![VendingShopItemInformation {
+                index: InventoryIndex(1),
+                amount: 2,
+                price: Price(100),
+            }])
+            .unwrap();
+        let packet = map_server.receive::<OpenVendingShopPacket>();
+        assert_eq!(packet.title, "Shop");
+        assert_eq!(packet.open, 1);
+        assert_eq!(packet.items.len(), 1);
+        assert_eq!(packet.items[0].index, InventoryIndex(1));
+        assert_eq!(packet.items[0].price, Price(100));
+
+        map_server.send(&OpenVendingShopResultPacket::new(OpenVendingShopResult::Success));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::VendingShopOpened));
+
+        map_server.send(&CartItemRemovedPacket::new(InventoryIndex(1), 1));
+        map_server.send(&VendingItemSoldPacket::new(0, 1, CharacterId(150001), 0, 100));
+        let events = poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, .. } if text == "Sold 1 item(s) for 100 zeny."),
+        );
+        assert!(events.iter().any(|event| matches!(event, NetworkEvent::CartItemRemoved {
+            index: InventoryIndex(1),
+            amount: 1,
+        })));
+
+        networking_system.close_vending_shop().unwrap();
+        map_server.receive::<CloseVendingShopPacket>();
+
+        networking_system.cancel_vending_setup().unwrap();
+        let packet = map_server.receive::<OpenVendingShopPacket>();
+        assert_eq!(packet.open, 0);
+        assert!(packet.items.is_empty());
+
+        let merchant_id = EntityId(2000002);
+
+        map_server.send(&VendingShopBoardPacket::new(merchant_id, "Cheap potions".to_owned()));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::VendingShopBoard { entity_id, title } if *entity_id == merchant_id && title == "Cheap potions"),
+        );
+
+        networking_system.request_vending_items(merchant_id).unwrap();
+        assert_eq!(map_server.receive::<RequestVendingItemListPacket>().entity_id, merchant_id);
+
+        map_server.send(&VendingItemListPacket::new(merchant_id, 7, vec![VendingItemInformation {
+            price: Price(50),
+            amount: 10,
+            index: 0,
+            item_type: 0,
+            item_id: ItemId(501),
+            is_identified: 1,
+            is_broken: 0,
+            refinement_level: 0,
+            cards: [0; 4],
+            option_data: std::array::from_fn(|_| ItemOptions {
+                index: 0,
+                value: 0,
+                parameter: 0,
+            }),
+            location: 0,
+            look: 0,
+            enchantment_level: 0,
+        }]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::VendingItemList { .. })
+        });
+        let Some(NetworkEvent::VendingItemList {
+            entity_id,
+            unique_id,
+            items,
+        }) = events.into_iter().last()
+        else {
+            panic!("expected vending item list event");
+        };
+        assert_eq!(entity_id, merchant_id);
+        assert_eq!(unique_id, 7);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].item.item_id, ItemId(501));
+        assert_eq!(items[0].item.price, Price(50));
+        assert!(matches!(items[0].item.quantity, ItemQuantity::Fixed(10)));
+
+        networking_system
+            .buy_vending_items(merchant_id, 7, vec![VendingPurchaseInformation { amount: 2, index: 0 }])
+            .unwrap();
+        let packet = map_server.receive::<BuyVendingItemsPacket>();
+        assert_eq!(packet.entity_id, merchant_id);
+        assert_eq!(packet.unique_id, 7);
+        assert_eq!(packet.items.len(), 1);
+        assert_eq!(packet.items[0].amount, 2);
+
+        map_server.send(&BuyVendingItemsResultPacket::new(0, 2, BuyVendingItemsResult::OutOfStock));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, .. } if text == "The item is out of stock."),
+        );
+
+        map_server.send(&VendingShopBoardRemovedPacket::new(merchant_id));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::VendingShopBoardRemoved { entity_id } if *entity_id == merchant_id),
+        );
+    }
+
+    #[test]
+    fn mail() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+        let no_options = || {
+            std::array::from_fn(|_| ItemOptions {
+                index: 0,
+                value: 0,
+                parameter: 0,
+            })
+        };
+
+        map_server.send(&NewMailStatusPacket::new(1));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::NewMail));
+
+        networking_system.open_mailbox().unwrap();
+        let packet = map_server.receive::<OpenMailboxPacket>();
+        assert_eq!(packet.mail_type, MailType::Character);
+        assert_eq!(packet.mail_id, MailId(0));
+
+        map_server.send(&MailListPacket::new(0, vec![MailInformation {
+            mail_type: MailType::Character,
+            mail_id: MailId(2),
+            is_read: 0,
+            attachment_flags: 0x2 | 0x4,
+            sender_name: "Sender".to_owned(),
+            expiration_time: 3600,
+            title_length: 6,
+            title: "Title".to_owned(),
+        }]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::UnreadMailCount { count: 1 })
+        });
+        let Some(NetworkEvent::MailList { mails, is_end }) =
+            events.into_iter().find(|event| matches!(event, NetworkEvent::MailList { .. }))
+        else {
+            panic!("expected mail list event");
+        };
+        assert!(!is_end);
+        assert_eq!(mails.len(), 1);
+        assert_eq!(mails[0].mail_id, MailId(2));
+        assert_eq!(mails[0].sender_name, "Sender");
+        assert_eq!(mails[0].title, "Title");
+        assert!(mails[0].has_zeny && mails[0].has_items);
+
+        networking_system.request_more_mails(MailType::Character, MailId(2)).unwrap();
+        assert_eq!(map_server.receive::<RefreshMailboxPacket>().mail_id, MailId(2));
+
+        networking_system.read_mail(MailType::Character, MailId(2)).unwrap();
+        assert_eq!(map_server.receive::<ReadMailPacket>().mail_id, MailId(2));
+
+        map_server.send(&MailContentPacket::new(
+            MailType::Character,
+            MailId(2),
+            6,
+            500,
+            1,
+            "Hello".to_owned(),
+            vec![MailItemInformation {
+                amount: 3,
+                item_id: ItemId(501),
+                is_identified: 1,
+                is_broken: 0,
+                refinement_level: 0,
+                cards: [0; 4],
+                location: EquipPosition::empty(),
+                item_type: 0,
+                look: 0,
+                bind_on_equip_type: 0,
+                option_data: no_options(),
+                enchantment_level: 0,
+            }],
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailContent { .. })
+        });
+        let Some(NetworkEvent::MailContent { mail }) = events.into_iter().last() else {
+            panic!("expected mail content event");
+        };
+        assert_eq!(mail.mail_id, MailId(2));
+        assert_eq!(mail.text, "Hello");
+        assert_eq!(mail.zeny, 500);
+        assert_eq!(mail.items.len(), 1);
+        assert!(matches!(mail.items[0].details, InventoryItemDetails::Regular { amount: 3, .. }));
+
+        networking_system.take_mail_zeny(MailType::Character, MailId(2)).unwrap();
+        assert_eq!(map_server.receive::<RequestMailZenyPacket>().mail_id, MailId(2));
+
+        map_server.send(&MailZenyResultPacket::new(
+            MailId(2),
+            MailType::Character,
+            MailAttachmentResult::Success,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailZenyTaken { mail_id: MailId(2) })
+        });
+
+        networking_system.take_mail_items(MailType::Character, MailId(2)).unwrap();
+        assert_eq!(map_server.receive::<RequestMailItemsPacket>().mail_id, MailId(2));
+
+        map_server.send(&MailItemsResultPacket::new(
+            MailId(2),
+            MailType::Character,
+            MailAttachmentResult::Overweight,
+        ));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, .. } if text == "You can not carry this much weight."),
+        );
+
+        networking_system.delete_mail(MailType::Character, MailId(2)).unwrap();
+        assert_eq!(map_server.receive::<DeleteMailPacket>().mail_id, MailId(2));
+
+        map_server.send(&MailDeletedPacket::new(MailType::Character, MailId(2)));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailDeleted { mail_id: MailId(2) })
+        });
+
+        networking_system.close_mailbox().unwrap();
+        map_server.receive::<CloseMailboxPacket>();
+
+        networking_system.start_writing_mail().unwrap();
+        assert_eq!(map_server.receive::<OpenWriteMailPacket>().receiver_name, "");
+
+        networking_system.check_mail_receiver("Friend".to_owned()).unwrap();
+        assert_eq!(map_server.receive::<CheckMailReceiverPacket>().receiver_name, "Friend");
+
+        map_server.send(&MailReceiverPacket::new(CharacterId(0), 0, 0));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailReceiver { character_id: None })
+        });
+        map_server.send(&MailReceiverPacket::new(CharacterId(150001), 0, 50));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailReceiver {
+                character_id: Some(CharacterId(150001))
+            })
+        });
+
+        networking_system.attach_mail_item(InventoryIndex(3), 1).unwrap();
+        let packet = map_server.receive::<AddMailItemPacket>();
+        assert_eq!(packet.index, InventoryIndex(3));
+        assert_eq!(packet.amount, 1);
+
+        map_server.send(&AddMailItemResultPacket::new(
+            AddMailItemResult::Success,
+            InventoryIndex(3),
+            1,
+            ItemId(909),
+            3,
+            1,
+            0,
+            0,
+            [0; 4],
+            no_options(),
+            1,
+            0,
+            EquipPosition::empty(),
+            0,
+        ));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailItemAttached { .. })
+        });
+        let Some(NetworkEvent::MailItemAttached { item }) = events.into_iter().last() else {
+            panic!("expected mail item attached event");
+        };
+        assert_eq!(item.index, InventoryIndex(3));
+        assert_eq!(item.item_id, ItemId(909));
+
+        networking_system.detach_mail_item(InventoryIndex(3), 1).unwrap();
+        assert_eq!(map_server.receive::<RemoveMailItemPacket>().index, InventoryIndex(3));
+
+        map_server.send(&RemoveMailItemResultPacket::new(1, InventoryIndex(3), 1, 0));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::MailItemDetached {
+                index: InventoryIndex(3),
+                amount: 1,
+            })
+        });
+
+        networking_system
+            .send_mail(
+                "Friend".to_owned(),
+                "Player".to_owned(),
+                CharacterId(150001),
+                "Hi".to_owned(),
+                "How are you?".to_owned(),
+                100,
+            )
+            .unwrap();
+        let packet = map_server.receive::<SendMailPacket>();
+        assert_eq!(packet.receiver_name, "Friend");
+        assert_eq!(packet.sender_name, "Player");
+        assert_eq!(packet.receiver_character_id, CharacterId(150001));
+        assert_eq!(packet.title_length, 3);
+        assert_eq!(packet.title, "Hi");
+        assert_eq!(packet.text_length, 13);
+        assert_eq!(packet.text, "How are you?");
+        assert_eq!(packet.zeny, 100);
+
+        map_server.send(&SendMailResultPacket::new(1));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::MailSent));
+    }
+
+    #[test]
+    fn achievements() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+        let achievement = |acheivement_id: u32, is_completed: u8, got_rewarded: u8| AchievementData {
+            acheivement_id,
+            is_completed,
+            objectives: [1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            completion_timestamp: 0,
+            got_rewarded,
+        };
+
+        map_server.send(&AchievementListPacket::new(20, 2, 5, 30, vec![
+            achievement(120001, 1, 0),
+            achievement(120002, 0, 0),
+        ]));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AchievementList { .. })
+        });
+        let Some(NetworkEvent::AchievementList { summary, achievements }) = events.into_iter().last() else {
+            panic!("expected achievement list event");
+        };
+        assert_eq!(summary.total_score, 20);
+        assert_eq!(summary.level, 2);
+        assert_eq!(summary.experience, 5);
+        assert_eq!(summary.experience_to_next_level, 30);
+        assert_eq!(achievements.len(), 2);
+        assert_eq!(achievements[0].achievement_id, 120001);
+        assert!(achievements[0].is_completed);
+        assert!(!achievements[1].is_completed);
+
+        map_server.send(&AchievementUpdatePacket::new(30, 2, 15, 30, achievement(120002, 1, 0)));
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AchievementUpdate { .. })
+        });
+        let Some(NetworkEvent::AchievementUpdate { summary, achievement }) = events.into_iter().last() else {
+            panic!("expected achievement update event");
+        };
+        assert_eq!(summary.total_score, 30);
+        assert_eq!(achievement.achievement_id, 120002);
+        assert!(achievement.is_completed);
+        assert_eq!(achievement.objectives[0], 1);
+
+        networking_system.request_achievement_reward(120001).unwrap();
+        assert_eq!(map_server.receive::<RequestAchievementRewardPacket>().achievement_id, 120001);
+
+        map_server.send(&AchievementRewardPacket::new(1, 120001));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::AchievementRewarded { achievement_id: 120001 })
+        });
+
+        map_server.send(&AchievementRewardPacket::new(0, 120002));
+        poll_until(
+            &mut networking_system,
+            |event| matches!(event, NetworkEvent::ChatMessage { text, .. } if text == "Failed to claim the achievement reward."),
+        );
+    }
+
+    #[test]
+    fn skip_unregistered_packets() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        // Character server packet that the map server handler doesn't register, so it
+        // can only be skipped using the built-in packet length table.
+        map_server.send(&CharacterServerLoginSuccessPacket::new(29, 9, 0, 0, 0, 9));
+        map_server.send(&DisplayEmotionPacket::new(EntityId(ACCOUNT_ID.0), EmotionId(2)));
+
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::EntityEmotion { emotion: EmotionId(2), .. })
+        });
+    }
+
+    #[test]
+    fn legacy_packet_version() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = NetworkingSystem::spawn();
+        networking_system.set_packet_version(PacketVersion(20150101));
+
+        let login_server_login_data = LoginServerLoginData {
+            account_id: ACCOUNT_ID,
+            login_id1: 11,
+            login_id2: 22,
+            sex: Sex::Male,
+        };
+        let character_server_login_data = CharacterServerLoginData {
+            server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server_port: map_server.address().port(),
+            character_id: CHARACTER_ID,
+        };
+
+        networking_system.connect_to_map_server(&login_server_login_data, character_server_login_data);
+        map_server.accept();
+
+        // The legacy login packet is shorter, so the map loaded packet would be cut
+        // off if the client sent the current layout.
+        let login_packet = map_server.receive::<LegacyMapServerLoginPacket>();
+        assert_eq!(login_packet.account_id, ACCOUNT_ID);
+        assert_eq!(login_packet.character_id, CHARACTER_ID);
+
+        map_server.send(&MapServerLoginSuccessPacket::new(
+            ClientTick(100),
+            WorldPosition::new(150, 180),
+            0,
+        ));
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetPlayerPosition(..))
+        });
+
+        networking_system.map_loaded().unwrap();
+        map_server.receive::<MapLoadedPacket>();
+
+        map_server.send(&LegacyRegularItemListPacket::new(vec![LegacyRegularItemInformation {
+            index: InventoryIndex(2),
+            item_id: 501,
+            item_type: 0,
+            amount: 5,
+            equipped_position: EquipPosition::empty(),
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            flags: RegularItemFlags::IDENTIFIED,
+        }]));
+        map_server.send(&LegacyEquippableItemListPacket::new(vec![LegacyEquippableItemInformation {
+            index: InventoryIndex(3),
+            item_id: 1201,
+            item_type: 5,
+            equip_position: EquipPosition::empty(),
+            equipped_position: EquipPosition::empty(),
+            refinement_level: 4,
+            slot: [4001, 0, 0, 0],
+            hire_expiration_date: 0,
+            bind_on_equip_type: 0,
+            w_item_sprite_number: 0,
+            option_count: 0,
+            option_data: std::array::from_fn(|_| ItemOptions {
+                index: 0,
+                value: 0,
+                parameter: 0,
+            }),
+            flags: EquippableItemFlags::IDENTIFIED,
+        }]));
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::SetInventory { .. })
+        });
+        let Some(NetworkEvent::SetInventory { items }) = events.into_iter().last() else {
+            panic!("expected set inventory event");
+        };
+
+        let item_ids: Vec<ItemId> = items.iter().map(|item| item.item_id).collect();
+        assert_eq!(item_ids, vec![ItemId(501), ItemId(1201)]);
+        assert_eq!(items[1].slot, [4001, 0, 0, 0]);
+    }
+
+    #[test]
+    fn statistics() {
+        let mut map_server = MockServer::bind();
+        let mut networking_system = enter_map(&mut map_server);
+
+        // The first ping is sent right after connecting.
+        map_server.send(&ServerTickPacket::new(ClientTick(200)));
+        poll_until(&mut networking_system, |event| matches!(event, NetworkEvent::TickSample(..)));
+
+        let statistics = networking_system.get_statistics();
+        let map_server_statistics = &statistics.map_server;
+
+        assert_eq!(map_server_statistics.sent_packets.get(&MapServerLoginPacket::HEADER), Some(&1));
+        assert_eq!(
+            map_server_statistics.received_packets.get(&MapServerLoginSuccessPacket::HEADER),
+            Some(&1)
+        );
+        assert_eq!(map_server_statistics.received_packets.get(&ServerTickPacket::HEADER), Some(&1));
+        assert!(map_server_statistics.bytes_received >= 17);
+        assert!(map_server_statistics.latency.is_some());
+        assert_eq!(statistics.login_server.packets_sent, 0);
+
+        networking_system.statistics_handle().reset();
+        assert_eq!(networking_system.get_statistics().map_server.packets_received, 0);
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let capture_path = std::env::temp_dir().join(format!("korangar-replay-{}.kcap", std::process::id()));
+        let packet_recorder = PacketRecorder::create(&capture_path).unwrap();
+
+        // Record a login.
+        let mut login_server = MockServer::bind();
+        let mut networking_system = NetworkingSystem::spawn_with_callback(packet_recorder.clone());
+
+        networking_system.connect_to_login_server(login_server.address(), "username", "password");
+        login_server.accept();
+        login_server.receive::<LoginServerLoginPacket>();
+
+        let (server_ip, server_port) = login_server.server_address();
+        let character_server_information = CharacterServerInformation::new(server_ip, server_port, "Mock".to_owned(), 0, 0, 0);
+        let mut login_success = LoginServerLoginSuccessPacket::new(11, ACCOUNT_ID, 22, Sex::Male, AuthToken([0; 17]), vec![
+            character_server_information,
+        ])
+        .packet_to_bytes()
+        .unwrap();
+
+        // Anything after the terminator of a string is dropped when parsing, so it
+        // would be lost if the packet was encoded again for the recording.
+        let name_end = login_success.windows(5).position(|window| window == b"Mock\0").unwrap() + 4;
+        login_success[name_end + 1] = b'X';
+
+        login_server.send_raw(&login_success);
+        poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::LoginServerConnected { .. })
+        });
+        packet_recorder.flush().unwrap();
+
+        let capture = Capture::load(&capture_path).unwrap();
+        std::fs::remove_file(&capture_path).unwrap();
+
+        let incoming_records: Vec<_> = capture
+            .records
+            .iter()
+            .filter(|record| record.direction == PacketDirection::Incoming)
+            .collect();
+        assert_eq!(incoming_records.len(), 1);
+        assert_eq!(incoming_records[0].bytes, login_success);
+
+        // Play it back without a server.
+        drop(login_server);
+        let mut networking_system = NetworkingSystem::spawn();
+        networking_system.set_replay(Some(capture));
+        networking_system.connect_to_login_server((Ipv4Addr::LOCALHOST, 1).into(), "username", "password");
+
+        let events = poll_until(&mut networking_system, |event| {
+            matches!(event, NetworkEvent::LoginServerConnected { .. })
+        });
+        assert!(
+            matches!(events.last(), Some(NetworkEvent::LoginServerConnected { login_data, .. }) if login_data.account_id == ACCOUNT_ID)
+        );
+    }
+
+    #[test]
+    fn record_versioned_packets() {
+        let capture_path = std::env::temp_dir().join(format!("korangar-versioned-{}.kcap", std::process::id()));
+        let packet_recorder = PacketRecorder::create(&capture_path).unwrap();
+
+        let mut map_server = MockServer::bind();
+        let mut networking_system = NetworkingSystem::spawn_with_callback(packet_recorder.clone());
+        networking_system.set_packet_version(PacketVersion(20150101));
+
+        let login_server_login_data = LoginServerLoginData {
+            account_id: ACCOUNT_ID,
+            login_id1: 11,
+            login_id2: 22,
+            sex: Sex::Male,
+        };
+        let character_server_login_data = CharacterServerLoginData {
+            server_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            server_port: map_server.address().port(),
+            character_id: CHARACTER_ID,
+        };
+
+        networking_system.connect_to_map_server(&login_server_login_data, character_server_login_data);
+        map_server.accept();
+        let login_packet = map_server.receive::<LegacyMapServerLoginPacket>();
+        packet_recorder.flush().unwrap();
+
+        let capture = Capture::load(&capture_path).unwrap();
+        std::fs::remove_file(&capture_path).unwrap();
+
+        // The recording has to contain the legacy layout that was sent and not the
+        // current one.
+        assert_eq!(capture.records.len(), 1);
+        assert_eq!(capture.records[0].direction, PacketDirection::Outgoing);
+        assert_eq!(capture.records[0].bytes, login_packet.packet_to_bytes().unwrap());
+    }
+}
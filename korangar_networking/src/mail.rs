@@ -0,0 +1,97 @@
+use ragnarok_packets::*;
+
+use crate::{InventoryItem, InventoryItemDetails, NoMetadata};
+
+/// A mail as it appears in the mailbox, before it is read.
+#[derive(Debug, Clone)]
+pub struct MailPreview {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub sender_name: String,
+    pub title: String,
+    pub is_read: bool,
+    pub has_zeny: bool,
+    pub has_items: bool,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+}
+
+impl From<MailInformation> for MailPreview {
+    fn from(mail: MailInformation) -> Self {
+        Self {
+            mail_type: mail.mail_type,
+            mail_id: mail.mail_id,
+            sender_name: mail.sender_name,
+            title: mail.title,
+            is_read: mail.is_read != 0,
+            has_zeny: mail.attachment_flags & 0x2 != 0,
+            has_items: mail.attachment_flags & 0x4 != 0,
+            expiration_time: mail.expiration_time,
+        }
+    }
+}
+
+/// The full content of a mail. The index of the attached items has no meaning
+/// since they are not part of the player's inventory.
+#[derive(Debug, Clone)]
+pub struct Mail<Meta> {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub text: String,
+    pub zeny: u64,
+    pub items: Vec<InventoryItem<Meta>>,
+}
+
+impl From<MailContentPacket> for Mail<NoMetadata> {
+    fn from(packet: MailContentPacket) -> Self {
+        Self {
+            mail_type: packet.mail_type,
+            mail_id: packet.mail_id,
+            text: packet.text,
+            zeny: packet.zeny,
+            items: packet.items.into_iter().map(InventoryItem::from).collect(),
+        }
+    }
+}
+
+impl From<MailItemInformation> for InventoryItem<NoMetadata> {
+    fn from(item: MailItemInformation) -> Self {
+        let details = match item.location.is_empty() {
+            true => InventoryItemDetails::Regular {
+                amount: item.amount,
+                equipped_position: EquipPosition::empty(),
+                flags: {
+                    let mut flags = RegularItemFlags::empty();
+                    flags.set(RegularItemFlags::IDENTIFIED, item.is_identified != 0);
+                    flags
+                },
+            },
+            false => InventoryItemDetails::Equippable {
+                equip_position: item.location,
+                equipped_position: EquipPosition::empty(),
+                bind_on_equip_type: item.bind_on_equip_type,
+                w_item_sprite_number: item.look,
+                option_count: item.option_data.len() as u8,
+                option_data: item.option_data,
+                refinement_level: item.refinement_level,
+                enchantment_level: item.enchantment_level,
+                flags: {
+                    let mut flags = EquippableItemFlags::empty();
+                    flags.set(EquippableItemFlags::IDENTIFIED, item.is_identified != 0);
+                    flags.set(EquippableItemFlags::IS_BROKEN, item.is_broken != 0);
+                    flags
+                },
+            },
+        };
+
+        InventoryItem {
+            metadata: NoMetadata,
+            index: InventoryIndex(0),
+            item_id: item.item_id,
+            item_type: item.item_type,
+            slot: item.cards,
+            hire_expiration_date: 0,
+            details,
+        }
+    }
+}
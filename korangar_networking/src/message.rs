@@ -5,4 +5,5 @@ pub enum MessageColor {
     Server,
     Error,
     Information,
+    Whisper,
 }
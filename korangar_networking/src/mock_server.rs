@@ -0,0 +1,156 @@
+//! Minimal stand-in for the login, character and map server, used to test the
+//! [`NetworkingSystem`](crate::NetworkingSystem) end-to-end. The server is
+//! driven by the test itself, so every call blocks until the client catches up
+//! or a timeout expires.
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::ByteStream;
+use ragnarok_packets::handler::PacketCallback;
+use ragnarok_packets::*;
+
+use crate::{NetworkEvent, NetworkingSystem};
+
+/// How long to wait for the client before failing the test.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub(crate) struct MockServer {
+    listener: TcpListener,
+    stream: Option<TcpStream>,
+    buffer: Vec<u8>,
+    /// Packets that the client sends on its own, like keep-alive packets.
+    /// These are skipped when receiving.
+    ignored_packets: PacketLengthTable,
+}
+
+impl MockServer {
+    pub fn bind() -> Self {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut ignored_packets = PacketLengthTable::empty();
+
+        Self::ignore(&mut ignored_packets, LoginServerKeepalivePacket::new());
+        Self::ignore(&mut ignored_packets, CharacterServerKeepalivePacket::new());
+        Self::ignore(&mut ignored_packets, RequestServerTickPacket::new(ClientTick(0)));
+
+        Self {
+            listener,
+            stream: None,
+            buffer: Vec::new(),
+            ignored_packets,
+        }
+    }
+
+    fn ignore<P: Packet>(ignored_packets: &mut PacketLengthTable, packet: P) {
+        let length = packet.packet_to_bytes().unwrap().len();
+        ignored_packets.insert(P::HEADER, PacketLength::Fixed(length));
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.listener.local_addr().unwrap()
+    }
+
+    /// The address in the format the login and character server use to
+    /// point the client to the next server.
+    pub fn server_address(&self) -> (ServerAddress, u16) {
+        (ServerAddress(Ipv4Addr::LOCALHOST.octets()), self.address().port())
+    }
+
+    /// Wait for the client to connect. Replaces the previous connection.
+    pub fn accept(&mut self) {
+        self.listener.set_nonblocking(true).unwrap();
+        let deadline = Instant::now() + TIMEOUT;
+
+        let stream = loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => break stream,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                    assert!(Instant::now() < deadline, "client did not connect");
+                    std::thread::sleep(Duration::from_millis(5));
+                }
+                Err(error) => panic!("failed to accept connection: {error}"),
+            }
+        };
+
+        stream.set_nonblocking(false).unwrap();
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+
+        self.stream = Some(stream);
+        self.buffer.clear();
+    }
+
+    pub fn send(&mut self, packet: &impl Packet) {
+        self.send_raw(&packet.packet_to_bytes().unwrap());
+    }
+
+    /// Send bytes that are not part of a packet, like the account id that the
+    /// character server sends after the login.
+    pub fn send_raw(&mut self, bytes: &[u8]) {
+        self.stream().write_all(bytes).unwrap();
+    }
+
+    /// Wait for the client to send a packet of type `P`. Fails the test if the
+    /// client sends any other packet first.
+    pub fn receive<P: Packet>(&mut self) -> P {
+        loop {
+            if self.buffer.len() >= 2 {
+                let header = PacketHeader(u16::from_le_bytes([self.buffer[0], self.buffer[1]]));
+
+                if let Some(PacketLength::Fixed(length)) = self.ignored_packets.get(header) {
+                    if self.buffer.len() >= length {
+                        self.buffer.drain(..length);
+                        continue;
+                    }
+                } else {
+                    assert_eq!(header, P::HEADER, "expected a different packet from the client");
+
+                    let mut byte_stream = ByteStream::<()>::without_metadata(&self.buffer);
+
+                    if let Ok(packet) = P::packet_from_bytes(&mut byte_stream) {
+                        let length = byte_stream.get_offset();
+                        self.buffer.drain(..length);
+                        return packet;
+                    }
+                }
+            }
+
+            self.read_more();
+        }
+    }
+
+    fn read_more(&mut self) {
+        let mut bytes = [0; 4096];
+
+        let received_bytes = match self.stream().read(&mut bytes) {
+            Ok(0) => panic!("client closed the connection"),
+            Ok(received_bytes) => received_bytes,
+            Err(error) => panic!("failed to receive from the client: {error}"),
+        };
+
+        self.buffer.extend_from_slice(&bytes[..received_bytes]);
+    }
+
+    fn stream(&mut self) -> &mut TcpStream {
+        self.stream.as_mut().expect("no client connected")
+    }
+}
+
+/// Poll events until `predicate` matches one of them. Returns all events that
+/// were received up to that point.
+pub(crate) fn poll_until<Callback: PacketCallback + Send>(
+    networking_system: &mut NetworkingSystem<Callback>,
+    predicate: impl Fn(&NetworkEvent) -> bool,
+) -> Vec<NetworkEvent> {
+    let deadline = Instant::now() + TIMEOUT;
+    let mut events = Vec::new();
+
+    while !events.iter().any(&predicate) {
+        assert!(Instant::now() < deadline, "timed out, received {events:?}");
+
+        events.extend(networking_system.get_events());
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    events
+}
@@ -0,0 +1,67 @@
+use ragnarok_packets::*;
+
+#[derive(Debug, Clone)]
+pub struct PartyMember {
+    pub account_id: AccountId,
+    /// Older servers don't send the character id of the members.
+    pub character_id: Option<CharacterId>,
+    pub name: String,
+    pub map_name: String,
+    pub is_leader: bool,
+    pub is_online: bool,
+    pub job: u16,
+    pub base_level: u16,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
+}
+
+impl From<PartyMemberInformation> for PartyMember {
+    fn from(member: PartyMemberInformation) -> Self {
+        Self {
+            account_id: member.account_id,
+            character_id: Some(member.character_id),
+            name: member.name,
+            map_name: member.map_name.replace(".gat", ""),
+            is_leader: member.role == 0,
+            is_online: member.is_offline == 0,
+            job: member.job,
+            base_level: member.base_level,
+            health_points: 0,
+            maximum_health_points: 0,
+        }
+    }
+}
+
+impl From<LegacyPartyMemberInformation> for PartyMember {
+    fn from(member: LegacyPartyMemberInformation) -> Self {
+        Self {
+            account_id: member.account_id,
+            character_id: None,
+            name: member.name,
+            map_name: member.map_name.replace(".gat", ""),
+            is_leader: member.role == 0,
+            is_online: member.is_offline == 0,
+            job: member.job,
+            base_level: member.base_level,
+            health_points: 0,
+            maximum_health_points: 0,
+        }
+    }
+}
+
+impl From<PartyMemberJoinedPacket> for PartyMember {
+    fn from(packet: PartyMemberJoinedPacket) -> Self {
+        Self {
+            account_id: packet.account_id,
+            character_id: Some(packet.character_id),
+            name: packet.name,
+            map_name: packet.map_name.replace(".gat", ""),
+            is_leader: packet.role == 0,
+            is_online: packet.is_offline == 0,
+            job: packet.job,
+            base_level: packet.base_level,
+            health_points: 0,
+            maximum_health_points: 0,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use ragnarok_packets::*;
+
+#[derive(Debug, Clone)]
+pub struct QuestObjective {
+    pub hunt_identification: u32,
+    pub mob_id: u32,
+    pub mob_name: String,
+    pub current_count: u16,
+    pub total_count: u16,
+}
+
+impl QuestObjective {
+    pub fn is_completed(&self) -> bool {
+        self.current_count >= self.total_count
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestInformation {
+    pub quest_id: u32,
+    pub active: bool,
+    pub start_time: u32,
+    pub expire_time: u32,
+    pub objectives: Vec<QuestObjective>,
+}
+
+impl From<Quest> for QuestInformation {
+    fn from(quest: Quest) -> Self {
+        let objectives = quest
+            .objective_details
+            .into_iter()
+            .map(|details| QuestObjective {
+                hunt_identification: details.hunt_identification,
+                mob_id: details.mob_id,
+                mob_name: details.mob_name,
+                current_count: details.kill_count,
+                total_count: details.total_count,
+            })
+            .collect();
+
+        Self {
+            quest_id: quest.quest_id,
+            active: quest.active != 0,
+            start_time: quest.start_time,
+            expire_time: quest.expire_time,
+            objectives,
+        }
+    }
+}
+
+impl From<QuestNotificationPacket1> for QuestInformation {
+    fn from(packet: QuestNotificationPacket1) -> Self {
+        // The packet always has space for three objectives, so we only take the
+        // ones that are actually used.
+        let objectives = packet
+            .objective_details
+            .into_iter()
+            .take(packet.objective_count as usize)
+            .map(|details| QuestObjective {
+                hunt_identification: details.hunt_identification,
+                mob_id: details.mob_id,
+                mob_name: details.mob_name,
+                current_count: 0,
+                total_count: details.mob_count,
+            })
+            .collect();
+
+        Self {
+            quest_id: packet.quest_id,
+            active: packet.active != 0,
+            start_time: packet.start_time,
+            expire_time: packet.expire_time,
+            objectives,
+        }
+    }
+}
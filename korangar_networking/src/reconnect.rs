@@ -0,0 +1,48 @@
+use std::time::{Duration, Instant};
+
+/// Policy for automatically reconnecting to the map server after the
+/// connection was lost unexpectedly.
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectPolicy {
+    /// Number of reconnect attempts before giving up.
+    pub max_attempts: u32,
+    /// Delay before the first attempt. Every following attempt doubles the
+    /// delay.
+    pub initial_delay: Duration,
+    /// Upper limit for the delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl ReconnectPolicy {
+    /// Delay before the given attempt, starting at 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(16),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ReconnectState {
+    /// Waiting for the backoff delay to run out.
+    Waiting { attempt: u32, next_attempt: Instant },
+    /// Connection was opened, waiting for the first packet from the server.
+    Connecting { attempt: u32 },
+}
+
+impl ReconnectState {
+    pub fn attempt(&self) -> u32 {
+        match self {
+            ReconnectState::Waiting { attempt, .. } | ReconnectState::Connecting { attempt } => *attempt,
+        }
+    }
+}
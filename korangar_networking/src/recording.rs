@@ -0,0 +1,416 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::handler::PacketCallback;
+use ragnarok_packets::*;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+const CAPTURE_MAGIC: &[u8; 4] = b"KCAP";
+const CAPTURE_VERSION: u8 = 1;
+/// Direction, server type, timestamp and length.
+const RECORD_HEADER_SIZE: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// A single packet in a [`Capture`].
+#[derive(Debug, Clone)]
+pub struct PacketRecord {
+    pub direction: PacketDirection,
+    pub server_type: ServerType,
+    /// Time since the recording started.
+    pub timestamp: Duration,
+    pub bytes: Vec<u8>,
+}
+
+/// Error when loading a [`Capture`].
+#[derive(Debug)]
+pub enum CaptureError {
+    Io(std::io::Error),
+    InvalidHeader,
+    UnsupportedVersion(u8),
+    InvalidRecord { offset: usize },
+}
+
+impl Display for CaptureError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureError::Io(error) => write!(formatter, "failed to read capture: {error}"),
+            CaptureError::InvalidHeader => write!(formatter, "not a packet capture"),
+            CaptureError::UnsupportedVersion(version) => write!(formatter, "unsupported capture version {version}"),
+            CaptureError::InvalidRecord { offset } => write!(formatter, "invalid record at offset {offset}"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// A recorded session, written by the [`PacketRecorder`].
+#[derive(Debug, Clone, Default)]
+pub struct Capture {
+    pub records: Vec<PacketRecord>,
+}
+
+impl Capture {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CaptureError> {
+        let bytes = std::fs::read(path).map_err(CaptureError::Io)?;
+        Self::parse(&bytes)
+    }
+
+    pub fn parse(bytes: &[u8]) -> Result<Self, CaptureError> {
+        let Some(data) = bytes.strip_prefix(CAPTURE_MAGIC) else {
+            return Err(CaptureError::InvalidHeader);
+        };
+
+        let Some((&version, mut data)) = data.split_first() else {
+            return Err(CaptureError::InvalidHeader);
+        };
+
+        if version != CAPTURE_VERSION {
+            return Err(CaptureError::UnsupportedVersion(version));
+        }
+
+        let mut records = Vec::new();
+
+        while !data.is_empty() {
+            let invalid_record = CaptureError::InvalidRecord {
+                offset: bytes.len() - data.len(),
+            };
+
+            if data.len() < RECORD_HEADER_SIZE {
+                return Err(invalid_record);
+            }
+
+            let direction = match data[0] {
+                0 => PacketDirection::Incoming,
+                1 => PacketDirection::Outgoing,
+                _ => return Err(invalid_record),
+            };
+            let server_type = match data[1] {
+                0 => ServerType::Login,
+                1 => ServerType::Character,
+                2 => ServerType::Map,
+                _ => return Err(invalid_record),
+            };
+            let timestamp = u32::from_le_bytes([data[2], data[3], data[4], data[5]]);
+            let length = u32::from_le_bytes([data[6], data[7], data[8], data[9]]) as usize;
+
+            let Some(packet_bytes) = data[RECORD_HEADER_SIZE..].get(..length) else {
+                return Err(invalid_record);
+            };
+
+            records.push(PacketRecord {
+                direction,
+                server_type,
+                timestamp: Duration::from_millis(timestamp as u64),
+                bytes: packet_bytes.to_vec(),
+            });
+
+            data = &data[RECORD_HEADER_SIZE + length..];
+        }
+
+        Ok(Self { records })
+    }
+}
+
+/// Packet callback that writes every packet to a [`Capture`]. Incoming packets
+/// are recorded with the exact bytes that the server sent, including unknown
+/// packets. Outgoing packets are recorded with the bytes that were sent, which
+/// are encoded for the packet version of the client.
+#[derive(Clone)]
+pub struct PacketRecorder {
+    server_type: Option<ServerType>,
+    start: Instant,
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl PacketRecorder {
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn new(mut writer: impl Write + Send + 'static) -> std::io::Result<Self> {
+        writer.write_all(CAPTURE_MAGIC)?;
+        writer.write_all(&[CAPTURE_VERSION])?;
+
+        Ok(Self {
+            server_type: None,
+            start: Instant::now(),
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+        })
+    }
+
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.writer.lock().unwrap().flush()
+    }
+
+    fn record(&self, direction: PacketDirection, bytes: &[u8]) {
+        // Packets are only recorded through the callbacks of the individual servers.
+        let Some(server_type) = self.server_type else {
+            return;
+        };
+
+        let direction = match direction {
+            PacketDirection::Incoming => 0u8,
+            PacketDirection::Outgoing => 1u8,
+        };
+        let server_type = match server_type {
+            ServerType::Login => 0u8,
+            ServerType::Character => 1u8,
+            ServerType::Map => 2u8,
+        };
+        let timestamp = self.start.elapsed().as_millis() as u32;
+
+        let mut record = Vec::with_capacity(RECORD_HEADER_SIZE + bytes.len());
+        record.push(direction);
+        record.push(server_type);
+        record.extend_from_slice(&timestamp.to_le_bytes());
+        record.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        record.extend_from_slice(bytes);
+
+        // A failed write shouldn't take down the connection, so the error is
+        // ignored.
+        let _ = self.writer.lock().unwrap().write_all(&record);
+    }
+}
+
+impl PacketCallback for PacketRecorder {
+    fn for_server(&self, server_type: ServerType) -> Self {
+        Self {
+            server_type: Some(server_type),
+            ..self.clone()
+        }
+    }
+
+    fn raw_incoming_packet(&self, bytes: &[u8]) {
+        self.record(PacketDirection::Incoming, bytes);
+    }
+
+    fn raw_outgoing_packet(&self, bytes: &[u8]) {
+        self.record(PacketDirection::Outgoing, bytes);
+    }
+}
+
+/// The data received during one connection to a server, with the time since
+/// the connection was opened.
+#[derive(Default)]
+pub(crate) struct ReplayConnection {
+    start: Duration,
+    chunks: Vec<(Duration, Vec<u8>)>,
+}
+
+impl ReplayConnection {
+    /// Create a stream that plays back the recorded data with the original
+    /// timing. Everything that is written to the stream is discarded.
+    pub(crate) fn into_stream(self) -> DuplexStream {
+        let (client_stream, server_stream) = tokio::io::duplex(64 * 1024);
+        let (mut reader, mut writer) = tokio::io::split(server_stream);
+
+        tokio::spawn(async move {
+            let play_back = async move {
+                let start = tokio::time::Instant::now();
+
+                for (offset, bytes) in self.chunks {
+                    tokio::time::sleep_until(start + offset).await;
+
+                    if writer.write_all(&bytes).await.is_err() {
+                        return;
+                    }
+                }
+
+                // Keep the write half alive so the connection stays open after
+                // the last packet.
+                std::future::pending::<()>().await;
+            };
+
+            let discard = async move {
+                let mut buffer = [0; 1024];
+                while matches!(reader.read(&mut buffer).await, Ok(1..)) {}
+            };
+
+            // The connection is closed as soon as the client drops its side.
+            tokio::select! {
+                _ = play_back => {}
+                _ = discard => {}
+            }
+        });
+
+        client_stream
+    }
+}
+
+/// Splits a [`Capture`] into the individual connections to each server, so
+/// they can be played back in place of a TCP stream.
+pub(crate) struct Replay {
+    connections: HashMap<ServerType, VecDeque<ReplayConnection>>,
+}
+
+impl Replay {
+    pub(crate) fn new(capture: Capture) -> Self {
+        let mut connections: HashMap<ServerType, VecDeque<ReplayConnection>> = HashMap::new();
+
+        for record in capture.records {
+            let server_connections = connections.entry(record.server_type).or_default();
+
+            match record.direction {
+                // Every connection starts with the client logging in.
+                PacketDirection::Outgoing if is_login_packet(record.server_type, &record.bytes) => {
+                    let mut connection = ReplayConnection {
+                        start: record.timestamp,
+                        chunks: Vec::new(),
+                    };
+
+                    // The character server sends the account id before the first packet. It is
+                    // not part of a packet, so it needs to be restored from the login packet.
+                    if record.server_type == ServerType::Character {
+                        if let Some(account_id) = record.bytes.get(2..6) {
+                            connection.chunks.push((Duration::ZERO, account_id.to_vec()));
+                        }
+                    }
+
+                    server_connections.push_back(connection);
+                }
+                PacketDirection::Outgoing => {}
+                PacketDirection::Incoming => {
+                    if let Some(connection) = server_connections.back_mut() {
+                        let offset = record.timestamp.saturating_sub(connection.start);
+                        connection.chunks.push((offset, record.bytes));
+                    }
+                }
+            }
+        }
+
+        Self { connections }
+    }
+
+    /// Take the next recorded connection to a server. If there are no more
+    /// connections, the server will never respond.
+    pub(crate) fn next_connection(&mut self, server_type: ServerType) -> ReplayConnection {
+        self.connections
+            .get_mut(&server_type)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_default()
+    }
+}
+
+fn is_login_packet(server_type: ServerType, bytes: &[u8]) -> bool {
+    let Some(header) = bytes
+        .get(..2)
+        .map(|header| PacketHeader(u16::from_le_bytes([header[0], header[1]])))
+    else {
+        return false;
+    };
+
+    match server_type {
+        ServerType::Login => header == LoginServerLoginPacket::HEADER,
+        ServerType::Character => header == CharacterServerLoginPacket::HEADER,
+        ServerType::Map => header == MapServerLoginPacket::HEADER,
+    }
+}
+
+#[cfg(test)]
+mod capture {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use ragnarok_packets::handler::PacketCallback;
+    use ragnarok_packets::*;
+
+    use super::{Capture, CaptureError, PacketDirection, PacketRecorder, Replay};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(bytes)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn record_and_load() {
+        let buffer = SharedBuffer::default();
+        let recorder = PacketRecorder::new(buffer.clone()).unwrap();
+
+        let character_server = recorder.for_server(ServerType::Character);
+        character_server.raw_outgoing_packet(&RequestCharacterListPacket::new().packet_to_bytes().unwrap());
+        character_server.raw_incoming_packet(&RequestCharacterListSuccessPacket::new(Vec::new()).packet_to_bytes().unwrap());
+        recorder.for_server(ServerType::Map).raw_incoming_packet(&[0xFF, 0xFF, 1, 2]);
+        // Decoded packets are only recorded through their raw bytes.
+        recorder
+            .for_server(ServerType::Map)
+            .incoming_packet(&DisplayEmotionPacket::new(EntityId(1), EmotionId(0)));
+        recorder.for_server(ServerType::Map).outgoing_packet(&MapLoadedPacket::new());
+        // Packets without a server are not recorded.
+        recorder.raw_outgoing_packet(&MapLoadedPacket::new().packet_to_bytes().unwrap());
+
+        let capture = Capture::parse(&buffer.0.lock().unwrap()).unwrap();
+
+        assert_eq!(capture.records.len(), 3);
+        assert_eq!(capture.records[0].direction, PacketDirection::Outgoing);
+        assert_eq!(capture.records[0].server_type, ServerType::Character);
+        assert_eq!(
+            capture.records[0].bytes,
+            RequestCharacterListPacket::new().packet_to_bytes().unwrap()
+        );
+        assert_eq!(capture.records[1].direction, PacketDirection::Incoming);
+        assert_eq!(capture.records[2].server_type, ServerType::Map);
+        assert_eq!(capture.records[2].bytes, [0xFF, 0xFF, 1, 2]);
+    }
+
+    #[test]
+    fn invalid_capture() {
+        assert!(matches!(Capture::parse(b"PCAP\x01"), Err(CaptureError::InvalidHeader)));
+        assert!(matches!(Capture::parse(b"KCAP\x02"), Err(CaptureError::UnsupportedVersion(2))));
+        assert!(matches!(
+            Capture::parse(b"KCAP\x01\x00\x00\x00\x00\x00\x00\x05\x00\x00\x00\x01"),
+            Err(CaptureError::InvalidRecord { offset: 5 })
+        ));
+    }
+
+    #[test]
+    fn split_connections() {
+        let record = |direction, server_type, timestamp, bytes: Vec<u8>| super::PacketRecord {
+            direction,
+            server_type,
+            timestamp: Duration::from_millis(timestamp),
+            bytes,
+        };
+        let login_packet = CharacterServerLoginPacket::new(AccountId(7), 0, 0, Sex::Male);
+
+        let mut replay = Replay::new(Capture {
+            records: vec![
+                record(
+                    PacketDirection::Outgoing,
+                    ServerType::Character,
+                    100,
+                    login_packet.packet_to_bytes().unwrap(),
+                ),
+                record(PacketDirection::Incoming, ServerType::Character, 150, vec![1, 2]),
+                record(PacketDirection::Incoming, ServerType::Map, 200, vec![3, 4]),
+            ],
+        });
+
+        let connection = replay.next_connection(ServerType::Character);
+        assert_eq!(connection.chunks, [
+            (Duration::ZERO, 7u32.to_le_bytes().to_vec()),
+            (Duration::from_millis(50), vec![1, 2])
+        ]);
+        // Incoming data before the client logged in is dropped.
+        assert!(replay.next_connection(ServerType::Map).chunks.is_empty());
+        assert!(replay.next_connection(ServerType::Character).chunks.is_empty());
+    }
+}
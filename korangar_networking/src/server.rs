@@ -1,9 +1,16 @@
+use std::fmt::{Display, Formatter};
+use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
 
-use ragnarok_packets::{AccountId, CharacterId, Sex};
+use ragnarok_packets::{AccountId, CharacterId, PacketLengthTable, PacketVersion, Sex};
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
 
 use crate::event::NetworkEvent;
+use crate::recording::ReplayConnection;
+use crate::statistics::StatisticsCollector;
 
 #[derive(Debug, Clone, Copy)]
 pub struct LoginServerLoginData {
@@ -42,30 +49,139 @@ pub struct CharacterServerLoginData {
     pub character_id: CharacterId,
 }
 
+/// Where a server connection reads from and writes to.
+pub(crate) enum ConnectionTarget {
+    Address(SocketAddr),
+    Replay(ReplayConnection),
+}
+
+/// Wait for a connection to a server to be established, giving up once the
+/// connect timeout elapsed.
+pub(crate) async fn connect_with_timeout<Stream>(
+    connect_timeout: Duration,
+    connect: impl Future<Output = std::io::Result<Stream>>,
+) -> Result<Stream, NetworkTaskError> {
+    tokio::time::timeout(connect_timeout, connect)
+        .await
+        .map_err(|_| NetworkTaskError::ConnectTimeout)?
+        .map_err(|_| NetworkTaskError::FailedToConnect)
+}
+
+pub(crate) trait ServerStream: AsyncRead + AsyncWrite + Unpin {}
+
+impl<T> ServerStream for T where T: AsyncRead + AsyncWrite + Unpin {}
+
 pub(crate) enum ServerConnectCommand {
     Login {
-        address: SocketAddr,
+        target: ConnectionTarget,
+        timeouts: ServerTimeouts,
+        packet_version: PacketVersion,
+        packet_lengths: PacketLengthTable,
+        statistics: StatisticsCollector,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        error_sender: oneshot::Sender<NetworkTaskError>,
     },
     Character {
-        address: SocketAddr,
+        target: ConnectionTarget,
+        timeouts: ServerTimeouts,
+        packet_version: PacketVersion,
+        packet_lengths: PacketLengthTable,
+        statistics: StatisticsCollector,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        error_sender: oneshot::Sender<NetworkTaskError>,
     },
     Map {
-        address: SocketAddr,
+        target: ConnectionTarget,
+        timeouts: ServerTimeouts,
+        packet_version: PacketVersion,
+        packet_lengths: PacketLengthTable,
+        statistics: StatisticsCollector,
         action_receiver: UnboundedReceiver<Vec<u8>>,
         event_sender: UnboundedSender<NetworkEvent>,
+        error_sender: oneshot::Sender<NetworkTaskError>,
     },
 }
 
-#[derive(Debug)]
-pub(crate) enum NetworkTaskError {
+/// Timeouts for the connection to a single server.
+#[derive(Debug, Clone, Copy)]
+pub struct ServerTimeouts {
+    /// Time to establish the TCP connection.
+    pub connect: Duration,
+    /// Time between connecting and receiving the first data from the server.
+    pub handshake: Duration,
+    /// Time without receiving any data before the connection is considered
+    /// dead. `None` for servers that don't answer keep-alive packets.
+    pub idle: Option<Duration>,
+}
+
+/// Timeouts for the connections to all three servers.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkTimeouts {
+    pub login_server: ServerTimeouts,
+    pub character_server: ServerTimeouts,
+    pub map_server: ServerTimeouts,
+}
+
+impl Default for NetworkTimeouts {
+    fn default() -> Self {
+        Self {
+            // Neither the login server nor the character server answer keep-alive packets, so
+            // we can't detect an idle connection.
+            login_server: ServerTimeouts {
+                connect: Duration::from_secs(10),
+                handshake: Duration::from_secs(10),
+                idle: None,
+            },
+            character_server: ServerTimeouts {
+                connect: Duration::from_secs(10),
+                handshake: Duration::from_secs(10),
+                idle: None,
+            },
+            map_server: ServerTimeouts {
+                connect: Duration::from_secs(10),
+                handshake: Duration::from_secs(10),
+                idle: Some(Duration::from_secs(20)),
+            },
+        }
+    }
+}
+
+/// Reason a connection to one of the servers ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkTaskError {
+    /// The server refused the connection or could not be reached.
     FailedToConnect,
+    /// The TCP connection could not be established within the connect
+    /// timeout.
+    ConnectTimeout,
+    /// The server accepted the connection but didn't send anything within
+    /// the handshake timeout.
+    HandshakeTimeout,
+    /// The server didn't send anything within the idle timeout.
+    IdleTimeout,
+    /// The connection was closed by the server or failed while sending or
+    /// receiving.
     ConnectionClosed,
 }
 
+impl Display for NetworkTaskError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            NetworkTaskError::FailedToConnect => "failed to connect to the server",
+            NetworkTaskError::ConnectTimeout => "timed out while connecting to the server",
+            NetworkTaskError::HandshakeTimeout => "the server did not respond after connecting",
+            NetworkTaskError::IdleTimeout => "the server stopped responding",
+            NetworkTaskError::ConnectionClosed => "the connection was closed",
+        };
+
+        formatter.write_str(message)
+    }
+}
+
+impl std::error::Error for NetworkTaskError {}
+
 #[derive(Debug)]
 pub struct NotConnectedError;
 
@@ -73,6 +189,7 @@ pub(crate) enum ServerConnection {
     Connected {
         action_sender: UnboundedSender<Vec<u8>>,
         event_receiver: UnboundedReceiver<NetworkEvent>,
+        error_receiver: oneshot::Receiver<NetworkTaskError>,
     },
     ClosingManually,
     Disconnected,
@@ -0,0 +1,579 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::handler::PacketCallback;
+use ragnarok_packets::*;
+
+use crate::{
+    DisconnectReason, EntityData, InventoryItem, InventoryItemDetails, LoginServerLoginData, MessageColor, NetworkEvent, NetworkingSystem,
+    NoMetadata, NotConnectedError,
+};
+
+/// How often the async functions of [`Session`] poll the networking system.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The character that a [`Session`] should log in with.
+#[derive(Debug, Clone)]
+pub enum CharacterSelection {
+    Name(String),
+    Slot(usize),
+}
+
+/// Everything a [`Session`] needs to log in without any user interaction.
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    pub login_server: SocketAddr,
+    pub username: String,
+    pub password: String,
+    /// Index into the list of character servers sent by the login server.
+    pub character_server: usize,
+    pub character: CharacterSelection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionError {
+    LoginFailed(&'static str),
+    CharacterServerNotFound,
+    CharacterServerConnectionFailed(&'static str),
+    CharacterNotFound,
+    CharacterSelectionFailed(&'static str),
+    Disconnected(DisconnectReason),
+    Timeout,
+}
+
+impl Display for SessionError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::LoginFailed(message) => write!(formatter, "failed to log in: {message}"),
+            SessionError::CharacterServerNotFound => write!(formatter, "character server not found"),
+            SessionError::CharacterServerConnectionFailed(message) => {
+                write!(formatter, "failed to connect to character server: {message}")
+            }
+            SessionError::CharacterNotFound => write!(formatter, "character not found"),
+            SessionError::CharacterSelectionFailed(message) => write!(formatter, "failed to select character: {message}"),
+            SessionError::Disconnected(DisconnectReason::ClosedByClient) => write!(formatter, "disconnected by the client"),
+            SessionError::Disconnected(DisconnectReason::ConnectionError(error)) => write!(formatter, "disconnected: {error}"),
+            SessionError::Timeout => write!(formatter, "timed out"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionState {
+    Disconnected,
+    LoggingIn,
+    SelectingCharacter,
+    /// Connected to the map server but the player is not spawned yet.
+    EnteringMap,
+    InGame,
+    Failed(SessionError),
+}
+
+/// An entity around the player.
+#[derive(Debug, Clone)]
+pub struct SessionEntity {
+    pub entity_id: EntityId,
+    pub job: u16,
+    /// Only known after requesting the details of the entity.
+    pub name: Option<String>,
+    pub position: WorldPosition,
+    pub destination: Option<WorldPosition>,
+    pub health_points: i32,
+    pub maximum_health_points: i32,
+}
+
+impl From<&EntityData> for SessionEntity {
+    fn from(entity_data: &EntityData) -> Self {
+        Self {
+            entity_id: entity_data.entity_id,
+            job: entity_data.job,
+            name: None,
+            position: entity_data.position,
+            destination: entity_data.destination,
+            health_points: entity_data.health_points,
+            maximum_health_points: entity_data.maximum_health_points,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionPlayer {
+    pub account_id: AccountId,
+    pub character: CharacterInformation,
+    pub map_name: String,
+    pub position: WorldPosition,
+    /// Where the player is walking to, if the player is moving.
+    pub destination: Option<WorldPosition>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ChatEntry {
+    Message { text: String, color: MessageColor },
+    Whisper { sender_name: String, message: String },
+}
+
+/// Headless client that logs in and keeps track of the state of the world
+/// based on the events of the [`NetworkingSystem`]. Meant for scripted clients
+/// like bots and load tests.
+///
+/// The session needs to be updated regularly, either by calling
+/// [`update`](Self::update) or by awaiting one of the async functions.
+pub struct Session<Callback> {
+    networking_system: NetworkingSystem<Callback>,
+    config: SessionConfig,
+    state: SessionState,
+    login_data: Option<LoginServerLoginData>,
+    characters: Vec<CharacterInformation>,
+    player: Option<SessionPlayer>,
+    entities: HashMap<EntityId, SessionEntity>,
+    inventory: Vec<InventoryItem<NoMetadata>>,
+    skills: Vec<SkillInformation>,
+    chat_messages: Vec<ChatEntry>,
+}
+
+impl<Callback> Session<Callback>
+where
+    Callback: PacketCallback + Send,
+{
+    pub fn new(networking_system: NetworkingSystem<Callback>, config: SessionConfig) -> Self {
+        Self {
+            networking_system,
+            config,
+            state: SessionState::Disconnected,
+            login_data: None,
+            characters: Vec::new(),
+            player: None,
+            entities: HashMap::new(),
+            inventory: Vec::new(),
+            skills: Vec::new(),
+            chat_messages: Vec::new(),
+        }
+    }
+
+    /// Start logging in. The rest of the login happens while updating the
+    /// session.
+    pub fn connect(&mut self) {
+        self.networking_system
+            .connect_to_login_server(self.config.login_server, &self.config.username, &self.config.password);
+        self.state = SessionState::LoggingIn;
+    }
+
+    /// Process all pending events. The events are returned so scripts can
+    /// react to anything the session doesn't track.
+    pub fn update(&mut self) -> Vec<NetworkEvent> {
+        let events = self.networking_system.get_events();
+
+        for event in &events {
+            self.process_event(event);
+        }
+
+        events
+    }
+
+    /// Wait until at least one event was received.
+    pub async fn next_events(&mut self) -> Vec<NetworkEvent> {
+        loop {
+            let events = self.update();
+
+            if !events.is_empty() {
+                return events;
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Update the session until `condition` is true. Fails if the session
+    /// fails or `timeout` expires first.
+    pub async fn wait_until(&mut self, timeout: Duration, mut condition: impl FnMut(&Self) -> bool) -> Result<(), SessionError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            self.update();
+
+            if condition(self) {
+                return Ok(());
+            }
+
+            if let SessionState::Failed(error) = self.state {
+                return Err(error);
+            }
+
+            if Instant::now() >= deadline {
+                return Err(SessionError::Timeout);
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn wait_until_in_game(&mut self, timeout: Duration) -> Result<(), SessionError> {
+        self.wait_until(timeout, |session| session.state == SessionState::InGame).await
+    }
+
+    fn fail(&mut self, error: SessionError) {
+        self.networking_system.disconnect_from_login_server();
+        self.networking_system.disconnect_from_character_server();
+        self.networking_system.disconnect_from_map_server();
+        self.state = SessionState::Failed(error);
+    }
+
+    fn process_event(&mut self, event: &NetworkEvent) {
+        match event {
+            NetworkEvent::LoginServerConnected {
+                character_servers,
+                login_data,
+            } => {
+                self.networking_system.disconnect_from_login_server();
+
+                let Some(character_server) = character_servers.get(self.config.character_server) else {
+                    self.fail(SessionError::CharacterServerNotFound);
+                    return;
+                };
+
+                self.networking_system
+                    .connect_to_character_server(login_data, character_server.clone());
+                self.login_data = Some(*login_data);
+                self.state = SessionState::SelectingCharacter;
+            }
+            NetworkEvent::LoginServerConnectionFailed { message, .. } => self.fail(SessionError::LoginFailed(message)),
+            NetworkEvent::CharacterServerConnected { .. } => {
+                let _ = self.networking_system.request_character_list();
+            }
+            NetworkEvent::CharacterServerConnectionFailed { message, .. } => {
+                self.fail(SessionError::CharacterServerConnectionFailed(message))
+            }
+            NetworkEvent::CharacterList { characters } => {
+                let character = characters.iter().find(|character| match &self.config.character {
+                    CharacterSelection::Name(name) => character.name == *name,
+                    CharacterSelection::Slot(slot) => character.character_number as usize == *slot,
+                });
+
+                let Some(character) = character else {
+                    self.fail(SessionError::CharacterNotFound);
+                    return;
+                };
+
+                let _ = self.networking_system.select_character(character.character_number as usize);
+                self.characters = characters.clone();
+            }
+            NetworkEvent::CharacterSelectionFailed { message, .. } => self.fail(SessionError::CharacterSelectionFailed(message)),
+            NetworkEvent::CharacterSelected { login_data, map_name } => {
+                let Some(login_server_login_data) = self.login_data else {
+                    return;
+                };
+
+                self.networking_system.disconnect_from_character_server();
+                self.networking_system.connect_to_map_server(&login_server_login_data, *login_data);
+                let _ = self.networking_system.map_loaded();
+
+                self.player = self
+                    .characters
+                    .iter()
+                    .find(|character| character.character_id == login_data.character_id)
+                    .map(|character| SessionPlayer {
+                        account_id: login_server_login_data.account_id,
+                        character: character.clone(),
+                        map_name: map_name.clone(),
+                        position: WorldPosition::new(0, 0),
+                        destination: None,
+                    });
+                self.entities.clear();
+                self.state = SessionState::EnteringMap;
+            }
+            NetworkEvent::LoginServerDisconnected {
+                reason: reason @ DisconnectReason::ConnectionError(..),
+            } if self.state == SessionState::LoggingIn => self.fail(SessionError::Disconnected(*reason)),
+            NetworkEvent::CharacterServerDisconnected {
+                reason: reason @ DisconnectReason::ConnectionError(..),
+            } if self.state == SessionState::SelectingCharacter => self.fail(SessionError::Disconnected(*reason)),
+            NetworkEvent::MapServerDisconnected {
+                reason: reason @ DisconnectReason::ConnectionError(..),
+            } if matches!(self.state, SessionState::EnteringMap | SessionState::InGame) => self.fail(SessionError::Disconnected(*reason)),
+            NetworkEvent::MapServerReconnecting { .. } => {
+                self.entities.clear();
+                self.state = SessionState::EnteringMap;
+            }
+            NetworkEvent::MapServerReconnected => {
+                let _ = self.networking_system.map_loaded();
+            }
+            NetworkEvent::LoggedOut => {
+                self.networking_system.disconnect_from_map_server();
+                self.player = None;
+                self.entities.clear();
+                self.state = SessionState::Disconnected;
+            }
+            NetworkEvent::SetPlayerPosition(position) => {
+                if let Some(player) = &mut self.player {
+                    player.position = *position;
+                    player.destination = None;
+                }
+
+                if self.state == SessionState::EnteringMap {
+                    self.state = SessionState::InGame;
+                }
+            }
+            NetworkEvent::PlayerMove(origin, destination, _) => {
+                if let Some(player) = &mut self.player {
+                    player.position = *origin;
+                    player.destination = Some(*destination);
+                }
+            }
+            NetworkEvent::ChangeMap(map_name, position) => {
+                if let Some(player) = &mut self.player {
+                    player.map_name = map_name.clone();
+                    player.position = WorldPosition::new(position.x as usize, position.y as usize);
+                    player.destination = None;
+                }
+
+                self.entities.clear();
+                let _ = self.networking_system.map_loaded();
+            }
+            NetworkEvent::AddEntity(entity_data) => {
+                self.entities.insert(entity_data.entity_id, SessionEntity::from(entity_data));
+            }
+            NetworkEvent::RemoveEntity(entity_id) => {
+                self.entities.remove(entity_id);
+            }
+            NetworkEvent::EntityMove(entity_id, origin, destination, _) => {
+                if let Some(entity) = self.entities.get_mut(entity_id) {
+                    entity.position = *origin;
+                    entity.destination = Some(*destination);
+                }
+            }
+            NetworkEvent::UpdateEntityDetails(entity_id, name) => {
+                if let Some(entity) = self.entities.get_mut(entity_id) {
+                    entity.name = Some(name.clone());
+                }
+            }
+            NetworkEvent::UpdateEntityHealth(entity_id, health_points, maximum_health_points) => {
+                if let Some(entity) = self.entities.get_mut(entity_id) {
+                    entity.health_points = *health_points as i32;
+                    entity.maximum_health_points = *maximum_health_points as i32;
+                }
+            }
+            NetworkEvent::SetInventory { items } => self.inventory = items.clone(),
+            NetworkEvent::IventoryItemAdded { item } => {
+                let existing_item = self.inventory.iter_mut().find(|inventory_item| inventory_item.index == item.index);
+
+                match (existing_item, &item.details) {
+                    (
+                        Some(InventoryItem {
+                            details: InventoryItemDetails::Regular { amount, .. },
+                            ..
+                        }),
+                        InventoryItemDetails::Regular { amount: added_amount, .. },
+                    ) => *amount += added_amount,
+                    (Some(existing_item), _) => *existing_item = item.clone(),
+                    (None, _) => self.inventory.push(item.clone()),
+                }
+            }
+            NetworkEvent::InventoryItemRemoved { index, amount, .. } => {
+                let Some(position) = self.inventory.iter().position(|item| item.index == *index) else {
+                    return;
+                };
+
+                if let InventoryItemDetails::Regular { amount: item_amount, .. } = &mut self.inventory[position].details {
+                    if *item_amount > *amount {
+                        *item_amount -= amount;
+                        return;
+                    }
+                }
+
+                self.inventory.remove(position);
+            }
+            NetworkEvent::UpdateEquippedPosition { index, equipped_position } => {
+                if let Some(item) = self.inventory.iter_mut().find(|item| item.index == *index) {
+                    match &mut item.details {
+                        InventoryItemDetails::Regular {
+                            equipped_position: position,
+                            ..
+                        }
+                        | InventoryItemDetails::Equippable {
+                            equipped_position: position,
+                            ..
+                        } => *position = *equipped_position,
+                    }
+                }
+            }
+            NetworkEvent::SkillTree(skills) => self.skills = skills.clone(),
+            NetworkEvent::ChatMessage { text, color } => self.chat_messages.push(ChatEntry::Message {
+                text: text.clone(),
+                color: *color,
+            }),
+            NetworkEvent::WhisperReceived { sender_name, message } => self.chat_messages.push(ChatEntry::Whisper {
+                sender_name: sender_name.clone(),
+                message: message.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// The player character. `None` until a character was selected.
+    pub fn player(&self) -> Option<&SessionPlayer> {
+        self.player.as_ref()
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = &SessionEntity> {
+        self.entities.values()
+    }
+
+    pub fn entity(&self, entity_id: EntityId) -> Option<&SessionEntity> {
+        self.entities.get(&entity_id)
+    }
+
+    pub fn inventory(&self) -> &[InventoryItem<NoMetadata>] {
+        &self.inventory
+    }
+
+    pub fn skills(&self) -> &[SkillInformation] {
+        &self.skills
+    }
+
+    /// Take all chat messages that were received since the last call.
+    pub fn take_chat_messages(&mut self) -> Vec<ChatEntry> {
+        std::mem::take(&mut self.chat_messages)
+    }
+
+    /// Access the underlying networking system to send requests that the
+    /// session has no shorthand for.
+    pub fn networking_system(&mut self) -> &mut NetworkingSystem<Callback> {
+        &mut self.networking_system
+    }
+
+    pub fn walk_to(&mut self, position: WorldPosition) -> Result<(), NotConnectedError> {
+        self.networking_system.player_move(position)
+    }
+
+    pub fn send_chat_message(&mut self, message: &str) -> Result<(), NotConnectedError> {
+        let player = self.player.as_ref().ok_or(NotConnectedError)?;
+        self.networking_system.send_chat_message(&player.character.name, message)
+    }
+}
+
+#[cfg(test)]
+mod tracking {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_packets::*;
+
+    use super::{CharacterSelection, Session, SessionConfig, SessionError, SessionState};
+    use crate::{EntityData, InventoryItem, InventoryItemDetails, NetworkEvent, NetworkingSystem, NoMetadata};
+
+    fn session() -> Session<NoPacketCallback> {
+        let config = SessionConfig {
+            login_server: SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 6900),
+            username: "username".to_owned(),
+            password: "password".to_owned(),
+            character_server: 0,
+            character: CharacterSelection::Name("character".to_owned()),
+        };
+
+        Session::new(NetworkingSystem::spawn(), config)
+    }
+
+    fn regular_item(index: u16, amount: u16) -> InventoryItem<NoMetadata> {
+        InventoryItem {
+            metadata: NoMetadata,
+            index: InventoryIndex(index),
+            item_id: ItemId(501),
+            item_type: 0,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            details: InventoryItemDetails::Regular {
+                amount,
+                equipped_position: EquipPosition::empty(),
+                flags: RegularItemFlags::IDENTIFIED,
+            },
+        }
+    }
+
+    fn amount(session: &Session<NoPacketCallback>, index: u16) -> Option<u16> {
+        session
+            .inventory()
+            .iter()
+            .find(|item| item.index == InventoryIndex(index))
+            .map(|item| match item.details {
+                InventoryItemDetails::Regular { amount, .. } => amount,
+                InventoryItemDetails::Equippable { .. } => 1,
+            })
+    }
+
+    #[test]
+    fn entities() {
+        let mut session = session();
+        let entity_id = EntityId(2000);
+
+        session.process_event(&NetworkEvent::AddEntity(EntityData {
+            entity_id,
+            movement_speed: 150,
+            job: 1002,
+            position: WorldPosition::new(10, 10),
+            destination: None,
+            health_points: 50,
+            maximum_health_points: 50,
+            head_direction: 0,
+            sex: Sex::Male,
+        }));
+        session.process_event(&NetworkEvent::EntityMove(
+            entity_id,
+            WorldPosition::new(10, 10),
+            WorldPosition::new(12, 14),
+            ClientTick(0),
+        ));
+        session.process_event(&NetworkEvent::UpdateEntityDetails(entity_id, "Poring".to_owned()));
+        session.process_event(&NetworkEvent::UpdateEntityHealth(entity_id, 20, 50));
+
+        let entity = session.entity(entity_id).unwrap();
+        assert_eq!(entity.name.as_deref(), Some("Poring"));
+        assert_eq!(entity.health_points, 20);
+        assert!(matches!(entity.destination, Some(WorldPosition { x: 12, y: 14 })));
+
+        session.process_event(&NetworkEvent::RemoveEntity(entity_id));
+        assert_eq!(session.entities().count(), 0);
+    }
+
+    #[test]
+    fn inventory() {
+        let mut session = session();
+
+        session.process_event(&NetworkEvent::SetInventory {
+            items: vec![regular_item(2, 5), regular_item(3, 1)],
+        });
+        session.process_event(&NetworkEvent::IventoryItemAdded { item: regular_item(2, 3) });
+        session.process_event(&NetworkEvent::IventoryItemAdded { item: regular_item(4, 1) });
+        assert_eq!(amount(&session, 2), Some(8));
+        assert_eq!(amount(&session, 4), Some(1));
+
+        session.process_event(&NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: InventoryIndex(2),
+            amount: 6,
+        });
+        session.process_event(&NetworkEvent::InventoryItemRemoved {
+            reason: RemoveItemReason::Normal,
+            index: InventoryIndex(3),
+            amount: 1,
+        });
+        assert_eq!(amount(&session, 2), Some(2));
+        assert_eq!(amount(&session, 3), None);
+    }
+
+    #[test]
+    fn missing_character() {
+        let mut session = session();
+
+        session.process_event(&NetworkEvent::CharacterList { characters: Vec::new() });
+
+        assert_eq!(session.state(), SessionState::Failed(SessionError::CharacterNotFound));
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ragnarok_packets::{PacketHeader, ServerType};
+
+/// Number of samples kept in [`ServerStatistics::history`].
+pub const STATISTICS_HISTORY_LENGTH: usize = 60;
+/// Time covered by a single [`TrafficSample`].
+pub const STATISTICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Traffic of a single server during one [`STATISTICS_SAMPLE_INTERVAL`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrafficSample {
+    pub bytes_received: u64,
+    pub bytes_sent: u64,
+    pub packets_received: u64,
+    pub packets_sent: u64,
+    /// Round-trip time of the last ping that was answered during this sample.
+    pub latency: Option<Duration>,
+}
+
+/// Traffic and latency of the connections to a single server. Totals are
+/// kept across reconnects until they are reset.
+#[derive(Debug, Clone, Default)]
+pub struct ServerStatistics {
+    pub bytes_received: u64,
+    pub bytes_sent: u64,
+    pub packets_received: u64,
+    pub packets_sent: u64,
+    /// Number of packets received per header.
+    pub received_packets: HashMap<PacketHeader, u64>,
+    /// Number of packets sent per header.
+    pub sent_packets: HashMap<PacketHeader, u64>,
+    /// Round-trip time of the last answered ping. Only the map server answers
+    /// pings, so this is always `None` for the other servers.
+    pub latency: Option<Duration>,
+    /// Completed samples, oldest first.
+    pub history: VecDeque<TrafficSample>,
+    current_sample: TrafficSample,
+    sample_start: Option<Instant>,
+}
+
+impl ServerStatistics {
+    /// Move all samples that ended before `now` to the history. Intervals
+    /// without any traffic are added as empty samples.
+    fn advance(&mut self, now: Instant) {
+        let Some(sample_start) = self.sample_start else {
+            self.sample_start = Some(now);
+            return;
+        };
+
+        let completed_samples = (now.saturating_duration_since(sample_start).as_millis() / STATISTICS_SAMPLE_INTERVAL.as_millis()) as u32;
+
+        if completed_samples == 0 {
+            return;
+        }
+
+        let current_sample = std::mem::take(&mut self.current_sample);
+        self.push_sample(current_sample);
+
+        for _ in 1..completed_samples.min(STATISTICS_HISTORY_LENGTH as u32) {
+            self.push_sample(TrafficSample::default());
+        }
+
+        self.sample_start = Some(sample_start + STATISTICS_SAMPLE_INTERVAL * completed_samples);
+    }
+
+    fn push_sample(&mut self, sample: TrafficSample) {
+        if self.history.len() == STATISTICS_HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+
+        self.history.push_back(sample);
+    }
+
+    fn record_received_bytes(&mut self, byte_count: usize, now: Instant) {
+        self.advance(now);
+        self.bytes_received += byte_count as u64;
+        self.current_sample.bytes_received += byte_count as u64;
+    }
+
+    fn record_received_packet(&mut self, header: PacketHeader, now: Instant) {
+        self.advance(now);
+        self.packets_received += 1;
+        self.current_sample.packets_received += 1;
+        *self.received_packets.entry(header).or_default() += 1;
+    }
+
+    fn record_sent_packet(&mut self, header: PacketHeader, byte_count: usize, now: Instant) {
+        self.advance(now);
+        self.bytes_sent += byte_count as u64;
+        self.packets_sent += 1;
+        self.current_sample.bytes_sent += byte_count as u64;
+        self.current_sample.packets_sent += 1;
+        *self.sent_packets.entry(header).or_default() += 1;
+    }
+
+    fn record_latency(&mut self, latency: Duration, now: Instant) {
+        self.advance(now);
+        self.latency = Some(latency);
+        self.current_sample.latency = Some(latency);
+    }
+}
+
+/// Statistics for the connections to all three servers.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkStatistics {
+    pub login_server: ServerStatistics,
+    pub character_server: ServerStatistics,
+    pub map_server: ServerStatistics,
+}
+
+impl NetworkStatistics {
+    pub fn server(&self, server_type: ServerType) -> &ServerStatistics {
+        match server_type {
+            ServerType::Login => &self.login_server,
+            ServerType::Character => &self.character_server,
+            ServerType::Map => &self.map_server,
+        }
+    }
+
+    fn server_mut(&mut self, server_type: ServerType) -> &mut ServerStatistics {
+        match server_type {
+            ServerType::Login => &mut self.login_server,
+            ServerType::Character => &mut self.character_server,
+            ServerType::Map => &mut self.map_server,
+        }
+    }
+}
+
+/// Shared access to the statistics that the networking thread collects. Can
+/// be cloned and handed to code that doesn't own the
+/// [`NetworkingSystem`](crate::NetworkingSystem), like debug windows.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkStatisticsHandle {
+    statistics: Arc<Mutex<NetworkStatistics>>,
+}
+
+impl NetworkStatisticsHandle {
+    /// Get a snapshot of the current statistics.
+    pub fn get(&self) -> NetworkStatistics {
+        let mut statistics = self.statistics.lock().unwrap();
+        let now = Instant::now();
+
+        statistics.login_server.advance(now);
+        statistics.character_server.advance(now);
+        statistics.map_server.advance(now);
+
+        statistics.clone()
+    }
+
+    pub fn reset(&self) {
+        *self.statistics.lock().unwrap() = NetworkStatistics::default();
+    }
+
+    pub(crate) fn collector(&self, server_type: ServerType) -> StatisticsCollector {
+        StatisticsCollector {
+            statistics: self.statistics.clone(),
+            server_type,
+        }
+    }
+}
+
+/// Records the traffic of a single server connection.
+#[derive(Clone)]
+pub(crate) struct StatisticsCollector {
+    statistics: Arc<Mutex<NetworkStatistics>>,
+    server_type: ServerType,
+}
+
+impl StatisticsCollector {
+    fn with_server(&self, f: impl FnOnce(&mut ServerStatistics, Instant)) {
+        let mut statistics = self.statistics.lock().unwrap();
+        f(statistics.server_mut(self.server_type), Instant::now());
+    }
+
+    pub fn bytes_received(&self, byte_count: usize) {
+        self.with_server(|statistics, now| statistics.record_received_bytes(byte_count, now));
+    }
+
+    /// Record a packet that was received from the server. `bytes` starts at
+    /// the packet header.
+    pub fn packet_received(&self, bytes: &[u8]) {
+        if let Some(header) = Self::header(bytes) {
+            self.with_server(|statistics, now| statistics.record_received_packet(header, now));
+        }
+    }
+
+    /// Record a packet that was sent to the server.
+    pub fn packet_sent(&self, bytes: &[u8]) {
+        if let Some(header) = Self::header(bytes) {
+            self.with_server(|statistics, now| statistics.record_sent_packet(header, bytes.len(), now));
+        }
+    }
+
+    /// Record the round-trip time of an answered ping.
+    pub fn latency_measured(&self, latency: Duration) {
+        self.with_server(|statistics, now| statistics.record_latency(latency, now));
+    }
+
+    fn header(bytes: &[u8]) -> Option<PacketHeader> {
+        match bytes {
+            [low, high, ..] => Some(PacketHeader(u16::from_le_bytes([*low, *high]))),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod history {
+    use std::time::{Duration, Instant};
+
+    use super::{ServerStatistics, TrafficSample, STATISTICS_HISTORY_LENGTH, STATISTICS_SAMPLE_INTERVAL};
+
+    #[test]
+    fn rolling_samples() {
+        let start = Instant::now();
+        let mut statistics = ServerStatistics::default();
+
+        statistics.record_received_bytes(100, start);
+        statistics.record_received_bytes(50, start + Duration::from_millis(500));
+        statistics.record_received_bytes(20, start + STATISTICS_SAMPLE_INTERVAL * 3);
+        statistics.advance(start + STATISTICS_SAMPLE_INTERVAL * 4);
+
+        let received: Vec<u64> = statistics.history.iter().map(|sample| sample.bytes_received).collect();
+        assert_eq!(received, [150, 0, 0, 20]);
+        assert_eq!(statistics.bytes_received, 170);
+
+        statistics.advance(start + STATISTICS_SAMPLE_INTERVAL * 1000);
+        assert_eq!(statistics.history.len(), STATISTICS_HISTORY_LENGTH);
+        assert!(statistics.history.iter().all(|sample| *sample == TrafficSample::default()));
+    }
+
+    #[test]
+    fn latency() {
+        let start = Instant::now();
+        let mut statistics = ServerStatistics::default();
+
+        statistics.record_latency(Duration::from_millis(80), start);
+        statistics.record_latency(Duration::from_millis(60), start + STATISTICS_SAMPLE_INTERVAL);
+        statistics.advance(start + STATISTICS_SAMPLE_INTERVAL * 2);
+
+        assert_eq!(statistics.latency, Some(Duration::from_millis(60)));
+
+        let latencies: Vec<_> = statistics.history.iter().map(|sample| sample.latency).collect();
+        assert_eq!(latencies, [Some(Duration::from_millis(80)), Some(Duration::from_millis(60))]);
+    }
+}
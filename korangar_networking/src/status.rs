@@ -0,0 +1,76 @@
+use ragnarok_packets::*;
+
+/// A status effect (buff or debuff) that was applied to an entity.
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub status_id: u16,
+    /// Total duration of the effect in milliseconds. `None` if the effect does
+    /// not expire on its own.
+    pub total_duration: Option<u32>,
+    /// Remaining duration of the effect in milliseconds at the time the packet
+    /// was received.
+    pub remaining_duration: Option<u32>,
+    pub values: [u32; 3],
+}
+
+impl From<StatusChangePacket> for StatusEffect {
+    fn from(packet: StatusChangePacket) -> Self {
+        // rAthena sends `-1` as the duration of effects that don't expire.
+        let (total_duration, remaining_duration) = match packet.duration_in_milliseconds {
+            0 | u32::MAX => (None, None),
+            duration => (Some(duration), Some(packet.remaining_in_milliseconds)),
+        };
+
+        Self {
+            status_id: packet.index,
+            total_duration,
+            remaining_duration,
+            values: packet.value,
+        }
+    }
+}
+
+impl From<StatusChangeSequencePacket> for StatusEffect {
+    fn from(packet: StatusChangeSequencePacket) -> Self {
+        Self {
+            status_id: packet.index,
+            total_duration: None,
+            remaining_duration: None,
+            values: [0; 3],
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use ragnarok_packets::{EntityId, StatusChangePacket};
+
+    use super::StatusEffect;
+
+    fn status_change(duration_in_milliseconds: u32, remaining_in_milliseconds: u32) -> StatusEffect {
+        StatusEffect::from(StatusChangePacket::new(
+            12,
+            EntityId(1),
+            1,
+            duration_in_milliseconds,
+            remaining_in_milliseconds,
+            [0; 3],
+        ))
+    }
+
+    #[test]
+    fn expiring_effect() {
+        let status_effect = status_change(10000, 4000);
+        assert_eq!(status_effect.total_duration, Some(10000));
+        assert_eq!(status_effect.remaining_duration, Some(4000));
+    }
+
+    #[test]
+    fn infinite_effect() {
+        for duration in [0, u32::MAX] {
+            let status_effect = status_change(duration, u32::MAX);
+            assert_eq!(status_effect.total_duration, None);
+            assert_eq!(status_effect.remaining_duration, None);
+        }
+    }
+}
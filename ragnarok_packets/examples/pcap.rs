@@ -174,6 +174,7 @@ fn main() {
 
     let mut client_login_handler = create_handler!(ServerType::Login, Direction::Incoming, [
         LoginServerLoginSuccessPacket,
+        LegacyLoginServerLoginSuccessPacket,
         LoginFailedPacket,
         LoginFailedPacket2
     ]);
@@ -187,15 +188,20 @@ fn main() {
         LoginFailedPacket,
         CharacterServerLoginSuccessPacket,
         RequestCharacterListSuccessPacket,
+        LegacyRequestCharacterListSuccessPacket,
         Packet006b,
         Packet0b18,
         CharacterSelectionSuccessPacket,
         CharacterSelectionFailedPacket,
         MapServerUnavailablePacket,
         CreateCharacterSuccessPacket,
+        LegacyCreateCharacterSuccessPacket,
         CharacterCreationFailedPacket,
         CharacterDeletionSuccessPacket,
         CharacterDeletionFailedPacket,
+        CharacterDeletionReservedPacket,
+        CharacterDeletionAcceptedPacket,
+        CharacterDeletionCanceledPacket,
         SwitchCharacterSlotResponsePacket,
     ]);
 
@@ -205,6 +211,9 @@ fn main() {
         SelectCharacterPacket,
         CreateCharacterPacket,
         DeleteCharacterPacket,
+        ReserveCharacterDeletionPacket,
+        AcceptCharacterDeletionPacket,
+        CancelCharacterDeletionPacket,
         SwitchCharacterSlotPacket,
     ]);
 
@@ -223,6 +232,9 @@ fn main() {
         EntityAppearedPacket,
         EntityAppeared2Packet,
         MovingEntityAppearedPacket,
+        LegacyEntityAppearedPacket,
+        LegacyEntityAppeared2Packet,
+        LegacyMovingEntityAppearedPacket,
         EntityDisappearedPacket,
         UpdateStatusPacket,
         UpdateStatusPacket1,
@@ -237,6 +249,8 @@ fn main() {
         InventoyStartPacket,
         RegularItemListPacket,
         EquippableItemListPacket,
+        LegacyRegularItemListPacket,
+        LegacyEquippableItemListPacket,
         InventoyEndPacket,
         EquippableSwitchItemListPacket,
         MapTypePacket,
@@ -269,6 +283,12 @@ fn main() {
         QuestEffectPacket,
         ItemPickupPacket,
         RemoveItemFromInventoryPacket,
+        UseItemSuccessPacket,
+        UseItemFailedPacket,
+        DropItemSuccessPacket,
+        GroundItemEntryPacket,
+        GroundItemFallPacket,
+        GroundItemDisappearPacket,
         ServerTickPacket,
         RequestPlayerDetailsSuccessPacket,
         RequestEntityDetailsSuccessPacket,
@@ -280,6 +300,7 @@ fn main() {
         RequestUnequipItemStatusPacket,
         Packet8302,
         Packet0b18,
+        LoginFailedPacket,
         MapServerLoginSuccessPacket,
         RestartResponsePacket,
         DisconnectResponsePacket,
@@ -317,12 +338,16 @@ fn main() {
         RequestDetailsPacket,
         RequestActionPacket,
         GlobalMessagePacket,
+        RequestEmotionPacket,
         StartDialogPacket,
         NextDialogPacket,
         CloseDialogPacket,
         ChooseDialogOptionPacket,
         RequestEquipItemPacket,
         RequestUnequipItemPacket,
+        UseItemPacket,
+        DropItemPacket,
+        PickUpItemPacket,
         UseSkillAtIdPacket,
         UseSkillOnGroundPacket,
         StartUseSkillPacket,
@@ -355,22 +380,28 @@ fn main() {
 
                     if source_port == LOGIN_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_login_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket = client_login_handler.process_one(&mut byte_stream) {
+                        }
                     } else if destination_port == LOGIN_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_login_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket = server_login_handler.process_one(&mut byte_stream) {
+                        }
                     } else if source_port == CHARACTER_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_character_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket =
+                            client_character_handler.process_one(&mut byte_stream)
+                        {}
                     } else if destination_port == CHARACTER_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_character_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket =
+                            server_character_handler.process_one(&mut byte_stream)
+                        {}
                     } else if source_port == MAP_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = client_map_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket = client_map_handler.process_one(&mut byte_stream) {}
                     } else if destination_port == MAP_SERVER_PORT {
                         let mut byte_stream = ByteStream::without_metadata(tcp_slice.payload());
-                        while let HandlerResult::Ok(_) = server_map_handler.process_one(&mut byte_stream) {}
+                        while let HandlerResult::Ok(_) | HandlerResult::SkippedPacket = server_map_handler.process_one(&mut byte_stream) {}
                     }
                 };
             }
@@ -2,13 +2,17 @@ use std::collections::HashMap;
 
 use ragnarok_bytes::{ByteStream, ConversionError, ConversionResult, FromBytes};
 
-use crate::PacketHeader;
+use crate::{PacketHeader, PacketLength, PacketLengthTable, PacketVersion, ServerType};
 
 /// Possible results of [`PacketHandler::process_one`].
 pub enum HandlerResult<Output> {
     /// Packet was successfully processed and produced some output.
     Ok(Output),
-    /// No packet handler was registered for the incoming packet.
+    /// No packet handler was registered for the incoming packet, but it was
+    /// skipped using the [`PacketLengthTable`].
+    SkippedPacket,
+    /// No packet handler was registered for the incoming packet and its
+    /// length is unknown.
     UnhandledPacket,
     /// Packet was most likely cut-off.
     PacketCutOff,
@@ -25,6 +29,13 @@ pub struct DuplicateHandlerError {
 
 /// Trait for monitoring the incoming and outgoing packets.
 pub trait PacketCallback: Clone + 'static {
+    /// Get the callback for the packets of a single server. Implementations
+    /// that need to tell the servers apart can store the server type here.
+    fn for_server(&self, server_type: ServerType) -> Self {
+        let _ = server_type;
+        self.clone()
+    }
+
     /// Called by the [`PacketHandler`] when a packet is received.
     fn incoming_packet<Packet>(&self, packet: &Packet)
     where
@@ -41,6 +52,21 @@ pub trait PacketCallback: Clone + 'static {
         let _ = packet;
     }
 
+    /// Called with the bytes of every packet that is received, exactly as the
+    /// server sent them. Unlike [`PacketCallback::incoming_packet`] this
+    /// includes packets that don't have a handler.
+    fn raw_incoming_packet(&self, bytes: &[u8]) {
+        let _ = bytes;
+    }
+
+    /// Called with the bytes of every packet that is sent, encoded for the
+    /// packet version of the client. These may differ from the bytes of
+    /// [`Packet::packet_to_bytes`](crate::Packet::packet_to_bytes)
+    /// if the packet has a legacy variant.
+    fn raw_outgoing_packet(&self, bytes: &[u8]) {
+        let _ = bytes;
+    }
+
     /// Called by the [`PacketHandler`] when a packet arrives that doesn't have
     /// a handler registered.
     fn unknown_packet(&self, bytes: Vec<u8>) {
@@ -64,11 +90,19 @@ pub type HandlerFunction<Output, Meta> = Box<dyn Fn(&mut ByteStream<Meta>) -> Co
 /// converting them to some common event type.
 ///
 /// It allows passing a packet callback to monitor incoming packets.
+///
+/// The handler is built for a single [`PacketVersion`]. Packets that are used
+/// by that version always take precedence. Packets of other versions are
+/// only used for headers that no packet of the current version claims, in
+/// which case the variant closest to the current version is used.
 pub struct PacketHandler<Output, Meta, Callback>
 where
     Meta: 'static,
 {
     handlers: HashMap<PacketHeader, HandlerFunction<Output, Meta>>,
+    fallback_handlers: HashMap<PacketHeader, (u32, HandlerFunction<Output, Meta>)>,
+    packet_version: PacketVersion,
+    packet_lengths: PacketLengthTable,
     packet_callback: Callback,
 }
 
@@ -80,6 +114,9 @@ where
     fn default() -> Self {
         Self {
             handlers: Default::default(),
+            fallback_handlers: Default::default(),
+            packet_version: Default::default(),
+            packet_lengths: Default::default(),
             packet_callback: Default::default(),
         }
     }
@@ -93,29 +130,54 @@ where
 {
     /// Create a new packet handler with a callback.
     pub fn with_callback(packet_callback: Callback) -> Self {
+        Self::with_version(PacketVersion::default(), packet_callback)
+    }
+
+    /// Create a new packet handler with a callback for a specific client date.
+    pub fn with_version(packet_version: PacketVersion, packet_callback: Callback) -> Self {
         Self {
             handlers: Default::default(),
+            fallback_handlers: Default::default(),
+            packet_version,
+            packet_lengths: PacketLengthTable::for_version(packet_version),
             packet_callback,
         }
     }
 
-    /// Register a new packet handler.
-    pub fn register<Packet, Return>(&mut self, handler: impl Fn(Packet) -> Return + 'static) -> Result<(), DuplicateHandlerError>
+    /// Set the lengths used to skip packets that don't have a handler. By
+    /// default the built-in table for the packet version is used.
+    pub fn set_packet_lengths(&mut self, packet_lengths: PacketLengthTable) {
+        self.packet_lengths = packet_lengths;
+    }
+
+    pub fn packet_version(&self) -> PacketVersion {
+        self.packet_version
+    }
+
+    pub fn packet_callback(&self) -> &Callback {
+        &self.packet_callback
+    }
+
+    fn insert_handler<Packet>(&mut self, handler: HandlerFunction<Output, Meta>) -> Result<(), DuplicateHandlerError>
     where
         Packet: ragnarok_packets::Packet,
-        Return: Into<Output>,
     {
-        let packet_callback = self.packet_callback.clone();
-        let old_handler = self.handlers.insert(
-            Packet::HEADER,
-            Box::new(move |byte_stream| {
-                let packet = Packet::payload_from_bytes(byte_stream)?;
+        let distance = Packet::VERSIONS.distance(self.packet_version);
 
-                packet_callback.incoming_packet(&packet);
+        if distance > 0 {
+            let is_closer = self
+                .fallback_handlers
+                .get(&Packet::HEADER)
+                .map_or(true, |(other_distance, _)| distance < *other_distance);
 
-                Ok(handler(packet).into())
-            }),
-        );
+            if is_closer {
+                self.fallback_handlers.insert(Packet::HEADER, (distance, handler));
+            }
+
+            return Ok(());
+        }
+
+        let old_handler = self.handlers.insert(Packet::HEADER, handler);
 
         match old_handler.is_some() {
             true => Err(DuplicateHandlerError {
@@ -125,29 +187,68 @@ where
         }
     }
 
+    /// Register a new packet handler.
+    pub fn register<Packet, Return>(&mut self, handler: impl Fn(Packet) -> Return + 'static) -> Result<(), DuplicateHandlerError>
+    where
+        Packet: ragnarok_packets::Packet,
+        Return: Into<Output>,
+    {
+        let packet_callback = self.packet_callback.clone();
+
+        self.insert_handler::<Packet>(Box::new(move |byte_stream| {
+            let packet = Packet::payload_from_bytes(byte_stream)?;
+
+            packet_callback.incoming_packet(&packet);
+
+            Ok(handler(packet).into())
+        }))
+    }
+
     /// Register a noop packet handler.
     pub fn register_noop<Packet>(&mut self) -> Result<(), DuplicateHandlerError>
     where
         Packet: ragnarok_packets::Packet,
     {
         let packet_callback = self.packet_callback.clone();
-        let old_handler = self.handlers.insert(
-            Packet::HEADER,
-            Box::new(move |byte_stream| {
-                let packet = Packet::payload_from_bytes(byte_stream)?;
 
-                packet_callback.incoming_packet(&packet);
+        self.insert_handler::<Packet>(Box::new(move |byte_stream| {
+            let packet = Packet::payload_from_bytes(byte_stream)?;
 
-                Ok(Output::default())
-            }),
-        );
+            packet_callback.incoming_packet(&packet);
 
-        match old_handler.is_some() {
-            true => Err(DuplicateHandlerError {
-                packet_header: Packet::HEADER,
-            }),
-            false => Ok(()),
-        }
+            Ok(Output::default())
+        }))
+    }
+
+    /// Skip a packet that doesn't have a handler. Returns `None` if the length
+    /// of a variable length packet is invalid.
+    fn skip_packet(&self, byte_stream: &mut ByteStream<Meta>, packet_length: PacketLength) -> Option<HandlerResult<Output>> {
+        let packet_length = match packet_length {
+            PacketLength::Fixed(packet_length) => packet_length,
+            PacketLength::Variable => {
+                let save_point = byte_stream.create_save_point();
+                let packet_length = byte_stream
+                    .slice::<Self>(4)
+                    .map(|bytes| u16::from_le_bytes([bytes[2], bytes[3]]) as usize);
+
+                byte_stream.restore_save_point(save_point);
+
+                match packet_length {
+                    Ok(packet_length) if packet_length < 4 => return None,
+                    Ok(packet_length) => packet_length,
+                    Err(..) => return Some(HandlerResult::PacketCutOff),
+                }
+            }
+        };
+
+        // The byte stream is not advanced if the slice fails.
+        let Ok(bytes) = byte_stream.slice::<Self>(packet_length) else {
+            return Some(HandlerResult::PacketCutOff);
+        };
+
+        self.packet_callback.unknown_packet(bytes.to_vec());
+
+        Some(HandlerResult::SkippedPacket)
     }
 
     /// Take a single packet from the byte stream.
@@ -160,9 +261,20 @@ where
             return HandlerResult::PacketCutOff;
         };
 
-        let Some(handler) = self.handlers.get(&header) else {
+        let handler = self
+            .handlers
+            .get(&header)
+            .or_else(|| self.fallback_handlers.get(&header).map(|(_, handler)| handler));
+
+        let Some(handler) = handler else {
             byte_stream.restore_save_point(save_point);
 
+            if let Some(packet_length) = self.packet_lengths.get(header) {
+                if let Some(result) = self.skip_packet(byte_stream, packet_length) {
+                    return result;
+                }
+            }
+
             self.packet_callback.unknown_packet(byte_stream.remaining_bytes());
 
             return HandlerResult::UnhandledPacket;
@@ -185,3 +297,166 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod versions {
+    use ragnarok_bytes::ByteStream;
+
+    use super::{HandlerResult, NoPacketCallback, PacketHandler};
+    use crate::*;
+
+    #[derive(Debug, Clone, Packet)]
+    #[header(0x0001)]
+    #[packet_version(until = 20170315)]
+    struct OldPacket {
+        value: u8,
+    }
+
+    #[derive(Debug, Clone, Packet, ClientPacket)]
+    #[header(0x0002)]
+    #[packet_version(since = 20170315)]
+    #[legacy_variant(ReusedHeaderPacket)]
+    struct NewPacket {
+        value: u16,
+    }
+
+    #[derive(Debug, Clone, Packet, ClientPacket)]
+    #[header(0x0002)]
+    #[packet_version(until = 20170315)]
+    struct ReusedHeaderPacket {
+        value: u8,
+    }
+
+    impl From<NewPacket> for ReusedHeaderPacket {
+        fn from(packet: NewPacket) -> Self {
+            Self { value: packet.value as u8 }
+        }
+    }
+
+    fn create_handler(packet_version: PacketVersion) -> PacketHandler<Vec<&'static str>, (), NoPacketCallback> {
+        let mut packet_handler = PacketHandler::with_version(packet_version, NoPacketCallback);
+
+        packet_handler.register(|_: OldPacket| vec!["old"]).unwrap();
+        packet_handler.register(|_: NewPacket| vec!["new"]).unwrap();
+        packet_handler.register(|_: ReusedHeaderPacket| vec!["reused"]).unwrap();
+        packet_handler
+    }
+
+    fn process(packet_handler: &mut PacketHandler<Vec<&'static str>, (), NoPacketCallback>, bytes: &[u8]) -> Vec<&'static str> {
+        let mut byte_stream = ByteStream::without_metadata(bytes);
+
+        match packet_handler.process_one(&mut byte_stream) {
+            HandlerResult::Ok(output) => output,
+            _ => panic!("failed to process packet"),
+        }
+    }
+
+    #[test]
+    fn version_range() {
+        let range = ReusedHeaderPacket::VERSIONS;
+
+        assert!(range.contains(PacketVersion(20150101)));
+        assert!(!range.contains(PacketVersion(20170315)));
+        assert!(NewPacket::VERSIONS.contains(PacketVersion::DEFAULT));
+    }
+
+    #[test]
+    fn current_version_takes_precedence() {
+        let mut new_handler = create_handler(PacketVersion::DEFAULT);
+        let mut old_handler = create_handler(PacketVersion(20150101));
+
+        assert_eq!(process(&mut new_handler, &[0x02, 0x00, 0x00, 0x00]), vec!["new"]);
+        assert_eq!(process(&mut old_handler, &[0x02, 0x00, 0x00]), vec!["reused"]);
+    }
+
+    #[test]
+    fn encode_legacy_variant() {
+        let packet = NewPacket { value: 7 };
+
+        assert_eq!(packet.versioned_packet_to_bytes(PacketVersion::DEFAULT).unwrap(), vec![
+            0x02, 0x00, 0x07, 0x00
+        ]);
+        assert_eq!(packet.versioned_packet_to_bytes(PacketVersion(20150101)).unwrap(), vec![
+            0x02, 0x00, 0x07
+        ]);
+    }
+
+    #[test]
+    fn fall_back_to_other_versions() {
+        let mut packet_handler = create_handler(PacketVersion::DEFAULT);
+
+        assert_eq!(process(&mut packet_handler, &[0x01, 0x00, 0x00]), vec!["old"]);
+    }
+}
+
+#[cfg(test)]
+mod unknown_packets {
+    use ragnarok_bytes::ByteStream;
+
+    use super::{HandlerResult, NoPacketCallback, PacketHandler};
+    use crate::*;
+
+    #[derive(Debug, Clone, Packet)]
+    #[header(0x0001)]
+    struct KnownPacket {
+        value: u8,
+    }
+
+    fn create_handler() -> PacketHandler<Vec<u8>, (), NoPacketCallback> {
+        let mut packet_lengths = PacketLengthTable::default();
+        packet_lengths.insert(PacketHeader(0x0002), PacketLength::Fixed(5));
+        packet_lengths.insert(PacketHeader(0x0003), PacketLength::Variable);
+
+        let mut packet_handler = PacketHandler::with_callback(NoPacketCallback);
+        packet_handler.register(|packet: KnownPacket| vec![packet.value]).unwrap();
+        packet_handler.set_packet_lengths(packet_lengths);
+        packet_handler
+    }
+
+    #[test]
+    fn skip_fixed_and_variable_length() {
+        let mut packet_handler = create_handler();
+        let bytes = [
+            0x02, 0x00, 0xAA, 0xBB, 0xCC, // fixed length
+            0x03, 0x00, 0x06, 0x00, 0xAA, 0xBB, // variable length
+            0x01, 0x00, 0x07, // known packet
+        ];
+        let mut byte_stream = ByteStream::without_metadata(&bytes);
+
+        assert!(matches!(
+            packet_handler.process_one(&mut byte_stream),
+            HandlerResult::SkippedPacket
+        ));
+        assert!(matches!(
+            packet_handler.process_one(&mut byte_stream),
+            HandlerResult::SkippedPacket
+        ));
+        assert!(matches!(packet_handler.process_one(&mut byte_stream), HandlerResult::Ok(output) if output == vec![0x07]));
+        assert!(byte_stream.is_empty());
+    }
+
+    #[test]
+    fn cut_off_unknown_packet() {
+        let mut packet_handler = create_handler();
+        let bytes = [0x02, 0x00, 0xAA];
+        let mut byte_stream = ByteStream::without_metadata(&bytes);
+
+        assert!(matches!(
+            packet_handler.process_one(&mut byte_stream),
+            HandlerResult::PacketCutOff
+        ));
+        assert_eq!(byte_stream.get_offset(), 0);
+    }
+
+    #[test]
+    fn unknown_length() {
+        let mut packet_handler = create_handler();
+        let bytes = [0x04, 0x00, 0xAA];
+        let mut byte_stream = ByteStream::without_metadata(&bytes);
+
+        assert!(matches!(
+            packet_handler.process_one(&mut byte_stream),
+            HandlerResult::UnhandledPacket
+        ));
+    }
+}
@@ -0,0 +1,529 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use crate::{Packet, PacketHeader, PacketVersion};
+
+/// Length of a packet in bytes, including the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketLength {
+    Fixed(usize),
+    /// The length is stored in the two bytes following the header.
+    Variable,
+}
+
+/// Error when loading a [`PacketLengthTable`].
+#[derive(Debug)]
+pub enum PacketLengthTableError {
+    Io(std::io::Error),
+    InvalidLine { line_number: usize, line: String },
+}
+
+impl Display for PacketLengthTableError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketLengthTableError::Io(error) => write!(formatter, "failed to read packet length table: {error}"),
+            PacketLengthTableError::InvalidLine { line_number, line } => {
+                write!(formatter, "invalid packet length in line {line_number}: {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PacketLengthTableError {}
+
+/// Lengths of packets by their header. Used to skip packets that don't have a
+/// handler without losing track of where the next packet starts.
+///
+/// The default table contains all packets that Korangar knows for the default
+/// client date. See [`PacketLengthTable::for_version`].
+#[derive(Debug, Clone)]
+pub struct PacketLengthTable {
+    lengths: HashMap<PacketHeader, PacketLength>,
+}
+
+impl Default for PacketLengthTable {
+    fn default() -> Self {
+        Self::for_version(PacketVersion::DEFAULT)
+    }
+}
+
+/// Calls the given macro with the names of all packets that Korangar knows.
+/// New packets need to be added here so their length is known when they are
+/// skipped.
+macro_rules! known_packets {
+    ($callback:ident) => {
+        $callback!(
+            LoginServerLoginPacket,
+            LoginServerLoginSuccessPacket,
+            LegacyLoginServerLoginSuccessPacket,
+            CharacterServerLoginSuccessPacket,
+            Packet006b,
+            Packet0b18,
+            MapServerLoginSuccessPacket,
+            LoginFailedPacket,
+            MapServerUnavailablePacket,
+            LoginFailedPacket2,
+            CharacterSelectionFailedPacket,
+            CharacterSelectionSuccessPacket,
+            CharacterCreationFailedPacket,
+            LoginServerKeepalivePacket,
+            CharacterServerLoginPacket,
+            MapServerLoginPacket,
+            LegacyMapServerLoginPacket,
+            Packet8302,
+            CreateCharacterPacket,
+            CreateCharacterSuccessPacket,
+            LegacyCreateCharacterSuccessPacket,
+            RequestCharacterListPacket,
+            RequestCharacterListSuccessPacket,
+            LegacyRequestCharacterListSuccessPacket,
+            MapServerPingPacket,
+            RequestPlayerMovePacket,
+            RequestWarpToMapPacket,
+            EntityMovePacket,
+            EntityStopMovePacket,
+            PlayerMovePacket,
+            DeleteCharacterPacket,
+            CharacterDeletionFailedPacket,
+            CharacterDeletionSuccessPacket,
+            ReserveCharacterDeletionPacket,
+            CharacterDeletionReservedPacket,
+            AcceptCharacterDeletionPacket,
+            CharacterDeletionAcceptedPacket,
+            CancelCharacterDeletionPacket,
+            CharacterDeletionCanceledPacket,
+            SelectCharacterPacket,
+            ServerMessagePacket,
+            RequestDetailsPacket,
+            RequestPlayerDetailsSuccessPacket,
+            RequestEntityDetailsSuccessPacket,
+            NewMailStatusPacket,
+            AchievementUpdatePacket,
+            AchievementListPacket,
+            CriticalWeightUpdatePacket,
+            SpriteChangePacket,
+            InventoyStartPacket,
+            InventoyEndPacket,
+            RegularItemListPacket,
+            LegacyRegularItemListPacket,
+            EquippableItemListPacket,
+            LegacyEquippableItemListPacket,
+            EquippableSwitchItemListPacket,
+            MapTypePacket,
+            Broadcast2MessagePacket,
+            BroadcastMessagePacket,
+            OverheadMessagePacket,
+            EntityMessagePacket,
+            DisplayEmotionPacket,
+            RequestEmotionPacket,
+            UpdateStatusPacket,
+            StatusChangeSequencePacket,
+            InitialStatusPacket,
+            UpdateStatusPacket1,
+            UpdateStatusPacket2,
+            UpdateStatusPacket3,
+            RequestStatusIncreasePacket,
+            StatusIncreaseResultPacket,
+            UpdateAttackRangePacket,
+            SwitchCharacterSlotPacket,
+            RequestActionPacket,
+            GlobalMessagePacket,
+            SendWhisperPacket,
+            ReceiveWhisperPacket,
+            WhisperResultPacket,
+            RequestPlayerAttackFailedPacket,
+            UpdateEntityHealthPointsPacket,
+            DamagePacket,
+            ServerTickPacket,
+            RequestServerTickPacket,
+            SwitchCharacterSlotResponsePacket,
+            ChangeMapPacket,
+            EntityDisappearedPacket,
+            MovingEntityAppearedPacket,
+            EntityAppearedPacket,
+            EntityAppeared2Packet,
+            LegacyMovingEntityAppearedPacket,
+            LegacyEntityAppearedPacket,
+            LegacyEntityAppeared2Packet,
+            UpdateSkillTreePacket,
+            UpdateHotkeysPacket,
+            SetHotkeyData1Packet,
+            SetHotkeyData2Packet,
+            UpdatePartyInvitationStatePacket,
+            UpdateShowEquipPacket,
+            UpdateConfigurationPacket,
+            NavigateToMonsterPacket,
+            MarkMinimapPositionPacket,
+            NextButtonPacket,
+            CloseButtonPacket,
+            DialogMenuPacket,
+            DisplaySpecialEffectPacket,
+            DisplaySkillCooldownPacket,
+            DisplaySkillEffectAndDamagePacket,
+            DisplayPlayerHealEffect,
+            DisplaySkillEffectNoDamagePacket,
+            StatusChangePacket,
+            QuestNotificationPacket1,
+            HuntingQuestNotificationPacket,
+            HuntingQuestUpdateObjectivePacket,
+            QuestRemovedPacket,
+            QuestListPacket,
+            VisualEffectPacket,
+            DisplayGainedExperiencePacket,
+            DisplayImagePacket,
+            StateChangePacket,
+            ItemPickupPacket,
+            RemoveItemFromInventoryPacket,
+            UseItemPacket,
+            UseItemSuccessPacket,
+            UseItemFailedPacket,
+            DropItemPacket,
+            DropItemSuccessPacket,
+            PickUpItemPacket,
+            GroundItemEntryPacket,
+            GroundItemFallPacket,
+            GroundItemDisappearPacket,
+            QuestEffectPacket,
+            NpcDialogPacket,
+            MapLoadedPacket,
+            CharacterServerKeepalivePacket,
+            StartDialogPacket,
+            NextDialogPacket,
+            CloseDialogPacket,
+            ChooseDialogOptionPacket,
+            RequestEquipItemPacket,
+            RequestEquipItemStatusPacket,
+            RequestUnequipItemPacket,
+            RequestUnequipItemStatusPacket,
+            ParameterChangePacket,
+            RestartPacket,
+            RestartResponsePacket,
+            DisconnectResponsePacket,
+            UseSkillAtIdPacket,
+            UseSkillOnGroundPacket,
+            StartUseSkillPacket,
+            EndUseSkillPacket,
+            UseSkillSuccessPacket,
+            ToUseSkillSuccessPacket,
+            NotifySkillUnitPacket,
+            NotifyGroundSkillPacket,
+            SkillUnitDisappearPacket,
+            AddFriendPacket,
+            RemoveFriendPacket,
+            NotifyFriendRemovedPacket,
+            FriendListPacket,
+            FriendOnlineStatusPacket,
+            FriendRequestPacket,
+            FriendRequestResponsePacket,
+            FriendRequestResultPacket,
+            PartyInvitePacket,
+            CreatePartyPacket,
+            CreatePartyResultPacket,
+            InviteToPartyPacket,
+            PartyInviteResultPacket,
+            PartyInviteResponsePacket,
+            PartyMemberListPacket,
+            LegacyPartyMemberListPacket,
+            PartyMemberJoinedPacket,
+            PartyMemberLeftPacket,
+            LeavePartyPacket,
+            ExpelPartyMemberPacket,
+            ChangePartyLeaderPacket,
+            PartyLeaderChangedPacket,
+            PartySettingsPacket,
+            PartyMemberHealthPointsPacket,
+            PartyMemberPositionPacket,
+            PartyMemberJobLevelPacket,
+            PartyMessagePacket,
+            ReceivePartyMessagePacket,
+            UpdateGuildPacket,
+            RequestGuildMenuPacket,
+            GuildInformationPacket,
+            GuildMemberListPacket,
+            GuildPositionNamesPacket,
+            GuildNoticePacket,
+            GuildMemberOnlineStatusPacket,
+            GuildMessagePacket,
+            ReceiveGuildMessagePacket,
+            InviteToGuildPacket,
+            GuildInvitationPacket,
+            GuildInviteResponsePacket,
+            GuildInviteResultPacket,
+            LeaveGuildPacket,
+            GuildMemberLeftPacket,
+            RequestGuildEmblemPacket,
+            GuildEmblemPacket,
+            ReputationPacket,
+            ClanInfoPacket,
+            ClanOnlineCountPacket,
+            ChangeMapCellPacket,
+            OpenMarketPacket,
+            ShopItemListPacket,
+            BuyOrSellPacket,
+            SelectBuyOrSellPacket,
+            BuyItemsPacket,
+            BuyShopItemsPacket,
+            BuyShopItemsResultPacket,
+            CloseShopPacket,
+            SellListPacket,
+            SellItemsPacket,
+            SellItemsResultPacket,
+            RequestTradePacket,
+            TradeRequestPacket,
+            TradeRequestResponsePacket,
+            TradeRequestResultPacket,
+            AddTradeItemPacket,
+            AddTradeItemResultPacket,
+            TradeItemAddedPacket,
+            LockTradePacket,
+            TradeLockedPacket,
+            CancelTradePacket,
+            TradeCancelledPacket,
+            CommitTradePacket,
+            TradeCompletedPacket,
+            StorageCapacityPacket,
+            MoveItemToStoragePacket,
+            MoveItemFromStoragePacket,
+            StorageItemAddedPacket,
+            StorageItemRemovedPacket,
+            CloseStoragePacket,
+            StorageClosedPacket,
+            CartCapacityPacket,
+            CartItemRemovedPacket,
+            OpenVendingSetupPacket,
+            OpenVendingShopPacket,
+            OpenVendingShopResultPacket,
+            CloseVendingShopPacket,
+            VendingShopBoardPacket,
+            VendingShopBoardRemovedPacket,
+            RequestVendingItemListPacket,
+            VendingItemListPacket,
+            BuyVendingItemsPacket,
+            BuyVendingItemsResultPacket,
+            OwnVendingItemListPacket,
+            VendingItemSoldPacket,
+            OpenMailboxPacket,
+            RefreshMailboxPacket,
+            CloseMailboxPacket,
+            MailListPacket,
+            ReadMailPacket,
+            MailContentPacket,
+            DeleteMailPacket,
+            MailDeletedPacket,
+            RequestMailZenyPacket,
+            MailZenyResultPacket,
+            RequestMailItemsPacket,
+            MailItemsResultPacket,
+            OpenWriteMailPacket,
+            OpenWriteMailResultPacket,
+            CheckMailReceiverPacket,
+            MailReceiverPacket,
+            AddMailItemPacket,
+            AddMailItemResultPacket,
+            RemoveMailItemPacket,
+            RemoveMailItemResultPacket,
+            SendMailPacket,
+            SendMailResultPacket,
+            RequestAchievementRewardPacket,
+            AchievementRewardPacket,
+        )
+    };
+}
+
+impl PacketLengthTable {
+    /// Create a table without any packets.
+    pub fn empty() -> Self {
+        Self { lengths: HashMap::new() }
+    }
+
+    /// Create a table with the lengths of all packets that Korangar knows. If
+    /// multiple packets share a header, the one closest to the client date is
+    /// used, the same way [`PacketHandler`](crate::handler::PacketHandler)
+    /// picks its handlers.
+    pub fn for_version(packet_version: PacketVersion) -> Self {
+        let mut table = Self::empty();
+        let mut distances = HashMap::new();
+
+        macro_rules! insert_packets {
+            ($($packet:ident),* $(,)?) => {
+                $(table.insert_packet::<crate::$packet>(&mut distances, packet_version);)*
+            };
+        }
+
+        known_packets!(insert_packets);
+
+        table
+    }
+
+    fn insert_packet<P: Packet>(&mut self, distances: &mut HashMap<PacketHeader, u32>, packet_version: PacketVersion) {
+        let distance = P::VERSIONS.distance(packet_version);
+
+        if distances.get(&P::HEADER).map_or(true, |other_distance| distance < *other_distance) {
+            distances.insert(P::HEADER, distance);
+            self.insert(P::HEADER, P::packet_length());
+        }
+    }
+
+    pub fn insert(&mut self, header: PacketHeader, length: PacketLength) {
+        self.lengths.insert(header, length);
+    }
+
+    pub fn get(&self, header: PacketHeader) -> Option<PacketLength> {
+        self.lengths.get(&header).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    /// Insert all packets of another table, replacing the lengths of packets
+    /// that are already in this table.
+    pub fn extend(&mut self, other: PacketLengthTable) {
+        self.lengths.extend(other.lengths);
+    }
+
+    /// Parse a table with one packet per line, in the format `0x0069,-1`.
+    /// The header is hexadecimal and a length of -1 marks a variable length
+    /// packet. Anything after the length is ignored and so are lines that
+    /// don't start with a header, which makes it possible to use the
+    /// `packet_db.txt` of older rAthena versions directly.
+    pub fn parse(source: &str) -> Result<Self, PacketLengthTableError> {
+        let mut table = Self::empty();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            let Some(line_data) = line.strip_prefix("0x") else {
+                continue;
+            };
+
+            let invalid_line = || PacketLengthTableError::InvalidLine {
+                line_number: index + 1,
+                line: line.to_owned(),
+            };
+
+            let mut fields = line_data.split(',').map(str::trim);
+            let header = fields
+                .next()
+                .and_then(|header| u16::from_str_radix(header, 16).ok())
+                .ok_or_else(invalid_line)?;
+            let length = fields
+                .next()
+                .and_then(|length| length.parse::<i32>().ok())
+                .ok_or_else(invalid_line)?;
+
+            let length = match length {
+                -1 => PacketLength::Variable,
+                // rAthena uses a length of 0 for packets that are disabled.
+                0 => continue,
+                length if length >= 2 => PacketLength::Fixed(length as usize),
+                _ => return Err(invalid_line()),
+            };
+
+            table.insert(PacketHeader(header), length);
+        }
+
+        Ok(table)
+    }
+
+    /// Load a table from a file. See [`PacketLengthTable::parse`] for the
+    /// format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PacketLengthTableError> {
+        let source = std::fs::read_to_string(path).map_err(PacketLengthTableError::Io)?;
+        Self::parse(&source)
+    }
+}
+
+#[cfg(test)]
+mod built_in {
+    use crate::*;
+
+    #[test]
+    fn known_packets() {
+        let table = PacketLengthTable::default();
+
+        assert_eq!(table.get(LoginFailedPacket::HEADER), Some(PacketLength::Fixed(3)));
+        assert_eq!(table.get(EntityMovePacket::HEADER), Some(PacketLength::Fixed(16)));
+        assert_eq!(table.get(PlayerMovePacket::HEADER), Some(PacketLength::Fixed(12)));
+        assert_eq!(
+            table.get(RequestCharacterListSuccessPacket::HEADER),
+            Some(PacketLength::Variable)
+        );
+        assert_eq!(table.get(PacketHeader(0x0001)), None);
+    }
+
+    #[test]
+    fn all_packets_are_known() {
+        macro_rules! packet_names {
+            ($($packet:ident),* $(,)?) => {
+                [$(stringify!($packet)),*]
+            };
+        }
+
+        let known_packets = known_packets!(packet_names);
+        let mut lines = include_str!("lib.rs").lines();
+
+        while lines.by_ref().any(|line| line.starts_with("#[header(")) {
+            let name = lines
+                .find_map(|line| line.strip_prefix("pub struct "))
+                .and_then(|line| line.split(|character: char| !character.is_alphanumeric()).next())
+                .expect("packet without a struct");
+
+            assert!(known_packets.contains(&name), "{name} is missing from the packet length table");
+        }
+    }
+
+    #[test]
+    fn closest_version() {
+        let new_table = PacketLengthTable::for_version(PacketVersion::DEFAULT);
+        let old_table = PacketLengthTable::for_version(PacketVersion(20150101));
+
+        assert_eq!(new_table.get(MapServerLoginPacket::HEADER), Some(PacketLength::Fixed(23)));
+        assert_eq!(old_table.get(MapServerLoginPacket::HEADER), Some(PacketLength::Fixed(19)));
+    }
+
+    #[test]
+    fn matches_encoded_length() {
+        let packet = MapServerLoginPacket::new(AccountId(1), CharacterId(2), 3, ClientTick(4), Sex::Female);
+
+        assert_eq!(
+            PacketLength::Fixed(packet.packet_to_bytes().unwrap().len()),
+            MapServerLoginPacket::packet_length()
+        );
+    }
+
+    #[test]
+    fn override_entries() {
+        let mut table = PacketLengthTable::default();
+        let overrides = PacketLengthTable::parse("0x0081,5\n0x0001,-1\n").unwrap();
+
+        table.extend(overrides);
+
+        assert_eq!(table.get(LoginFailedPacket::HEADER), Some(PacketLength::Fixed(5)));
+        assert_eq!(table.get(PacketHeader(0x0001)), Some(PacketLength::Variable));
+        assert_eq!(table.get(EntityMovePacket::HEADER), Some(PacketLength::Fixed(16)));
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use crate::{PacketHeader, PacketLength, PacketLengthTable};
+
+    #[test]
+    fn packet_db() {
+        let source = "// comment\npacket_ver: 5\n0x0069,-1\n0x0072,19,wanttoconnection,2:6:10:14:18\n0x0073,0\n";
+        let table = PacketLengthTable::parse(source).unwrap();
+
+        assert_eq!(table.get(PacketHeader(0x0069)), Some(PacketLength::Variable));
+        assert_eq!(table.get(PacketHeader(0x0072)), Some(PacketLength::Fixed(19)));
+        assert_eq!(table.get(PacketHeader(0x0073)), None);
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert!(PacketLengthTable::parse("0x0069,1").is_err());
+        assert!(PacketLengthTable::parse("0xZZZZ,10").is_err());
+        assert!(PacketLengthTable::parse("0x0069").is_err());
+    }
+}
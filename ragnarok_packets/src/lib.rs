@@ -1,5 +1,7 @@
 pub mod handler;
+mod length;
 mod position;
+mod version;
 
 use std::net::Ipv4Addr;
 
@@ -11,13 +13,15 @@ pub use ragnarok_procedural::{CharacterServer, ClientPacket, LoginServer, MapSer
 #[cfg(not(feature = "derive"))]
 use ragnarok_procedural::{CharacterServer, ClientPacket, LoginServer, MapServer, Packet, ServerPacket};
 
+pub use self::length::{PacketLength, PacketLengthTable, PacketLengthTableError};
 pub use self::position::{WorldPosition, WorldPosition2};
+pub use self::version::{PacketVersion, PacketVersionRange};
 
 // To make proc macros work in korangar_interface.
 extern crate self as ragnarok_packets;
 
 /// The header of a Ragnarok Online packet. It is always two bytes long.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize, PartialOrd, Ord, Hash)]
 pub struct PacketHeader(pub u16);
 
 /// Base trait that all packets implement.
@@ -32,6 +36,12 @@ pub trait Packet: std::fmt::Debug + Clone {
     const IS_PING: bool;
     /// The header of the Packet.
     const HEADER: PacketHeader;
+    /// The client dates that use this packet.
+    const VERSIONS: PacketVersionRange = PacketVersionRange::ALL;
+
+    /// Length of the packet **including the header**. Used to skip packets
+    /// that don't have a handler.
+    fn packet_length() -> PacketLength;
 
     /// Read packet **without the header**. To read the packet with the header,
     /// use [`PacketExt::packet_from_bytes`].
@@ -83,8 +93,16 @@ where
     }
 }
 
-/// Marker trait for packets sent by the client.
-pub trait ClientPacket: Packet {}
+/// Trait for packets sent by the client.
+pub trait ClientPacket: Packet {
+    /// Write packet **with the header** in the layout used by the given client
+    /// date. Packets that changed over time list their older layouts with
+    /// `#[legacy_variant(..)]` and are converted to the variant that matches
+    /// the client date.
+    fn versioned_packet_to_bytes(&self, _packet_version: PacketVersion) -> ConversionResult<Vec<u8>> {
+        self.packet_to_bytes()
+    }
+}
 
 /// Marker trait for packets sent by the server.
 pub trait ServerPacket: Packet {}
@@ -98,7 +116,15 @@ pub trait CharacterServerPacket: Packet {}
 /// Marker trait for map server packets.
 pub trait MapServerPacket: Packet {}
 
-#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
+/// The three servers that the client connects to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServerType {
+    Login,
+    Character,
+    Map,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ClientTick(pub u32);
 
@@ -114,6 +140,10 @@ pub struct CharacterId(pub u32);
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct PartyId(pub u32);
 
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct GuildId(pub u32);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct EntityId(pub u32);
@@ -142,6 +172,10 @@ pub struct ShopId(pub u32);
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct Price(pub u32);
 
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailId(pub u64);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ServerAddress(pub [u8; 4]);
@@ -183,7 +217,7 @@ pub struct ColorBGRA {
     pub alpha: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ColorRGBA {
     pub red: u8,
@@ -193,19 +227,39 @@ pub struct ColorRGBA {
 }
 
 /// Item index is always actual index + 2.
+///
+/// Storage item lists reuse the inventory item packets but their indices are
+/// only offset by 1, so the index may wrap around when parsing those.
 #[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct InventoryIndex(pub u16);
 
 impl FromBytes for InventoryIndex {
     fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
-        u16::from_bytes(byte_stream).map(|raw| Self(raw - 2))
+        u16::from_bytes(byte_stream).map(|raw| Self(raw.wrapping_sub(2)))
     }
 }
 
 impl ToBytes for InventoryIndex {
     fn to_bytes(&self) -> ConversionResult<Vec<u8>> {
-        u16::to_bytes(&(self.0 + 2))
+        u16::to_bytes(&(self.0.wrapping_add(2)))
+    }
+}
+
+/// Storage index is always actual index + 1.
+#[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct StorageIndex(pub u16);
+
+impl FromBytes for StorageIndex {
+    fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
+        u16::from_bytes(byte_stream).map(|raw| Self(raw - 1))
+    }
+}
+
+impl ToBytes for StorageIndex {
+    fn to_bytes(&self) -> ConversionResult<Vec<u8>> {
+        u16::to_bytes(&(self.0 + 1))
     }
 }
 
@@ -213,6 +267,16 @@ impl ToBytes for InventoryIndex {
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ItemId(pub u32);
 
+/// Index of an emotion in the `emotion.act` of the client.
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct EmotionId(pub u8);
+
+/// Id of an item lying on the ground.
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct GroundItemId(pub u32);
+
 #[derive(Copy, Debug, Clone, ByteConvertable, FixedByteSize, PartialEq)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum Sex {
@@ -247,6 +311,7 @@ pub struct LoginServerLoginPacket {
 #[derive(Debug, Clone, Packet, ServerPacket, LoginServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0AC4)]
+#[packet_version(since = 20170315)]
 #[variable_length]
 pub struct LoginServerLoginSuccessPacket {
     pub login_id1: u32,
@@ -267,6 +332,32 @@ pub struct LoginServerLoginSuccessPacket {
     pub character_server_information: Vec<CharacterServerInformation>,
 }
 
+/// Version of [LoginServerLoginSuccessPacket] used by clients before
+/// 2017-03-15. It doesn't contain an auth token and the character server
+/// information is shorter.
+#[derive(Debug, Clone, Packet, ServerPacket, LoginServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0069)]
+#[packet_version(until = 20170315)]
+#[variable_length]
+pub struct LegacyLoginServerLoginSuccessPacket {
+    pub login_id1: u32,
+    pub account_id: AccountId,
+    pub login_id2: u32,
+    /// Deprecated and always 0 on rAthena
+    #[new_default]
+    pub ip_address: u32,
+    /// Deprecated and always 0 on rAthena
+    #[new_default]
+    pub name: [u8; 24],
+    /// Always 0 on rAthena
+    #[new_default]
+    pub unknown: u16,
+    pub sex: Sex,
+    #[repeating_remaining]
+    pub character_server_information: Vec<LegacyCharacterServerInformation>,
+}
+
 /// Sent by the character server as a response to [CharacterServerLoginPacket]
 /// succeeding. Provides basic information about the number of available
 /// character slots.
@@ -319,7 +410,7 @@ pub struct MapServerLoginSuccessPacket {
     pub font: u16,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum LoginFailedReason {
     #[numeric_value(1)]
@@ -330,7 +421,7 @@ pub enum LoginFailedReason {
     AlreadyOnline,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, LoginServer, CharacterServer)]
+#[derive(Debug, Clone, Packet, ServerPacket, LoginServer, CharacterServer, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0081)]
 pub struct LoginFailedPacket {
@@ -346,7 +437,7 @@ pub struct MapServerUnavailablePacket {
     pub unknown: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum LoginFailedReason2 {
     UnregisteredId,
@@ -367,7 +458,7 @@ pub struct LoginFailedPacket2 {
     pub reason: LoginFailedReason2,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum CharacterSelectionFailedReason {
     RejectedFromServer,
@@ -399,7 +490,7 @@ pub struct CharacterSelectionSuccessPacket {
     pub unknown: [u8; 128],
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum CharacterCreationFailedReason {
     CharacterNameAlreadyUsed,
@@ -444,6 +535,33 @@ pub struct CharacterServerInformation {
     pub unknown: [u8; 128],
 }
 
+/// Version of [CharacterServerInformation] used by clients before 2017-03-15.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct LegacyCharacterServerInformation {
+    pub server_ip: ServerAddress,
+    pub server_port: u16,
+    #[length(20)]
+    pub server_name: String,
+    pub user_count: u16,
+    pub server_type: u16,
+    pub display_new: u16,
+}
+
+impl From<LegacyCharacterServerInformation> for CharacterServerInformation {
+    fn from(information: LegacyCharacterServerInformation) -> Self {
+        Self {
+            server_ip: information.server_ip,
+            server_port: information.server_port,
+            server_name: information.server_name,
+            user_count: information.user_count,
+            server_type: information.server_type,
+            display_new: information.display_new,
+            unknown: [0; 128],
+        }
+    }
+}
+
 /// Sent by the client to the character server after after successfully logging
 /// into the login server.
 /// Attempts to log into the character server using the provided information.
@@ -465,6 +583,8 @@ pub struct CharacterServerLoginPacket {
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0436)]
+#[packet_version(since = 20191223)]
+#[legacy_variant(LegacyMapServerLoginPacket)]
 pub struct MapServerLoginPacket {
     pub account_id: AccountId,
     pub character_id: CharacterId,
@@ -475,6 +595,32 @@ pub struct MapServerLoginPacket {
     pub unknown: [u8; 4],
 }
 
+/// Version of [MapServerLoginPacket] used by clients before 2019-12-23. It
+/// uses the same header but is four bytes shorter.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0436)]
+#[packet_version(until = 20191223)]
+pub struct LegacyMapServerLoginPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub login_id1: u32,
+    pub client_tick: ClientTick,
+    pub sex: Sex,
+}
+
+impl From<MapServerLoginPacket> for LegacyMapServerLoginPacket {
+    fn from(packet: MapServerLoginPacket) -> Self {
+        Self {
+            account_id: packet.account_id,
+            character_id: packet.character_id,
+            login_id1: packet.login_id1,
+            client_tick: packet.client_tick,
+            sex: packet.sex,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0283)]
@@ -552,16 +698,131 @@ pub struct CharacterInformation {
     pub sex: Sex,
 }
 
+/// Version of [CharacterInformation] used by clients before 2021-11-03. Health
+/// points are only four bytes and spell points only two bytes long.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct LegacyCharacterInformation {
+    pub character_id: CharacterId,
+    pub experience: i64,
+    pub money: i32,
+    pub job_experience: i64,
+    pub jop_level: i32,
+    pub body_state: i32,
+    pub health_state: i32,
+    pub effect_state: i32,
+    pub virtue: i32,
+    pub honor: i32,
+    pub jobpoint: i16,
+    pub health_points: i32,
+    pub maximum_health_points: i32,
+    pub spell_points: i16,
+    pub maximum_spell_points: i16,
+    pub movement_speed: i16,
+    pub job: i16,
+    pub head: i16,
+    pub body: i16,
+    pub weapon: i16,
+    pub level: i16,
+    pub sp_point: i16,
+    pub accessory: i16,
+    pub shield: i16,
+    pub accessory2: i16,
+    pub accessory3: i16,
+    pub head_palette: i16,
+    pub body_palette: i16,
+    #[length(24)]
+    pub name: String,
+    pub strength: u8,
+    pub agility: u8,
+    pub vit: u8,
+    pub intelligence: u8,
+    pub dexterity: u8,
+    pub luck: u8,
+    pub character_number: u8,
+    pub hair_color: u8,
+    pub b_is_changed_char: i16,
+    #[length(16)]
+    pub map_name: String,
+    pub deletion_reverse_date: i32,
+    pub robe_palette: i32,
+    pub character_slot_change_count: i32,
+    pub character_name_change_count: i32,
+    pub sex: Sex,
+}
+
+impl From<LegacyCharacterInformation> for CharacterInformation {
+    fn from(information: LegacyCharacterInformation) -> Self {
+        Self {
+            character_id: information.character_id,
+            experience: information.experience,
+            money: information.money,
+            job_experience: information.job_experience,
+            jop_level: information.jop_level,
+            body_state: information.body_state,
+            health_state: information.health_state,
+            effect_state: information.effect_state,
+            virtue: information.virtue,
+            honor: information.honor,
+            jobpoint: information.jobpoint,
+            health_points: information.health_points.into(),
+            maximum_health_points: information.maximum_health_points.into(),
+            spell_points: information.spell_points.into(),
+            maximum_spell_points: information.maximum_spell_points.into(),
+            movement_speed: information.movement_speed,
+            job: information.job,
+            head: information.head,
+            body: information.body,
+            weapon: information.weapon,
+            level: information.level,
+            sp_point: information.sp_point,
+            accessory: information.accessory,
+            shield: information.shield,
+            accessory2: information.accessory2,
+            accessory3: information.accessory3,
+            head_palette: information.head_palette,
+            body_palette: information.body_palette,
+            name: information.name,
+            strength: information.strength,
+            agility: information.agility,
+            vit: information.vit,
+            intelligence: information.intelligence,
+            dexterity: information.dexterity,
+            luck: information.luck,
+            character_number: information.character_number,
+            hair_color: information.hair_color,
+            b_is_changed_char: information.b_is_changed_char,
+            map_name: information.map_name,
+            deletion_reverse_date: information.deletion_reverse_date,
+            robe_palette: information.robe_palette,
+            character_slot_change_count: information.character_slot_change_count,
+            character_name_change_count: information.character_name_change_count,
+            sex: information.sex,
+        }
+    }
+}
+
 /// Sent by the character server as a response to [CreateCharacterPacket]
 /// succeeding. Provides all character information of the newly created
 /// character.
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B6F)]
+#[packet_version(since = 20211103)]
 pub struct CreateCharacterSuccessPacket {
     pub character_information: CharacterInformation,
 }
 
+/// Version of [CreateCharacterSuccessPacket] used by clients before
+/// 2021-11-03.
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x006D)]
+#[packet_version(until = 20211103)]
+pub struct LegacyCreateCharacterSuccessPacket {
+    pub character_information: LegacyCharacterInformation,
+}
+
 /// Sent by the client to the character server.
 /// Requests a list of every character associated with the account.
 #[derive(Debug, Clone, Default, Packet, ClientPacket, CharacterServer)]
@@ -574,12 +835,25 @@ pub struct RequestCharacterListPacket {}
 #[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B72)]
+#[packet_version(since = 20211103)]
 #[variable_length]
 pub struct RequestCharacterListSuccessPacket {
     #[repeating_remaining]
     pub character_information: Vec<CharacterInformation>,
 }
 
+/// Version of [RequestCharacterListSuccessPacket] used by clients before
+/// 2021-11-03.
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x099D)]
+#[packet_version(until = 20211103)]
+#[variable_length]
+pub struct LegacyRequestCharacterListSuccessPacket {
+    #[repeating_remaining]
+    pub character_information: Vec<LegacyCharacterInformation>,
+}
+
 /// Sent by the map server to the client.
 #[derive(Debug, Clone, Default, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
@@ -659,7 +933,7 @@ pub struct DeleteCharacterPacket {
     pub unknown: [u8; 10],
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum CharacterDeletionFailedReason {
     NotAllowed,
@@ -683,6 +957,112 @@ pub struct CharacterDeletionFailedPacket {
 #[header(0x006F)]
 pub struct CharacterDeletionSuccessPacket {}
 
+/// Sent by the client to the character server when the user tries to delete a
+/// character. Instead of deleting the character right away, the server
+/// schedules the deletion, which has to be accepted with
+/// [AcceptCharacterDeletionPacket] once the waiting time is over.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0827)]
+pub struct ReserveCharacterDeletionPacket {
+    pub character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionReservationStatus {
+    #[numeric_value(0)]
+    UnknownError,
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(3)]
+    DatabaseError,
+    #[numeric_value(4)]
+    InGuild,
+    #[numeric_value(5)]
+    InParty,
+}
+
+/// Sent by the character server as a response to
+/// [ReserveCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0828)]
+pub struct CharacterDeletionReservedPacket {
+    pub character_id: CharacterId,
+    pub status: CharacterDeletionReservationStatus,
+    /// Seconds until the deletion can be accepted.
+    pub deletion_date: u32,
+}
+
+/// Sent by the client to the character server to delete a character after
+/// the deletion was reserved and the waiting time is over.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0829)]
+pub struct AcceptCharacterDeletionPacket {
+    pub character_id: CharacterId,
+    /// Birthdate of the account owner in the format `YYMMDD`.
+    pub birthdate: [u8; 6],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionAcceptStatus {
+    #[numeric_value(0)]
+    UnknownError,
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(2)]
+    NotAllowed,
+    #[numeric_value(3)]
+    DatabaseError,
+    #[numeric_value(4)]
+    NotYetPossible,
+    #[numeric_value(5)]
+    BirthdateMismatch,
+}
+
+/// Sent by the character server as a response to
+/// [AcceptCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082A)]
+pub struct CharacterDeletionAcceptedPacket {
+    pub character_id: CharacterId,
+    pub status: CharacterDeletionAcceptStatus,
+}
+
+/// Sent by the client to the character server to cancel a reserved deletion.
+#[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082B)]
+pub struct CancelCharacterDeletionPacket {
+    pub character_id: CharacterId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum CharacterDeletionCancelStatus {
+    #[numeric_value(1)]
+    Success,
+    #[numeric_value(2)]
+    UnknownError,
+}
+
+/// Sent by the character server as a response to
+/// [CancelCharacterDeletionPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, CharacterServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x082C)]
+pub struct CharacterDeletionCanceledPacket {
+    pub character_id: CharacterId,
+    pub status: CharacterDeletionCancelStatus,
+}
+
 /// Sent by the client to the character server when the user selects a
 /// character. Attempts to select the character in the specified slot.
 #[derive(Debug, Clone, Packet, ClientPacket, CharacterServer)]
@@ -752,7 +1132,7 @@ pub struct NewMailStatusPacket {
     pub new_available: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct AchievementData {
     pub acheivement_id: u32,
@@ -805,12 +1185,21 @@ pub struct SpriteChangePacket {
     pub value2: u32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum InventoryType {
+    Inventory,
+    Cart,
+    Storage,
+    GuildStorage,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B08)]
 #[variable_length]
 pub struct InventoyStartPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[length_remaining]
     pub inventory_name: String,
 }
@@ -819,7 +1208,7 @@ pub struct InventoyStartPacket {
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B0B)]
 pub struct InventoyEndPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     pub flag: u8, // maybe char ?
 }
 
@@ -874,6 +1263,7 @@ pub struct RegularItemInformation {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B09)]
+#[packet_version(since = 20180704)]
 #[variable_length]
 pub struct RegularItemListPacket {
     pub inventory_type: u8,
@@ -881,6 +1271,49 @@ pub struct RegularItemListPacket {
     pub item_information: Vec<RegularItemInformation>,
 }
 
+/// Version of [RegularItemInformation] used by clients before 2018-07-04. Item
+/// ids and cards are only two bytes long.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct LegacyRegularItemInformation {
+    pub index: InventoryIndex,
+    pub item_id: u16,
+    pub item_type: u8,
+    pub amount: u16,
+    pub equipped_position: EquipPosition,
+    pub slot: [u16; 4], // card ?
+    pub hire_expiration_date: u32,
+    pub flags: RegularItemFlags,
+}
+
+impl From<LegacyRegularItemInformation> for RegularItemInformation {
+    fn from(information: LegacyRegularItemInformation) -> Self {
+        Self {
+            index: information.index,
+            item_id: ItemId(information.item_id.into()),
+            item_type: information.item_type,
+            amount: information.amount,
+            equipped_position: information.equipped_position,
+            slot: information.slot.map(u32::from),
+            hire_expiration_date: information.hire_expiration_date,
+            flags: information.flags,
+        }
+    }
+}
+
+/// Version of [RegularItemListPacket] used by clients before 2018-07-04. It
+/// is only sent for the inventory and is not framed by [InventoyStartPacket]
+/// and [InventoyEndPacket].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0991)]
+#[packet_version(until = 20180704)]
+#[variable_length]
+pub struct LegacyRegularItemListPacket {
+    #[repeating_remaining]
+    pub item_information: Vec<LegacyRegularItemInformation>,
+}
+
 bitflags::bitflags! {
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
@@ -931,6 +1364,7 @@ pub struct EquippableItemInformation {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0B39)]
+#[packet_version(since = 20180704)]
 #[variable_length]
 pub struct EquippableItemListPacket {
     pub inventory_type: u8,
@@ -938,23 +1372,78 @@ pub struct EquippableItemListPacket {
     pub item_information: Vec<EquippableItemInformation>,
 }
 
+/// Version of [EquippableItemInformation] used by clients before 2018-07-04.
+/// Item ids and cards are only two bytes long and there is no enchantment
+/// level.
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct EquippableSwitchItemInformation {
+pub struct LegacyEquippableItemInformation {
     pub index: InventoryIndex,
-    pub position: u32,
+    pub item_id: u16,
+    pub item_type: u8,
+    pub equip_position: EquipPosition,
+    pub equipped_position: EquipPosition,
+    pub refinement_level: u8,
+    pub slot: [u16; 4], // card ?
+    pub hire_expiration_date: u32,
+    pub bind_on_equip_type: u16,
+    pub w_item_sprite_number: u16,
+    pub option_count: u8,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub flags: EquippableItemFlags,
 }
 
+impl From<LegacyEquippableItemInformation> for EquippableItemInformation {
+    fn from(information: LegacyEquippableItemInformation) -> Self {
+        Self {
+            index: information.index,
+            item_id: ItemId(information.item_id.into()),
+            item_type: information.item_type,
+            equip_position: information.equip_position,
+            equipped_position: information.equipped_position,
+            slot: information.slot.map(u32::from),
+            hire_expiration_date: information.hire_expiration_date,
+            bind_on_equip_type: information.bind_on_equip_type,
+            w_item_sprite_number: information.w_item_sprite_number,
+            option_count: information.option_count,
+            option_data: information.option_data,
+            refinement_level: information.refinement_level,
+            enchantment_level: 0,
+            flags: information.flags,
+        }
+    }
+}
+
+/// Version of [EquippableItemListPacket] used by clients before 2018-07-04.
+/// It is only sent for the inventory, right after the
+/// [LegacyRegularItemListPacket].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0A9B)]
+#[header(0x0A0D)]
+#[packet_version(until = 20180704)]
 #[variable_length]
-pub struct EquippableSwitchItemListPacket {
+pub struct LegacyEquippableItemListPacket {
     #[repeating_remaining]
-    pub item_information: Vec<EquippableSwitchItemInformation>,
+    pub item_information: Vec<LegacyEquippableItemInformation>,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct EquippableSwitchItemInformation {
+    pub index: InventoryIndex,
+    pub position: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A9B)]
+#[variable_length]
+pub struct EquippableSwitchItemListPacket {
+    #[repeating_remaining]
+    pub item_information: Vec<EquippableSwitchItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x099B)]
 pub struct MapTypePacket {
@@ -1022,7 +1511,17 @@ pub struct EntityMessagePacket {
 #[header(0x00C0)]
 pub struct DisplayEmotionPacket {
     pub entity_id: EntityId,
-    pub emotion: u8,
+    pub emotion: EmotionId,
+}
+
+/// Sent by the client to the map server to show an emotion above the head of
+/// the player. The server answers with a [`DisplayEmotionPacket`] to everyone
+/// around, including the player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BF)]
+pub struct RequestEmotionPacket {
+    pub emotion: EmotionId,
 }
 
 /// Every value that can be set from the server through [UpdateStatusPacket],
@@ -1201,13 +1700,11 @@ pub struct UpdateStatusPacket {
 #[header(0x0196)]
 pub struct StatusChangeSequencePacket {
     pub index: u16,
-    pub id: u32,
+    pub entity_id: EntityId,
     pub state: u8,
 }
 
 /// Sent by the character server to the client when loading onto a new map.
-/// This packet is ignored by Korangar since all of the provided values are set
-/// again individually using the UpdateStatusPackets.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x00BD)]
@@ -1267,6 +1764,43 @@ pub struct UpdateStatusPacket3 {
     pub status_type: StatusType,
 }
 
+/// Attributes that can be raised by spending status points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum StatusIncreaseType {
+    #[numeric_value(13)]
+    Strength,
+    Agility,
+    Vitality,
+    Intelligence,
+    Dexterity,
+    Luck,
+}
+
+/// Sent by the client to the map server to spend status points on an
+/// attribute.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BB)]
+pub struct RequestStatusIncreasePacket {
+    pub status_type: StatusIncreaseType,
+    pub amount: u8,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestStatusIncreasePacket`]. The new values of the attribute and the
+/// remaining status points are sent separately using the UpdateStatusPackets.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00BC)]
+pub struct StatusIncreaseResultPacket {
+    pub status_type: StatusIncreaseType,
+    /// 0 if the attribute could not be increased.
+    pub result: u8,
+    pub value: u8,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x013A)]
@@ -1286,7 +1820,7 @@ pub struct SwitchCharacterSlotPacket {
     pub remaining_moves: u16,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum Action {
     Attack,
@@ -1317,6 +1851,53 @@ pub struct GlobalMessagePacket {
     pub message: String,
 }
 
+/// Sent by the client to the map server when the player sends a private
+/// message to another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0096)]
+#[variable_length]
+pub struct SendWhisperPacket {
+    #[length(24)]
+    pub name: String,
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when another player sent a private
+/// message to the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DE)]
+#[variable_length]
+pub struct ReceiveWhisperPacket {
+    pub sender_character_id: CharacterId,
+    #[length(24)]
+    pub sender_name: String,
+    pub is_admin: u8,
+    #[length_remaining]
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum WhisperResult {
+    Success,
+    TargetNotOnline,
+    TargetIgnoresYou,
+    TargetIgnoresEveryone,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`SendWhisperPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09DF)]
+pub struct WhisperResultPacket {
+    pub result: WhisperResult,
+    pub character_id: CharacterId,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x0139)]
@@ -1373,7 +1954,7 @@ pub struct RequestServerTickPacket {
     pub client_tick: ClientTick,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum SwitchCharacterSlotResponseStatus {
@@ -1400,7 +1981,7 @@ pub struct ChangeMapPacket {
     pub position: TilePosition,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum DissapearanceReason {
     OutOfSight,
@@ -1421,6 +2002,7 @@ pub struct EntityDisappearedPacket {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x09FD)]
+#[packet_version(since = 20180704)]
 #[variable_length]
 pub struct MovingEntityAppearedPacket {
     pub object_type: u8,
@@ -1464,6 +2046,7 @@ pub struct MovingEntityAppearedPacket {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x09FE)]
+#[packet_version(since = 20180704)]
 #[variable_length]
 pub struct EntityAppearedPacket {
     pub object_type: u8,
@@ -1506,6 +2089,7 @@ pub struct EntityAppearedPacket {
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[header(0x09FF)]
+#[packet_version(since = 20180704)]
 #[variable_length]
 pub struct EntityAppeared2Packet {
     pub object_type: u8,
@@ -1546,6 +2130,140 @@ pub struct EntityAppeared2Packet {
     pub name: String,
 }
 
+/// Version of [MovingEntityAppearedPacket] used by clients before 2018-07-04.
+/// It uses the same header but doesn't contain the shield.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09FD)]
+#[packet_version(until = 20180704)]
+#[variable_length]
+pub struct LegacyMovingEntityAppearedPacket {
+    pub object_type: u8,
+    pub entity_id: EntityId,
+    pub group_id: u32, // may be reversed - or completely wrong
+    pub movement_speed: u16,
+    pub body_state: u16,
+    pub health_state: u16,
+    pub effect_state: u32,
+    pub job: u16,
+    pub head: u16,
+    pub weapon: u32,
+    pub accessory: u16,
+    pub move_start_time: u32,
+    pub accessory2: u16,
+    pub accessory3: u16,
+    pub head_palette: u16,
+    pub body_palette: u16,
+    pub head_direction: u16,
+    pub robe: u16,
+    pub guild_id: u32, // may be reversed - or completely wrong
+    pub emblem_version: u16,
+    pub honor: u16,
+    pub virtue: u32,
+    pub is_pk_mode_on: u8,
+    pub sex: Sex,
+    pub position: WorldPosition2,
+    pub x_size: u8,
+    pub y_size: u8,
+    pub c_level: u16,
+    pub font: u16,
+    pub maximum_health_points: i32,
+    pub health_points: i32,
+    pub is_boss: u8,
+    pub body: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Version of [EntityAppearedPacket] used by clients before 2018-07-04. It
+/// uses the same header but doesn't contain the shield.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09FE)]
+#[packet_version(until = 20180704)]
+#[variable_length]
+pub struct LegacyEntityAppearedPacket {
+    pub object_type: u8,
+    pub entity_id: EntityId,
+    pub group_id: u32, // may be reversed - or completely wrong
+    pub movement_speed: u16,
+    pub body_state: u16,
+    pub health_state: u16,
+    pub effect_state: u32,
+    pub job: u16,
+    pub head: u16,
+    pub weapon: u32,
+    pub accessory: u16,
+    pub accessory2: u16,
+    pub accessory3: u16,
+    pub head_palette: u16,
+    pub body_palette: u16,
+    pub head_direction: u16,
+    pub robe: u16,
+    pub guild_id: u32, // may be reversed - or completely wrong
+    pub emblem_version: u16,
+    pub honor: u16,
+    pub virtue: u32,
+    pub is_pk_mode_on: u8,
+    pub sex: Sex,
+    pub position: WorldPosition,
+    pub x_size: u8,
+    pub y_size: u8,
+    pub c_level: u16,
+    pub font: u16,
+    pub maximum_health_points: i32,
+    pub health_points: i32,
+    pub is_boss: u8,
+    pub body: u16,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Version of [EntityAppeared2Packet] used by clients before 2018-07-04. It
+/// uses the same header but doesn't contain the shield.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09FF)]
+#[packet_version(until = 20180704)]
+#[variable_length]
+pub struct LegacyEntityAppeared2Packet {
+    pub object_type: u8,
+    pub entity_id: EntityId,
+    pub group_id: u32, // may be reversed - or completely wrong
+    pub movement_speed: u16,
+    pub body_state: u16,
+    pub health_state: u16,
+    pub effect_state: u32,
+    pub job: u16,
+    pub head: u16,
+    pub weapon: u32,
+    pub accessory: u16,
+    pub accessory2: u16,
+    pub accessory3: u16,
+    pub head_palette: u16,
+    pub body_palette: u16,
+    pub head_direction: u16,
+    pub robe: u16,
+    pub guild_id: u32, // may be reversed - or completely wrong
+    pub emblem_version: u16,
+    pub honor: u16,
+    pub virtue: u32,
+    pub is_pk_mode_on: u8,
+    pub sex: Sex,
+    pub position: WorldPosition,
+    pub x_size: u8,
+    pub y_size: u8,
+    pub state: u8,
+    pub c_level: u16,
+    pub font: u16,
+    pub maximum_health_points: i32,
+    pub health_points: i32,
+    pub is_boss: u8,
+    pub body: u16,
+    #[length(24)]
+    pub name: String,
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
@@ -1586,7 +2304,7 @@ pub struct UpdateSkillTreePacket {
     pub skill_information: Vec<SkillInformation>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct HotkeyData {
     pub is_skill: u8,
@@ -1663,7 +2381,7 @@ pub struct NavigateToMonsterPacket {
     pub target_monster_id: u16,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
 pub enum MarkerType {
@@ -1739,7 +2457,7 @@ pub struct DisplaySkillEffectAndDamagePacket {
     pub skill_type: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum HealType {
@@ -1780,7 +2498,7 @@ pub struct StatusChangePacket {
     pub value: [u32; 3],
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct ObjectiveDetails1 {
     pub hunt_identification: u32,
@@ -1810,7 +2528,9 @@ pub struct QuestNotificationPacket1 {
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub struct HuntingObjective {
-    pub quest_id: u32,
+    /// Identifies the objective. Matches the `hunt_identification` of
+    /// [`QuestDetails`].
+    pub hunt_identification: u32,
     pub mob_id: u32,
     pub total_count: u16,
     pub current_count: u16,
@@ -1861,8 +2581,12 @@ pub struct QuestDetails {
 pub struct Quest {
     pub quest_id: u32,
     pub active: u8,
-    pub remaining_time: u32, // TODO: double check these
-    pub expire_time: u32,    // TODO: double check these
+    /// Server time (seconds since the unix epoch) at which the quest was
+    /// started.
+    pub start_time: u32,
+    /// Server time (seconds since the unix epoch) at which the quest expires.
+    /// `0` if the quest does not expire.
+    pub expire_time: u32,
     #[new_derive]
     pub objective_count: u16,
     #[repeating(objective_count)]
@@ -1880,7 +2604,7 @@ pub struct QuestListPacket {
     pub quests: Vec<Quest>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
 pub enum VisualEffect {
@@ -1904,7 +2628,7 @@ pub struct VisualEffectPacket {
     pub effect: VisualEffect,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum ExperienceType {
@@ -1913,7 +2637,7 @@ pub enum ExperienceType {
     JobExperience,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum ExperienceSource {
@@ -1931,7 +2655,7 @@ pub struct DisplayGainedExperiencePacket {
     pub experience_source: ExperienceSource,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum ImageLocation {
     BottomLeft,
@@ -1963,7 +2687,7 @@ pub struct StateChangePacket {
     pub is_pk_mode_on: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum ItemPickupResult {
     Success,
@@ -1998,7 +2722,7 @@ pub struct ItemPickupPacket {
     pub enchantment_level: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum RemoveItemReason {
@@ -2021,51 +2745,157 @@ pub struct RemoveItemFromInventoryPacket {
     pub amount: u16,
 }
 
-// TODO: improve names
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the client to the map server when the player uses an item from
+/// their inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u16)]
-pub enum QuestEffect {
-    Quest,
-    Quest2,
-    Job,
-    Job2,
-    Event,
-    Event2,
-    ClickMe,
-    DailyQuest,
-    Event3,
-    JobQuest,
-    JumpingPoring,
-    #[numeric_value(9999)]
-    None,
+#[header(0x0439)]
+pub struct UseItemPacket {
+    pub index: InventoryIndex,
+    pub account_id: AccountId,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the map server to all players in the area when an item was used
+/// successfully.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u16)]
-pub enum QuestColor {
-    Yellow,
-    Orange,
-    Green,
-    Purple,
+#[header(0x01C8)]
+pub struct UseItemSuccessPacket {
+    pub index: InventoryIndex,
+    pub item_id: ItemId,
+    pub entity_id: EntityId,
+    /// Amount of the item that is left after using it.
+    pub remaining_amount: u16,
+    pub result: u8,
 }
 
+/// Sent by the map server to the player if an item could not be used.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0446)]
-pub struct QuestEffectPacket {
-    pub entity_id: EntityId,
-    pub position: TilePosition,
-    pub effect: QuestEffect,
-    pub color: QuestColor,
+#[header(0x00A8)]
+pub struct UseItemFailedPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub result: u8,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+/// Sent by the client to the map server when the player drops an item from
+/// their inventory onto the ground.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00B4)]
-#[variable_length]
-pub struct NpcDialogPacket {
+#[header(0x0363)]
+pub struct DropItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the map server as a response to [DropItemPacket] succeeding. The
+/// item is removed from the inventory.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00AF)]
+pub struct DropItemSuccessPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the client to the map server when the player tries to pick up an
+/// item from the ground. The map server will respond with an
+/// [ItemPickupPacket].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0362)]
+pub struct PickUpItemPacket {
+    pub ground_item_id: GroundItemId,
+}
+
+/// Sent by the map server when an item lying on the ground comes into the
+/// view of the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x009D)]
+pub struct GroundItemEntryPacket {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub amount: u16,
+    pub sub_x: u8,
+    pub sub_y: u8,
+}
+
+/// Sent by the map server when an item falls to the ground, for example when
+/// a monster is killed or an item is dropped.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0ADD)]
+pub struct GroundItemFallPacket {
+    pub ground_item_id: GroundItemId,
+    pub item_id: ItemId,
+    pub item_type: u16,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub amount: u16,
+    pub show_drop_effect: u8,
+    pub drop_effect_mode: u16,
+}
+
+/// Sent by the map server when an item on the ground was picked up or
+/// vanished.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00A1)]
+pub struct GroundItemDisappearPacket {
+    pub ground_item_id: GroundItemId,
+}
+
+// TODO: improve names
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum QuestEffect {
+    Quest,
+    Quest2,
+    Job,
+    Job2,
+    Event,
+    Event2,
+    ClickMe,
+    DailyQuest,
+    Event3,
+    JobQuest,
+    JumpingPoring,
+    #[numeric_value(9999)]
+    None,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum QuestColor {
+    Yellow,
+    Orange,
+    Green,
+    Purple,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0446)]
+pub struct QuestEffectPacket {
+    pub entity_id: EntityId,
+    pub position: TilePosition,
+    pub effect: QuestEffect,
+    pub color: QuestColor,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00B4)]
+#[variable_length]
+pub struct NpcDialogPacket {
     pub npc_id: EntityId,
     #[length_remaining]
     pub text: String,
@@ -2175,7 +3005,7 @@ pub struct RequestEquipItemPacket {
     pub equip_position: EquipPosition,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum RequestEquipItemStatus {
     Success,
@@ -2200,7 +3030,7 @@ pub struct RequestUnequipItemPacket {
     pub inventory_index: InventoryIndex,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum RequestUnequipItemStatus {
     Success,
@@ -2216,7 +3046,7 @@ pub struct RequestUnequipItemStatusPacket {
     pub result: RequestUnequipItemStatus,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum RestartType {
     Respawn,
@@ -2240,7 +3070,7 @@ pub struct RestartPacket {
 
 // TODO: check that this can be only 1 and 0, if not ByteConvertable
 // should be implemented manually
-#[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum RestartResponseStatus {
     Nothing,
@@ -2256,7 +3086,7 @@ pub struct RestartResponsePacket {
 
 // TODO: check that this can be only 1 and 0, if not Named, ByteConvertable
 // should be implemented manually
-#[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum DisconnectResponseStatus {
@@ -2331,7 +3161,7 @@ pub struct ToUseSkillSuccessPacket {
     pub cause: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
 pub enum UnitId {
@@ -2600,7 +3430,7 @@ pub struct FriendListPacket {
     pub friends: Vec<Friend>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 pub enum OnlineState {
     Online,
@@ -2625,7 +3455,7 @@ pub struct FriendRequestPacket {
     pub requestee: Friend,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u32)]
 pub enum FriendRequestResponse {
@@ -2642,7 +3472,7 @@ pub struct FriendRequestResponsePacket {
     pub response: FriendRequestResponse,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
 #[numeric_type(u16)]
 pub enum FriendRequestResult {
@@ -2669,274 +3499,1564 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct ReputationEntry {
-    pub reputation_type: u64,
-    pub points: i64,
+pub enum PartyItemRule {
+    Individual,
+    Shared,
+}
+
+/// Sent by the client to the map server when the player wants to create a new
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01E8)]
+pub struct CreatePartyPacket {
+    #[length(24)]
+    pub party_name: String,
+    pub item_pickup_rule: PartyItemRule,
+    pub item_share_rule: PartyItemRule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum CreatePartyResult {
+    Success,
+    NameAlreadyExists,
+    AlreadyInParty,
+    NotAllowedOnMap,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B8D)]
-#[variable_length]
-pub struct ReputationPacket {
-    pub success: u8,
-    #[repeating_remaining]
-    pub entries: Vec<ReputationEntry>,
+#[header(0x00FA)]
+pub struct CreatePartyResultPacket {
+    pub result: CreatePartyResult,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Sent by the client to the map server to invite another player to the
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct Aliance {
+#[header(0x02C4)]
+pub struct InviteToPartyPacket {
     #[length(24)]
-    pub name: String,
+    pub character_name: String,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct Antagonist {
+#[numeric_type(u32)]
+pub enum PartyInviteResult {
+    AlreadyInParty,
+    Rejected,
+    Accepted,
+    PartyFull,
+    SameAccount,
+    InvitationBlocked,
+    #[numeric_value(7)]
+    NotOnline,
+    NotAllowedOnMap,
+    LevelRestriction,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C5)]
+pub struct PartyInviteResultPacket {
+    #[length(24)]
+    pub character_name: String,
+    pub result: PartyInviteResult,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum PartyInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server to answer a [`PartyInvitePacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x02C7)]
+pub struct PartyInviteResponsePacket {
+    pub party_id: PartyId,
+    pub response: PartyInviteResponse,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct PartyMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
     #[length(24)]
     pub name: String,
+    #[length(16)]
+    pub map_name: String,
+    pub role: u8, // 0 for the party leader
+    pub is_offline: u8,
+    pub job: u16,
+    pub base_level: u16,
 }
 
+/// Sent by the map server to the client to set the complete member list of the
+/// party.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x098A)]
+#[header(0x0AE5)]
+#[packet_version(since = 20171207)]
 #[variable_length]
-pub struct ClanInfoPacket {
-    pub clan_id: u32,
+pub struct PartyMemberListPacket {
     #[length(24)]
-    pub clan_name: String,
+    pub party_name: String,
+    #[repeating_remaining]
+    pub members: Vec<PartyMemberInformation>,
+}
+
+/// Version of [PartyMemberInformation] used by clients before 2017-12-07. It
+/// doesn't contain the character id.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct LegacyPartyMemberInformation {
+    pub account_id: AccountId,
     #[length(24)]
-    pub clan_master: String,
+    pub name: String,
     #[length(16)]
-    pub clan_map: String,
-    #[new_derive]
-    pub aliance_count: u8,
-    #[new_derive]
-    pub antagonist_count: u8,
-    #[repeating(aliance_count)]
-    pub aliances: Vec<Aliance>,
-    #[repeating(antagonist_count)]
-    pub antagonists: Vec<Antagonist>,
+    pub map_name: String,
+    pub role: u8, // 0 for the party leader
+    pub is_offline: u8,
+    pub job: u16,
+    pub base_level: u16,
 }
 
+/// Version of [PartyMemberListPacket] used by clients before 2017-12-07.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0988)]
-pub struct ClanOnlineCountPacket {
-    pub online_members: u16,
-    pub maximum_members: u16,
+#[header(0x0A44)]
+#[packet_version(until = 20171207)]
+#[variable_length]
+pub struct LegacyPartyMemberListPacket {
+    #[length(24)]
+    pub party_name: String,
+    #[repeating_remaining]
+    pub members: Vec<LegacyPartyMemberInformation>,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0192)]
-pub struct ChangeMapCellPacket {
+#[header(0x0AE4)]
+pub struct PartyMemberJoinedPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub role: u32, // 0 for the party leader
+    pub job: u16,
+    pub base_level: u16,
     pub position: TilePosition,
-    pub cell_type: u16,
+    pub is_offline: u8,
+    #[length(24)]
+    pub party_name: String,
+    #[length(24)]
+    pub name: String,
     #[length(16)]
     pub map_name: String,
+    pub item_pickup_rule: PartyItemRule,
+    pub item_share_rule: PartyItemRule,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct MarketItemInformation {
-    pub name_id: u32,
-    pub item_type: u8,
-    pub price: Price,
-    pub quantity: u32,
-    pub weight: u16,
-    pub location: u32,
+pub enum PartyMemberLeftReason {
+    Left,
+    Expelled,
+    CannotLeaveOnMap,
+    CannotExpelOnMap,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B7A)]
-#[variable_length]
-pub struct OpenMarketPacket {
-    #[repeating_remaining]
-    pub items: Vec<MarketItemInformation>,
+#[header(0x0105)]
+pub struct PartyMemberLeftPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub name: String,
+    pub reason: PartyMemberLeftReason,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the client to the map server when the player wants to leave the
+/// party.
+#[derive(Debug, Clone, Default, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct ShopItemInformation {
-    pub item_id: ItemId,
-    pub price: Price,
-    pub discount_price: Price,
-    pub item_type: u8,
-    pub view_sprite: u16,
-    pub location: u32,
+#[header(0x0100)]
+pub struct LeavePartyPacket {}
+
+/// Sent by the client to the map server when the party leader wants to remove
+/// a member from the party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0103)]
+pub struct ExpelPartyMemberPacket {
+    pub account_id: AccountId,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the client to the map server when the party leader wants to pass
+/// leadership on to another member.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x07DA)]
+pub struct ChangePartyLeaderPacket {
+    pub account_id: AccountId,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B77)]
-#[variable_length]
-pub struct ShopItemListPacket {
-    #[repeating_remaining]
-    pub items: Vec<ShopItemInformation>,
+#[header(0x07FC)]
+pub struct PartyLeaderChangedPacket {
+    pub old_leader_account_id: AccountId,
+    pub new_leader_account_id: AccountId,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C4)]
-pub struct BuyOrSellPacket {
-    pub shop_id: ShopId,
+#[header(0x07D8)]
+pub struct PartySettingsPacket {
+    pub experience_share: u32,
+    pub item_pickup_rule: PartyItemRule,
+    pub item_share_rule: PartyItemRule,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum BuyOrSellOption {
-    Buy,
-    Sell,
+#[header(0x080E)]
+pub struct PartyMemberHealthPointsPacket {
+    pub account_id: AccountId,
+    pub health_points: u32,
+    pub maximum_health_points: u32,
 }
 
-#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C5)]
-pub struct SelectBuyOrSellPacket {
-    pub shop_id: ShopId,
-    pub option: BuyOrSellOption,
+#[header(0x0107)]
+pub struct PartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum BuyItemResult {
-    #[numeric_value(0)]
-    Successful,
-    #[numeric_value(1)]
+#[header(0x0ABD)]
+pub struct PartyMemberJobLevelPacket {
+    pub account_id: AccountId,
+    pub job: u16,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server when the player sends a message to
+/// the party chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0108)]
+#[variable_length]
+pub struct PartyMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when a member of the party sent a
+/// message to the party chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0109)]
+#[variable_length]
+pub struct ReceivePartyMessagePacket {
+    pub account_id: AccountId,
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when the character is a member of a
+/// guild, usually right after entering the map.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016C)]
+pub struct UpdateGuildPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    pub mode: u32,
+    pub is_master: u8,
+    pub inter_server_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum GuildMenuType {
+    Information,
+    MemberList,
+    Positions,
+}
+
+/// Sent by the client to the map server to request one of the sections of
+/// the guild window.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x014F)]
+pub struct RequestGuildMenuPacket {
+    pub menu_type: GuildMenuType,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildMenuPacket`] with [`GuildMenuType::Information`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B6)]
+pub struct GuildInformationPacket {
+    pub guild_id: GuildId,
+    pub level: u32,
+    pub member_count: u32,
+    pub maximum_member_count: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub next_experience: u32,
+    pub tax_points: u32,
+    pub honor: u32,
+    pub virtue: u32,
+    pub emblem_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+    #[length(24)]
+    pub master_name: String,
+    #[length(16)]
+    pub managed_castle: String,
+    pub zeny: u32,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct GuildMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub head: u16,
+    pub head_palette: u16,
+    pub sex: u16,
+    pub job: u16,
+    pub base_level: u16,
+    pub contributed_experience: u32,
+    pub is_online: u32,
+    pub position_id: u32,
+    #[length(50)]
+    pub memo: String,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildMenuPacket`] with [`GuildMenuType::MemberList`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0154)]
+#[variable_length]
+pub struct GuildMemberListPacket {
+    #[repeating_remaining]
+    pub members: Vec<GuildMemberInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct GuildPositionName {
+    pub position_id: u32,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildMenuPacket`] with [`GuildMenuType::Positions`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0166)]
+#[variable_length]
+pub struct GuildPositionNamesPacket {
+    #[repeating_remaining]
+    pub positions: Vec<GuildPositionName>,
+}
+
+/// Sent by the map server to the client when entering the map or when the
+/// guild notice was changed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016F)]
+pub struct GuildNoticePacket {
+    #[length(60)]
+    pub subject: String,
+    #[length(120)]
+    pub notice: String,
+}
+
+/// Sent by the map server to the client when a guild member logs in or out.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016D)]
+pub struct GuildMemberOnlineStatusPacket {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub is_online: u32,
+}
+
+/// Sent by the client to the map server when the player sends a message to
+/// the guild chat.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x017E)]
+#[variable_length]
+pub struct GuildMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when a member of the guild sent a
+/// message to the guild chat.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x017F)]
+#[variable_length]
+pub struct ReceiveGuildMessagePacket {
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the client to the map server to invite a character to the guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0916)]
+pub struct InviteToGuildPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client when the player is invited to a
+/// guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016A)]
+pub struct GuildInvitationPacket {
+    pub guild_id: GuildId,
+    #[length(24)]
+    pub guild_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u32)]
+pub enum GuildInviteResponse {
+    Reject,
+    Accept,
+}
+
+/// Sent by the client to the map server to answer a [`GuildInvitationPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x016B)]
+pub struct GuildInviteResponsePacket {
+    pub guild_id: GuildId,
+    pub response: GuildInviteResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum GuildInviteResult {
+    AlreadyInGuild,
+    Rejected,
+    Accepted,
+    GuildFull,
+}
+
+/// Sent by the map server to the client with the answer to an invitation sent
+/// by the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0169)]
+pub struct GuildInviteResultPacket {
+    pub result: GuildInviteResult,
+}
+
+/// Sent by the client to the map server when the player leaves the guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0159)]
+pub struct LeaveGuildPacket {
+    pub guild_id: GuildId,
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(40)]
+    pub reason: String,
+}
+
+/// Sent by the map server to the client when a member left the guild.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x015A)]
+pub struct GuildMemberLeftPacket {
+    #[length(24)]
+    pub name: String,
+    #[length(40)]
+    pub reason: String,
+}
+
+/// Sent by the client to the map server to download the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: GuildId,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestGuildEmblemPacket`]. The emblem is a zlib compressed bitmap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0152)]
+#[variable_length]
+pub struct GuildEmblemPacket {
+    pub guild_id: GuildId,
+    pub emblem_id: u32,
+    #[repeating_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct ReputationEntry {
+    pub reputation_type: u64,
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B8D)]
+#[variable_length]
+pub struct ReputationPacket {
+    pub success: u8,
+    #[repeating_remaining]
+    pub entries: Vec<ReputationEntry>,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct Aliance {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct Antagonist {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x098A)]
+#[variable_length]
+pub struct ClanInfoPacket {
+    pub clan_id: u32,
+    #[length(24)]
+    pub clan_name: String,
+    #[length(24)]
+    pub clan_master: String,
+    #[length(16)]
+    pub clan_map: String,
+    #[new_derive]
+    pub aliance_count: u8,
+    #[new_derive]
+    pub antagonist_count: u8,
+    #[repeating(aliance_count)]
+    pub aliances: Vec<Aliance>,
+    #[repeating(antagonist_count)]
+    pub antagonists: Vec<Antagonist>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0988)]
+pub struct ClanOnlineCountPacket {
+    pub online_members: u16,
+    pub maximum_members: u16,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0192)]
+pub struct ChangeMapCellPacket {
+    pub position: TilePosition,
+    pub cell_type: u16,
+    #[length(16)]
+    pub map_name: String,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MarketItemInformation {
+    pub name_id: u32,
+    pub item_type: u8,
+    pub price: Price,
+    pub quantity: u32,
+    pub weight: u16,
+    pub location: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B7A)]
+#[variable_length]
+pub struct OpenMarketPacket {
+    #[repeating_remaining]
+    pub items: Vec<MarketItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct ShopItemInformation {
+    pub item_id: ItemId,
+    pub price: Price,
+    pub discount_price: Price,
+    pub item_type: u8,
+    pub view_sprite: u16,
+    pub location: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B77)]
+#[variable_length]
+pub struct ShopItemListPacket {
+    #[repeating_remaining]
+    pub items: Vec<ShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C4)]
+pub struct BuyOrSellPacket {
+    pub shop_id: ShopId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum BuyOrSellOption {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C5)]
+pub struct SelectBuyOrSellPacket {
+    pub shop_id: ShopId,
+    pub option: BuyOrSellOption,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum BuyItemResult {
+    #[numeric_value(0)]
+    Successful,
+    #[numeric_value(1)]
     NotEoughZeny,
     #[numeric_value(2)]
     WeightLimitExceeded,
     #[numeric_value(3)]
     TooManyItems,
-    #[numeric_value(9)]
+    #[numeric_value(9)]
+    TooManyOfThisItem,
+    #[numeric_value(10)]
+    PropsOpenAir,
+    #[numeric_value(11)]
+    ExchangeFailed,
+    #[numeric_value(12)]
+    ExchangeWellDone,
+    #[numeric_value(13)]
+    ItemSoldOut,
+    #[numeric_value(14)]
+    NotEnoughGoods,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyItemInformation {
+    pub amount: u16,
+    pub item_id: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C8)]
+#[variable_length]
+pub struct BuyItemsPacket {
+    #[repeating_remaining]
+    pub items: Vec<BuyItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BuyShopItemInformation {
+    pub item_id: ItemId,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09D6)]
+#[variable_length]
+pub struct BuyShopItemsPacket {
+    pub items: Vec<BuyShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u16)]
+pub enum BuyShopItemsResult {
+    #[numeric_value(0)]
+    Success,
+    #[numeric_value(0xFFFF)]
+    Error,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct BoughtShopItemInformation {
+    pub item_id: ItemId,
+    pub amount: u16,
+    pub price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B4E)]
+#[variable_length]
+pub struct BuyShopItemsResultPacket {
+    pub result: BuyShopItemsResult,
+    #[repeating_remaining]
+    pub purchased_items: Vec<BoughtShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09D4)]
+pub struct CloseShopPacket {}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct SellItemInformation {
+    pub inventory_index: InventoryIndex,
+    pub price: Price,
+    pub overcharge_price: Price,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C7)]
+#[variable_length]
+pub struct SellListPacket {
+    #[repeating_remaining]
+    pub items: Vec<SellItemInformation>,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct SoldItemInformation {
+    pub inventory_index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00C9)]
+#[variable_length]
+pub struct SellItemsPacket {
+    #[repeating_remaining]
+    pub items: Vec<SoldItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[numeric_type(u8)]
+pub enum SellItemsResult {
+    Success,
+    Error,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00CB)]
+pub struct SellItemsResultPacket {
+    pub result: SellItemsResult,
+}
+
+/// Sent by the client to the map server when the player wants to trade with
+/// another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub entity_id: EntityId,
+}
+
+/// Sent by the map server to the client when another player wants to trade
+/// with the player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+#[derive(Debug, Clone, Copy, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum TradeRequestResponse {
+    #[numeric_value(3)]
+    Accept,
+    Reject,
+}
+
+/// Sent by the client to the map server to answer a [`TradeRequestPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E6)]
+pub struct TradeRequestResponsePacket {
+    pub response: TradeRequestResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum TradeRequestResult {
+    TooFarAway,
+    CharacterNotFound,
+    Failed,
+    Accepted,
+    Rejected,
+    Busy,
+}
+
+/// Sent by the map server to both players to start a trade or to tell the
+/// requesting player why it could not be started.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01F5)]
+pub struct TradeRequestResultPacket {
+    pub result: TradeRequestResult,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server to offer an item or zeny in the
+/// current trade. An index of `0` offers zeny, any other index is an
+/// inventory index (which is always actual index + 2).
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00E8)]
+pub struct AddTradeItemPacket {
+    pub index: u16,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum AddTradeItemResult {
+    Success,
+    Overweight,
+    TradeClosed,
+    TooManyItems,
     TooManyOfThisItem,
-    #[numeric_value(10)]
-    PropsOpenAir,
-    #[numeric_value(11)]
-    ExchangeFailed,
-    #[numeric_value(12)]
-    ExchangeWellDone,
-    #[numeric_value(13)]
-    ItemSoldOut,
-    #[numeric_value(14)]
-    NotEnoughGoods,
 }
 
-#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+/// Sent by the map server in response to an [`AddTradeItemPacket`]. Uses the
+/// same indices as the request.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EA)]
+pub struct AddTradeItemResultPacket {
+    pub index: u16,
+    pub result: AddTradeItemResult,
+}
+
+/// Sent by the map server when the trade partner offers an item. Zeny is sent
+/// as an item with the id `0`.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B42)]
+pub struct TradeItemAddedPacket {
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub equip_position: EquipPosition,
+    pub look: u16,
+    pub refinement_level: u8,
+    pub enchantment_level: u8,
+}
+
+/// Sent by the client to the map server to lock the own offer.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EB)]
+pub struct LockTradePacket {}
+
+/// Sent by the map server when one of the two offers was locked.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EC)]
+pub struct TradeLockedPacket {
+    /// `0` if the player locked the offer, `1` if the trade partner did.
+    pub is_partner: u8,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00ED)]
+pub struct CancelTradePacket {}
+
+/// Sent by the map server to both players when the trade was cancelled.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EE)]
+pub struct TradeCancelledPacket {}
+
+/// Sent by the client to the map server to execute the trade once both offers
+/// are locked.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00EF)]
+pub struct CommitTradePacket {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum TradeResult {
+    Success,
+    Failed,
+}
+
+/// Sent by the map server to both players once the trade was executed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F0)]
+pub struct TradeCompletedPacket {
+    pub result: TradeResult,
+}
+
+/// Sent by the map server after the storage item list to tell the client how
+/// many items are stored.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F2)]
+pub struct StorageCapacityPacket {
+    pub amount: u16,
+    pub maximum_amount: u16,
+}
+
+/// Sent by the client to the map server to move an item from the inventory
+/// to the open storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0364)]
+pub struct MoveItemToStoragePacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to move an item from the open storage
+/// to the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0365)]
+pub struct MoveItemFromStoragePacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server when an item was added to the open storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B44)]
+pub struct StorageItemAddedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server when an item was removed from the open storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F6)]
+pub struct StorageItemRemovedPacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F7)]
+pub struct CloseStoragePacket {}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x00F8)]
+pub struct StorageClosedPacket {}
+
+/// Sent by the map server after the cart item list to tell the client how many
+/// items are in the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0121)]
+pub struct CartCapacityPacket {
+    pub amount: u16,
+    pub maximum_amount: u16,
+    pub weight: u32,
+    pub maximum_weight: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0125)]
+pub struct CartItemRemovedPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server when the player uses the vending skill. The client
+/// should let the player pick the items to sell from the cart.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x012D)]
+pub struct OpenVendingSetupPacket {
+    pub maximum_items: u16,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct VendingShopItemInformation {
+    /// Index of the item in the cart.
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub price: Price,
+}
+
+/// Sent by the client to the map server to open a vending shop with items
+/// from the cart.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x01B2)]
+#[variable_length]
+pub struct OpenVendingShopPacket {
+    #[length(80)]
+    pub title: String,
+    /// `1` to open the shop, `0` to cancel.
+    pub open: u8,
+    #[repeating_remaining]
+    pub items: Vec<VendingShopItemInformation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum OpenVendingShopResult {
+    Success,
+    Failed,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A28)]
+pub struct OpenVendingShopResultPacket {
+    pub result: OpenVendingShopResult,
+}
+
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BuyItemInformation {
-    pub amount: u16,
-    pub item_id: u16,
+#[header(0x012E)]
+pub struct CloseVendingShopPacket {}
+
+/// Sent by the map server to show the title board of a vending shop above the
+/// merchant.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0131)]
+pub struct VendingShopBoardPacket {
+    pub entity_id: EntityId,
+    #[length(80)]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0132)]
+pub struct VendingShopBoardRemovedPacket {
+    pub entity_id: EntityId,
 }
 
+/// Sent by the client to the map server to browse the vending shop of another
+/// player.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C8)]
+#[header(0x0130)]
+pub struct RequestVendingItemListPacket {
+    pub entity_id: EntityId,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    /// Index of the item in the vending shop.
+    pub index: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub location: u32,
+    pub look: u16,
+    pub enchantment_level: u8,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0B3D)]
 #[variable_length]
-pub struct BuyItemsPacket {
+pub struct VendingItemListPacket {
+    pub entity_id: EntityId,
+    /// Identifies the current state of the shop, so the server can detect
+    /// purchases from a shop that changed in the meantime.
+    pub unique_id: u32,
     #[repeating_remaining]
-    pub items: Vec<BuyItemInformation>,
+    pub items: Vec<VendingItemInformation>,
 }
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BuyShopItemInformation {
-    pub item_id: ItemId,
-    pub amount: u32,
+pub struct VendingPurchaseInformation {
+    pub amount: u16,
+    pub index: u16,
 }
 
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x09D6)]
+#[header(0x0801)]
 #[variable_length]
-pub struct BuyShopItemsPacket {
-    pub items: Vec<BuyShopItemInformation>,
+pub struct BuyVendingItemsPacket {
+    pub entity_id: EntityId,
+    pub unique_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingPurchaseInformation>,
 }
 
-#[derive(Debug, Clone, Copy, ByteConvertable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u16)]
-pub enum BuyShopItemsResult {
-    #[numeric_value(0)]
+pub enum BuyVendingItemsResult {
     Success,
-    #[numeric_value(0xFFFF)]
-    Error,
+    NotEnoughZeny,
+    Overweight,
+    #[numeric_value(4)]
+    OutOfStock,
+    VendorBusy,
+    ShopChanged,
+    NoSalesInformation,
 }
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the map server if buying from a vending shop failed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct BoughtShopItemInformation {
-    pub item_id: ItemId,
+#[header(0x0135)]
+pub struct BuyVendingItemsResultPacket {
+    pub index: u16,
     pub amount: u16,
+    pub result: BuyVendingItemsResult,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct OwnVendingItemInformation {
     pub price: Price,
+    pub index: u16,
+    pub amount: u16,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub enchantment_level: u8,
 }
 
+/// Sent by the map server to the merchant once the vending shop is open.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x0B4E)]
+#[header(0x0B3C)]
 #[variable_length]
-pub struct BuyShopItemsResultPacket {
-    pub result: BuyShopItemsResult,
+pub struct OwnVendingItemListPacket {
+    pub account_id: AccountId,
     #[repeating_remaining]
-    pub purchased_items: Vec<BoughtShopItemInformation>,
+    pub items: Vec<OwnVendingItemInformation>,
+}
+
+/// Sent by the map server to the merchant when an item from the vending shop
+/// was sold.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E5)]
+pub struct VendingItemSoldPacket {
+    pub index: u16,
+    pub amount: u16,
+    pub buyer_character_id: CharacterId,
+    pub time: u32,
+    pub zeny: u32,
+}
+
+/// The different inboxes of the mail system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum MailType {
+    Character,
+    Account,
+    Returned,
+    Unset,
 }
 
+/// Sent by the client to the map server to open the mailbox. The server
+/// responds with the newest mails, starting after `mail_id`.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x09D4)]
-pub struct CloseShopPacket {}
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    #[new_default]
+    pub unknown: [u8; 15],
+}
 
-#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+/// Sent by the client to the map server to request the mails following
+/// `mail_id`.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct SellItemInformation {
-    pub inventory_index: InventoryIndex,
-    pub price: Price,
-    pub overcharge_price: Price,
+#[header(0x0AC1)]
+pub struct RefreshMailboxPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    #[new_default]
+    pub unknown: [u8; 15],
+}
+
+/// Sent by the client to the map server when the mailbox is closed.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09E9)]
+pub struct CloseMailboxPacket {}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub struct MailInformation {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub is_read: u8,
+    /// `0x2` if zeny is attached, `0x4` if items are attached.
+    pub attachment_flags: u8,
+    #[length(24)]
+    pub sender_name: String,
+    /// Seconds until the mail expires.
+    pub expiration_time: u32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
 }
 
+/// Sent by the map server in response to [`OpenMailboxPacket`] and
+/// [`RefreshMailboxPacket`].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C7)]
+#[header(0x0AC2)]
 #[variable_length]
-pub struct SellListPacket {
-    #[repeating_remaining]
-    pub items: Vec<SellItemInformation>,
+pub struct MailListPacket {
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailInformation>,
+}
+
+/// Sent by the client to the map server to read a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EA)]
+pub struct ReadMailPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
 }
 
 #[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-pub struct SoldItemInformation {
-    pub inventory_index: InventoryIndex,
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub location: EquipPosition,
+    pub item_type: u8,
+    pub look: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5], // fix count
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server in response to [`ReadMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct MailContentPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+/// Sent by the client to the map server to delete a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F5)]
+pub struct DeleteMailPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the map server when a mail was deleted.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F6)]
+pub struct MailDeletedPacket {
+    pub mail_type: MailType,
+    pub mail_id: MailId,
+}
+
+/// Sent by the client to the map server to take the zeny attached to a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F1)]
+pub struct RequestMailZenyPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum MailAttachmentResult {
+    Success,
+    Failed,
+    /// Only used when taking items.
+    Overweight,
+}
+
+/// Sent by the map server in response to [`RequestMailZenyPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F2)]
+pub struct MailZenyResultPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+    pub result: MailAttachmentResult,
+}
+
+/// Sent by the client to the map server to take the items attached to a
+/// mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F3)]
+pub struct RequestMailItemsPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+}
+
+/// Sent by the map server in response to [`RequestMailItemsPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x09F4)]
+pub struct MailItemsResultPacket {
+    pub mail_id: MailId,
+    pub mail_type: MailType,
+    pub result: MailAttachmentResult,
+}
+
+/// Sent by the client to the map server before writing a mail. Resets the
+/// attachments of the previous mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A08)]
+pub struct OpenWriteMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server in response to [`OpenWriteMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A12)]
+pub struct OpenWriteMailResultPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    pub success: u8,
+}
+
+/// Sent by the client to the map server to look up the receiver of a mail.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A13)]
+pub struct CheckMailReceiverPacket {
+    #[length(24)]
+    pub receiver_name: String,
+}
+
+/// Sent by the map server in response to [`CheckMailReceiverPacket`]. The
+/// character id is `0` if no character with that name exists.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A14)]
+pub struct MailReceiverPacket {
+    pub character_id: CharacterId,
+    pub job: u16,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server to attach an item to the mail that
+/// is being written.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A04)]
+pub struct AddMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+pub enum AddMailItemResult {
+    Success,
+    Overweight,
+    Failed,
+    TooManyItems,
+    NotTradable,
+}
+
+/// Sent by the map server in response to [`AddMailItemPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A05)]
+pub struct AddMailItemResultPacket {
+    pub result: AddMailItemResult,
+    pub index: InventoryIndex,
     pub amount: u16,
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub is_identified: u8,
+    pub is_broken: u8,
+    pub refinement_level: u8,
+    pub cards: [u32; 4],
+    pub option_data: [ItemOptions; 5], // fix count
+    pub weight: u16,
+    pub is_favorite: u8,
+    pub location: EquipPosition,
+    pub enchantment_level: u8,
 }
 
+/// Sent by the client to the map server to remove an attached item from the
+/// mail that is being written.
 #[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00C9)]
+#[header(0x0A06)]
+pub struct RemoveMailItemPacket {
+    pub index: InventoryIndex,
+    pub amount: u16,
+}
+
+/// Sent by the map server in response to [`RemoveMailItemPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A07)]
+pub struct RemoveMailItemResultPacket {
+    pub success: u8,
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub weight: u16,
+}
+
+/// Sent by the client to the map server to send the mail that is being
+/// written, including all attached items.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A6E)]
 #[variable_length]
-pub struct SellItemsPacket {
-    #[repeating_remaining]
-    pub items: Vec<SoldItemInformation>,
+pub struct SendMailPacket {
+    #[length(24)]
+    pub receiver_name: String,
+    #[length(24)]
+    pub sender_name: String,
+    pub zeny: u64,
+    pub title_length: u16,
+    pub text_length: u16,
+    pub receiver_character_id: CharacterId,
+    #[length(title_length)]
+    pub title: String,
+    #[length(text_length)]
+    pub text: String,
 }
 
-#[derive(Debug, Clone, Copy, ByteConvertable)]
+/// Sent by the map server in response to [`SendMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[numeric_type(u8)]
-pub enum SellItemsResult {
-    Success,
-    Error,
+#[header(0x09ED)]
+pub struct SendMailResultPacket {
+    pub success: u8,
 }
 
+/// Sent by the client to the map server to claim the reward of a completed
+/// achievement.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
+#[header(0x0A25)]
+pub struct RequestAchievementRewardPacket {
+    pub achievement_id: u32,
+}
+
+/// Sent by the map server in response to [`RequestAchievementRewardPacket`].
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
-#[header(0x00CB)]
-pub struct SellItemsResultPacket {
-    pub result: SellItemsResult,
+#[header(0x0A26)]
+pub struct AchievementRewardPacket {
+    pub success: u8,
+    pub achievement_id: u32,
 }
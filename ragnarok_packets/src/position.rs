@@ -1,4 +1,4 @@
-use ragnarok_bytes::{ByteStream, ConversionResult, FromBytes, ToBytes};
+use ragnarok_bytes::{ByteStream, ConversionResult, FixedByteSize, FromBytes, ToBytes};
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "interface", derive(korangar_interface::elements::PrototypeElement))]
@@ -13,6 +13,12 @@ impl WorldPosition {
     }
 }
 
+impl FixedByteSize for WorldPosition {
+    fn size_in_bytes() -> usize {
+        3
+    }
+}
+
 impl FromBytes for WorldPosition {
     fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
         let coordinates: Vec<usize> = byte_stream.slice::<Self>(3)?.iter().map(|byte| *byte as usize).collect();
@@ -59,6 +65,12 @@ impl WorldPosition2 {
     }
 }
 
+impl FixedByteSize for WorldPosition2 {
+    fn size_in_bytes() -> usize {
+        6
+    }
+}
+
 impl FromBytes for WorldPosition2 {
     fn from_bytes<Meta>(byte_stream: &mut ByteStream<Meta>) -> ConversionResult<Self> {
         let coordinates: Vec<usize> = byte_stream.slice::<Self>(6)?.iter().map(|byte| *byte as usize).collect();
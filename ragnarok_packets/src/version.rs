@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+/// Date of the client that a server is configured for, in the format
+/// `YYYYMMDD`. This is the same value as `PACKETVER` in rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketVersion(pub u32);
+
+impl PacketVersion {
+    /// The client date that all packets without a version restriction are
+    /// defined for. This matches the default of rAthena.
+    pub const DEFAULT: Self = Self(20211103);
+}
+
+impl Default for PacketVersion {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Display for PacketVersion {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(formatter)
+    }
+}
+
+/// Range of client dates that use a packet. Packets that changed their header
+/// or layout over time are defined once per version, each with its own range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketVersionRange {
+    /// First client date that uses the packet.
+    pub since: Option<PacketVersion>,
+    /// First client date that no longer uses the packet.
+    pub until: Option<PacketVersion>,
+}
+
+impl PacketVersionRange {
+    /// Range of a packet that is used by all client dates.
+    pub const ALL: Self = Self { since: None, until: None };
+
+    pub fn contains(&self, version: PacketVersion) -> bool {
+        self.distance(version) == 0
+    }
+
+    /// How far the version is outside of the range, or 0 if it is inside.
+    /// The distance is computed on the raw `YYYYMMDD` values, which is only
+    /// meant for finding the closest range.
+    pub fn distance(&self, version: PacketVersion) -> u32 {
+        match (self.since, self.until) {
+            (Some(since), _) if version < since => since.0 - version.0,
+            (_, Some(until)) if version >= until => version.0 - until.0 + 1,
+            _ => 0,
+        }
+    }
+}
@@ -9,7 +9,7 @@ mod utils;
 
 use proc_macro::TokenStream as InterfaceTokenStream;
 use quote::quote;
-use syn::{parse, Data, DeriveInput};
+use syn::{parse, Data, DeriveInput, Type};
 
 use self::convertable::*;
 use self::fixed_size::{derive_fixed_byte_size_enum, derive_fixed_byte_size_struct};
@@ -135,6 +135,7 @@ pub fn derive_to_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenStre
         new_default,
         new_derive,
         new_value,
+        packet_version,
         ping,
         repeating,
         repeating_option,
@@ -169,13 +170,43 @@ pub fn derive_server_packet(token_stream: InterfaceTokenStream) -> InterfaceToke
     .into()
 }
 
-#[proc_macro_derive(ClientPacket)]
+#[proc_macro_derive(ClientPacket, attributes(legacy_variant))]
 pub fn derive_client_packet(token_stream: InterfaceTokenStream) -> InterfaceTokenStream {
-    let DeriveInput { ident, generics, .. } = parse(token_stream).expect("failed to parse token stream");
+    let DeriveInput {
+        ident, generics, attrs, ..
+    } = parse(token_stream).expect("failed to parse token stream");
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
+    let legacy_variants: Vec<Type> = attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("legacy_variant"))
+        .map(|attribute| attribute.parse_args().expect("failed to parse legacy variant"))
+        .collect();
+
+    let versioned_implementation = (!legacy_variants.is_empty()).then(|| {
+        quote! {
+            fn versioned_packet_to_bytes(
+                &self,
+                packet_version: ragnarok_packets::PacketVersion,
+            ) -> ragnarok_bytes::ConversionResult<Vec<u8>> {
+                if !<Self as ragnarok_packets::Packet>::VERSIONS.contains(packet_version) {
+                    #(
+                        if <#legacy_variants as ragnarok_packets::Packet>::VERSIONS.contains(packet_version) {
+                            let legacy_packet = <#legacy_variants as From<Self>>::from(self.clone());
+                            return ragnarok_packets::PacketExt::packet_to_bytes(&legacy_packet);
+                        }
+                    )*
+                }
+
+                ragnarok_packets::PacketExt::packet_to_bytes(self)
+            }
+        }
+    });
+
     quote! {
-        impl #impl_generics ragnarok_packets::ClientPacket for #ident #type_generics #where_clause {}
+        impl #impl_generics ragnarok_packets::ClientPacket for #ident #type_generics #where_clause {
+            #versioned_implementation
+        }
     }
     .into()
 }
@@ -19,6 +19,49 @@ pub fn derive_packet_struct(
         .expect("failed to parse packet header");
     let is_ping = get_unique_attribute(&mut attributes, "ping").is_some();
     let is_variable_length = get_unique_attribute(&mut attributes, "variable_length").is_some();
+    let packet_versions = get_unique_attribute(&mut attributes, "packet_version")
+        .map(|attribute| attribute.parse_args::<PacketVersionRange>())
+        .map(|range| range.expect("failed to parse packet version"))
+        .map(|PacketVersionRange { since, until }| {
+            let since = match since {
+                Some(since) => quote!(Some(ragnarok_packets::PacketVersion(#since))),
+                None => quote!(None),
+            };
+            let until = match until {
+                Some(until) => quote!(Some(ragnarok_packets::PacketVersion(#until))),
+                None => quote!(None),
+            };
+
+            quote! {
+                const VERSIONS: ragnarok_packets::PacketVersionRange = ragnarok_packets::PacketVersionRange {
+                    since: #since,
+                    until: #until,
+                };
+            }
+        });
+
+    let packet_length = match is_variable_length {
+        true => quote!(ragnarok_packets::PacketLength::Variable),
+        false => {
+            let sizes = data_struct.fields.iter().cloned().map(|mut field| {
+                let field_type = field.ty;
+
+                get_unique_attribute(&mut field.attrs, "length")
+                    .map(|attribute| match attribute.meta {
+                        syn::Meta::List(list) => list.tokens,
+                        syn::Meta::Path(_) | syn::Meta::NameValue(_) => panic!("expected token stream in attribute"),
+                    })
+                    .map(|length| quote!((#length) as usize))
+                    .unwrap_or(quote!(<#field_type as ragnarok_bytes::FixedByteSize>::size_in_bytes()))
+            });
+
+            quote! {
+                let mut total = <ragnarok_packets::PacketHeader as ragnarok_bytes::FixedByteSize>::size_in_bytes();
+                #(total += #sizes;)*
+                ragnarok_packets::PacketLength::Fixed(total)
+            }
+        }
+    };
 
     let signature = packet_signature.signature;
     let (new_implementation, from_bytes_implementations, implemented_fields, to_bytes_implementations, delimiter) =
@@ -62,6 +105,11 @@ pub fn derive_packet_struct(
         impl #impl_generics ragnarok_packets::Packet for #name #type_generics #where_clause {
             const IS_PING: bool = #is_ping;
             const HEADER: ragnarok_packets::PacketHeader = ragnarok_packets::PacketHeader(#signature);
+            #packet_versions
+
+            fn packet_length() -> ragnarok_packets::PacketLength {
+                #packet_length
+            }
 
             fn payload_from_bytes<Meta>(byte_stream: &mut ragnarok_bytes::ByteStream<Meta>) -> ragnarok_bytes::ConversionResult<Self> {
                 let base_offset = byte_stream.get_offset();
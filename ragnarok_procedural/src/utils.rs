@@ -1,6 +1,6 @@
 use proc_macro2::Punct;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, Error, LitInt};
+use syn::{Attribute, Error, Ident, LitInt, Token};
 
 #[derive(Clone)]
 pub struct PacketSignature {
@@ -16,6 +16,38 @@ impl Parse for PacketSignature {
     }
 }
 
+/// Range of client dates a packet is used for, e.g.
+/// `#[packet_version(since = 20170315, until = 20200101)]`.
+#[derive(Clone, Default)]
+pub struct PacketVersionRange {
+    pub since: Option<u32>,
+    pub until: Option<u32>,
+}
+
+impl Parse for PacketVersionRange {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
+        let mut range = PacketVersionRange::default();
+
+        while !input.is_empty() {
+            let bound: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let version = input.parse::<LitInt>()?.base10_parse::<u32>()?;
+
+            match bound.to_string().as_str() {
+                "since" => range.since = Some(version),
+                "until" => range.until = Some(version),
+                _ => return Err(Error::new(bound.span(), "expected `since` or `until`")),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(range)
+    }
+}
+
 #[derive(Clone)]
 pub struct Version {
     pub major: LitInt,